@@ -3,7 +3,8 @@
 pub use glide_core::client::{GlideRt, get_or_init_runtime};
 
 use crate::connection_request::ConnectionRequest;
-use redis::{Pipeline, PipelineRetryStrategy, ScanStateRC, Cmd, PushInfo, Value, ClusterScanArgs, RoutingInfo, RedisResult};
+use crate::pubsub::PushSender;
+use redis::{Pipeline, PipelineRetryStrategy, ScanStateRC, Cmd, Value, ClusterScanArgs, RoutingInfo, RedisResult};
 
 pub struct ConnectionError;
 
@@ -17,15 +18,20 @@ impl fmt::Display for ConnectionError {
     }
 }
 
+/// The URI parsing doesn't call foreign code, so the real implementation is used.
+pub fn parse_connection_uri(uri: &str) -> Result<ConnectionRequest, ConnectionError> {
+    glide_core::client::parse_connection_uri(uri).map_err(|_| ConnectionError)
+}
+
 #[derive(Clone)]
 pub struct Client {
-    _push_sender: Option<tokio::sync::mpsc::UnboundedSender<PushInfo>>
+    _push_sender: Option<PushSender>
 }
 
 impl Client {
     pub async fn new(
         _request: ConnectionRequest,
-        push_sender: Option<tokio::sync::mpsc::UnboundedSender<PushInfo>>,
+        push_sender: Option<PushSender>,
     ) -> Result<Self, ConnectionError> {
         Ok(Client {
             _push_sender: push_sender
@@ -71,6 +77,36 @@ impl Client {
         todo!()
     }
 
+    pub async fn start_transaction_session(
+        &mut self,
+        _routing_key: Option<&[u8]>,
+    ) -> RedisResult<String> {
+        todo!()
+    }
+
+    pub async fn send_session_command(
+        &mut self,
+        _session_id: &str,
+        _cmd: &Cmd,
+        _request_timeout: Option<u32>,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub async fn send_session_transaction(
+        &mut self,
+        _session_id: &str,
+        _pipeline: &Pipeline,
+        _transaction_timeout: Option<u32>,
+        _raise_on_error: bool,
+    ) -> RedisResult<Value> {
+        todo!()
+    }
+
+    pub fn end_transaction_session(&self, _session_id: &str) -> bool {
+        false
+    }
+
     pub fn send_command<'a>(
         &'a mut self,
         _cmd: &'a Cmd,
@@ -79,6 +115,15 @@ impl Client {
         todo!()
     }
 
+    pub fn send_command_with_timeout<'a>(
+        &'a mut self,
+        _cmd: &'a mut Cmd,
+        _routing: Option<RoutingInfo>,
+        _request_timeout: Option<u32>,
+    ) -> redis::RedisFuture<'a, redis::Value> {
+        todo!()
+    }
+
     pub async fn cluster_scan<'a>(
         &'a mut self,
         _scan_state_cursor: &'a ScanStateRC,
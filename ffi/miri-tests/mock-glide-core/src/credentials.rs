@@ -0,0 +1,18 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Mock credentials module for Miri tests
+
+use crate::connection_request::ConnectionRequest;
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait CredentialsProvider: Send + Sync {
+    async fn get_password(&self) -> Option<String>;
+}
+
+/// The mock client doesn't connect, so the provider is dropped.
+pub fn set_credentials_provider(
+    _request: &mut ConnectionRequest,
+    _provider: Arc<dyn CredentialsProvider>,
+) {
+}
@@ -5,8 +5,10 @@ pub mod cluster_scan_container;
 pub mod command_request;
 pub mod compression;
 pub mod connection_request;
+pub mod credentials;
 pub mod errors;
 pub mod otel_db_semantics;
+pub mod pubsub;
 pub mod request_type;
 pub mod scripts_container;
 
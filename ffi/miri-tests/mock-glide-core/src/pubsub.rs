@@ -0,0 +1,20 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use redis::PushInfo;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+#[derive(Clone)]
+pub struct PushSender(UnboundedSender<PushInfo>);
+
+pub struct PushReceiver(UnboundedReceiver<PushInfo>);
+
+pub fn push_channel() -> (PushSender, PushReceiver) {
+    let (sender, receiver) = unbounded_channel();
+    (PushSender(sender), PushReceiver(receiver))
+}
+
+impl PushReceiver {
+    pub async fn recv(&mut self) -> Option<PushInfo> {
+        self.0.recv().await
+    }
+}
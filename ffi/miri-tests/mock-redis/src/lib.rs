@@ -132,6 +132,10 @@ impl PipelineRetryStrategy {
     pub fn new(_retry_server_error: bool, _retry_connection_error: bool) -> Self {
         PipelineRetryStrategy
     }
+
+    pub fn with_idempotent_connection_error_retries(self) -> Self {
+        self
+    }
 }
 
 pub fn parse_redis_url(input: &str) -> Option<url::Url> {
@@ -1,7 +1,32 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-use std::marker::PhantomData;
+use std::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 pub struct JoinHandle<T> {
     pub _p: PhantomData<T>,
 }
+
+#[derive(Debug)]
+pub struct JoinError;
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        todo!()
+    }
+}
+
+/// The blocking closure isn't run, like the futures given to `Runtime::spawn`.
+pub fn spawn_blocking<F, R>(_f: F) -> JoinHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    JoinHandle { _p: PhantomData }
+}
@@ -10,6 +10,7 @@ use miri_tests::{
     free_connection_response,
 };
 use miri_tests::{Level, LogResult, free_log_result, glide_log, init};
+use miri_tests::{free_c_string, serialize_cluster_scan_cursor};
 use miri_tests::{
     create_batch_otel_span, create_batch_otel_span_with_parent, create_named_otel_span,
     create_otel_span, create_otel_span_with_parent, drop_otel_span,
@@ -98,6 +99,20 @@ fn create_client_from_uri_test() {
     }
 }
 
+#[test]
+fn serialize_cluster_scan_cursor_test() {
+    let cursor_id = CString::new("cursor").unwrap();
+
+    unsafe {
+        let serialized = serialize_cluster_scan_cursor(cursor_id.as_ptr());
+        assert!(!serialized.is_null());
+        assert_eq!(CStr::from_ptr(serialized).to_str().unwrap(), "cursor");
+        free_c_string(serialized);
+
+        assert!(serialize_cluster_scan_cursor(ptr::null()).is_null());
+    }
+}
+
 #[test]
 fn test_create_otel_span_miri() {
     // Test basic span creation
@@ -15,7 +15,14 @@ struct Server {
 impl Server {
     fn new() -> Self {
         let port = Self::get_available_port();
-        let process = Self::start_server(port);
+        let process = Self::start_server(port, &[]);
+        Self { process, port }
+    }
+
+    /// Starts a server that also listens on the Unix domain socket at `socket_path`.
+    fn with_unix_socket(socket_path: &str) -> Self {
+        let port = Self::get_available_port();
+        let process = Self::start_server(port, &["--unixsocket", socket_path]);
         Self { process, port }
     }
 
@@ -27,7 +34,7 @@ impl Server {
             .expect("Failed to find an available port")
     }
 
-    fn start_server(port: u16) -> Child {
+    fn start_server(port: u16, extra_args: &[&str]) -> Child {
         let run_server = |engine_type: &str| {
             Command::new(engine_type)
                 .arg("--port")
@@ -36,6 +43,7 @@ impl Server {
                 .arg("")
                 .arg("--appendonly")
                 .arg("no")
+                .args(extra_args)
                 .spawn()
         };
 
@@ -774,6 +782,47 @@ fn test_create_client_from_uri_valid_formats(#[case] uri_format: &str) {
     }
 }
 
+#[rstest]
+#[case("valkey+unix")]
+#[case("redis+unix")]
+#[case("unix")]
+fn test_create_client_from_uri_unix_socket(#[case] scheme: &str) {
+    let socket_path = std::env::temp_dir().join(format!(
+        "glide-ffi-{}-{}.sock",
+        std::process::id(),
+        scheme.replace('+', "-")
+    ));
+    let socket_path = socket_path.to_str().unwrap();
+    let _server = Server::with_unix_socket(socket_path);
+    let uri = CString::new(format!("{scheme}://{socket_path}")).unwrap();
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+
+    let response = unsafe {
+        create_client_from_uri(
+            uri.as_ptr(),
+            ptr::null(),
+            client_type,
+            null_pubsub_callback(),
+        )
+    };
+
+    assert!(!response.is_null());
+    let conn_response = unsafe { &*response };
+    let error = parse_error_msg(conn_response.connection_error_message);
+    assert!(
+        conn_response.connection_error_message.is_null(),
+        "Failed to create a client from `{scheme}://`: {error}"
+    );
+    assert!(!conn_response.conn_ptr.is_null());
+
+    unsafe {
+        close_client(conn_response.conn_ptr);
+        free_connection_response(response as *mut ConnectionResponse);
+        drop(Box::from_raw(client_type));
+    }
+    std::fs::remove_file(socket_path).ok();
+}
+
 #[rstest]
 #[case("PreferReplica")]
 // #[case("LowestLatency")] // TODO: Not yet implemented in glide-core
@@ -1118,3 +1167,37 @@ fn test_create_client_from_uri_invalid_service_type() {
         drop(Box::from_raw(client_type));
     }
 }
+
+#[test]
+fn test_create_client_from_uri_out_of_range_in_json() {
+    let server = Server::new();
+    let uri = CString::new(format!("redis://127.0.0.1:{}", server.port)).unwrap();
+
+    // Larger than u32::MAX, which used to be truncated silently
+    let options = CString::new(r#"{"lowest_latency_hysteresis_ms": 4294967296}"#).unwrap();
+
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+
+    let response = unsafe {
+        create_client_from_uri(
+            uri.as_ptr(),
+            options.as_ptr(),
+            client_type,
+            null_pubsub_callback(),
+        )
+    };
+
+    assert!(!response.is_null());
+    let conn_response = unsafe { &*response };
+
+    assert!(!conn_response.connection_error_message.is_null());
+    assert!(conn_response.conn_ptr.is_null());
+
+    let error = parse_error_msg(conn_response.connection_error_message);
+    assert!(error.contains("lowest_latency_hysteresis_ms must be at most 4294967295"));
+
+    unsafe {
+        free_connection_response(response as *mut ConnectionResponse);
+        drop(Box::from_raw(client_type));
+    }
+}
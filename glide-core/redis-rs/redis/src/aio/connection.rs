@@ -11,7 +11,7 @@ use crate::connection::{
 use crate::parser::ValueCodec;
 use crate::pipeline::PipelineRetryStrategy;
 use crate::types::{ErrorKind, FromRedisValue, RedisError, RedisFuture, RedisResult, Value};
-use crate::{from_owned_redis_value, ProtocolVersion, ToRedisArgs};
+use crate::{from_owned_redis_value, DnsResolver, ProtocolVersion, ToRedisArgs};
 use ::tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 #[cfg(feature = "tokio-comp")]
 use ::tokio::net::lookup_host;
@@ -388,14 +388,31 @@ where
     }
 }
 
+/// Resolves hostnames with the system resolver.
+#[cfg(feature = "tokio-comp")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemDnsResolver;
+
+#[cfg(feature = "tokio-comp")]
+#[async_trait::async_trait]
+impl DnsResolver for SystemDnsResolver {
+    async fn resolve(&self, host: &str, port: u16) -> RedisResult<Vec<SocketAddr>> {
+        Ok(lookup_host((host, port)).await?.collect())
+    }
+}
+
 pub(crate) async fn get_socket_addrs(
     host: &str,
     port: u16,
-) -> RedisResult<impl Iterator<Item = SocketAddr> + Send + '_> {
+    dns_resolver: Option<&dyn DnsResolver>,
+) -> RedisResult<impl Iterator<Item = SocketAddr> + Send> {
     #[cfg(feature = "tokio-comp")]
-    let socket_addrs = lookup_host((host, port)).await?;
+    let socket_addrs = dns_resolver
+        .unwrap_or(&SystemDnsResolver)
+        .resolve(host, port)
+        .await?;
 
-    let mut socket_addrs = socket_addrs.peekable();
+    let mut socket_addrs = socket_addrs.into_iter().peekable();
     match socket_addrs.peek() {
         Some(_) => Ok(socket_addrs),
         None => Err(RedisError::from((
@@ -420,6 +437,7 @@ pub(crate) async fn connect_simple<T: RedisRuntime>(
     connection_info: &ConnectionInfo,
     _socket_addr: Option<SocketAddr>,
     tcp_nodelay: bool,
+    dns_resolver: Option<&dyn DnsResolver>,
 ) -> RedisResult<(T, Option<IpAddr>)> {
     Ok(match connection_info.addr {
         ConnectionAddr::Tcp(ref host, port) => {
@@ -429,7 +447,7 @@ pub(crate) async fn connect_simple<T: RedisRuntime>(
                     Some(socket_addr.ip()),
                 ));
             }
-            let socket_addrs = get_socket_addrs(host, port).await?;
+            let socket_addrs = get_socket_addrs(host, port, dns_resolver).await?;
             select_ok(socket_addrs.map(|socket_addr| {
                 log_conn_creation("TCP", format!("{host}:{port}"), Some(socket_addr.ip()));
                 Box::pin(async move {
@@ -456,7 +474,7 @@ pub(crate) async fn connect_simple<T: RedisRuntime>(
                     Some(socket_addr.ip()),
                 ));
             }
-            let socket_addrs = get_socket_addrs(host, port).await?;
+            let socket_addrs = get_socket_addrs(host, port, dns_resolver).await?;
             select_ok(socket_addrs.map(|socket_addr| {
                 log_conn_creation(
                     "TCP with TLS",
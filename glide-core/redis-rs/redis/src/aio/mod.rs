@@ -1,4 +1,5 @@
 //! Adds async IO support to redis.
+use crate::cache::TrackingMode;
 use crate::cmd::{cmd, Cmd};
 use crate::connection::{get_resp3_hello_command_error, RedisConnectionInfo};
 use crate::pipeline::PipelineRetryStrategy;
@@ -96,6 +97,12 @@ pub trait ConnectionLike {
     fn update_push_manager_node_address(&mut self, _address: String) {
         // Default: no-op
     }
+
+    /// Returns the number of requests sent on this connection that are still waiting for their response.
+    /// Default implementation returns 0 - only MultiplexedConnection tracks its pending requests.
+    fn pending_requests(&self) -> usize {
+        0
+    }
 }
 
 /// Implements ability to notify about disconnection events
@@ -225,6 +232,27 @@ where
         }
     }
 
+    if let Some(cache) = &connection_info.cache {
+        if connection_info.protocol == ProtocolVersion::RESP2 {
+            tracing::warn!(
+                "Client-side cache is used without RESP3, the server will not send invalidation messages"
+            );
+        } else {
+            let mut command = cmd("CLIENT");
+            command.arg("TRACKING").arg("ON");
+            if cache.tracking_mode() == TrackingMode::OptIn {
+                command.arg("OPTIN");
+            }
+            match command.query_async(con).await {
+                Ok(Value::Okay) => {}
+                _ => fail!((
+                    ErrorKind::ResponseError,
+                    "Redis server refused to enable client tracking"
+                )),
+            }
+        }
+    }
+
     if discover_az {
         update_az_from_info(con).await?;
     }
@@ -1,9 +1,11 @@
 use super::{ConnectionLike, Runtime};
 use crate::aio::setup_connection;
 use crate::aio::DisconnectNotifier;
-use crate::cache::glide_cache::GlideCache;
+use crate::cache::glide_cache::{CachedKeyType, GlideCache};
+use crate::cache::TrackingMode;
 use crate::client::GlideConnectionOptions;
-use crate::cmd::Cmd;
+use crate::cluster_routing::{Routable, RoutingInfo};
+use crate::cmd::{cacheable_cmd_type, Cmd};
 #[cfg(feature = "tokio-comp")]
 use crate::parser::ValueCodec;
 use crate::pipeline::PipelineRetryStrategy;
@@ -27,7 +29,7 @@ use std::collections::VecDeque;
 use std::fmt;
 use std::fmt::Debug;
 use std::pin::Pin;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::task::{self, Poll};
 use std::time::Duration;
@@ -92,6 +94,24 @@ pub(crate) struct Pipeline<SinkItem> {
     sender: mpsc::Sender<PipelineMessage<SinkItem>>,
     push_manager: Arc<ArcSwap<PushManager>>,
     is_stream_closed: Arc<AtomicBool>,
+    // Number of requests sent through this pipeline that are still waiting for their response.
+    pending_requests: Arc<AtomicUsize>,
+}
+
+// Decrements the pending requests counter when a request completes, times out or is dropped.
+struct PendingRequestGuard(Arc<AtomicUsize>);
+
+impl PendingRequestGuard {
+    fn new(pending_requests: &Arc<AtomicUsize>) -> Self {
+        pending_requests.fetch_add(1, Ordering::Relaxed);
+        Self(pending_requests.clone())
+    }
+}
+
+impl Drop for PendingRequestGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
 }
 
 impl<SinkItem> Debug for Pipeline<SinkItem>
@@ -496,6 +516,7 @@ where
                 sender,
                 push_manager,
                 is_stream_closed,
+                pending_requests: Arc::new(AtomicUsize::new(0)),
             },
             f,
         )
@@ -520,6 +541,7 @@ where
         is_atomic: bool,
         is_fenced: bool,
     ) -> Result<Value, RedisError> {
+        let _pending_request = PendingRequestGuard::new(&self.pending_requests);
         let (sender, receiver) = oneshot::channel();
 
         self.sender
@@ -566,6 +588,17 @@ where
     pub fn is_closed(&self) -> bool {
         self.is_stream_closed.load(Ordering::Relaxed)
     }
+
+    /// Returns the number of requests that are waiting for their response.
+    pub fn pending_requests(&self) -> usize {
+        self.pending_requests.load(Ordering::Relaxed)
+    }
+}
+
+/// Returns the key and key type cached by `cmd`, if it's a supported cacheable command.
+fn cacheable_cmd_key(cmd: &Cmd) -> Option<(&[u8], CachedKeyType)> {
+    let key_type = cacheable_cmd_type(&cmd.command()?)?;
+    RoutingInfo::key_for_command(cmd).map(|key| (key, key_type))
 }
 
 /// A connection object which can be cloned, allowing requests to be be sent concurrently
@@ -632,7 +665,8 @@ impl MultiplexedConnection {
             glide_connection_options.push_sender,
             glide_connection_options.pubsub_synchronizer,
             Some(connection_info.addr.to_string()),
-        );
+        )
+        .with_cache(connection_info.redis.cache.clone());
 
         pipeline.set_push_manager(pm.clone());
 
@@ -687,14 +721,19 @@ impl MultiplexedConnection {
                 return Ok(value);
             }
         }
-        let result = self
-            .pipeline
-            .send_single(
-                cmd.get_packed_command(),
-                self.response_timeout,
-                cmd.is_fenced(),
-            )
-            .await;
+        // Taken before sending, so a reply invalidated while in flight isn't cached
+        let invalidation_epoch = self.cache.as_ref().map(|cache| cache.invalidation_epoch());
+        let result = if self.requires_caching_opt_in(cmd) {
+            self.send_with_caching_opt_in(cmd).await
+        } else {
+            self.pipeline
+                .send_single(
+                    cmd.get_packed_command(),
+                    self.response_timeout,
+                    cmd.is_fenced(),
+                )
+                .await
+        };
         if self.protocol != ProtocolVersion::RESP2 {
             if let Err(e) = &result {
                 if e.is_connection_dropped() {
@@ -708,16 +747,63 @@ impl MultiplexedConnection {
         }
 
         // Store in cache if applicable
-        if let Some(cache) = &self.cache {
-            if let Ok(value) = &result {
-                if *value != Value::Nil {
-                    cache.set_cached_cmd(cmd, value.clone());
+        if let (Some(cache), Some(epoch), Ok(value)) = (&self.cache, invalidation_epoch, &result) {
+            if *value != Value::Nil {
+                if let Some((key, key_type)) = cacheable_cmd_key(cmd) {
+                    // Tracked before the insert, so a disconnection in between invalidates the key
+                    self.push_manager.track_cached_key(key.to_vec());
+                    cache.insert_if_not_invalidated(key.to_vec(), key_type, value.clone(), epoch);
                 }
             }
         }
         result
     }
 
+    /// Returns true if the cache tracks keys in `OPTIN` mode and `cmd` is a cacheable read,
+    /// so the server has to be told to track the keys it reads.
+    fn requires_caching_opt_in(&self, cmd: &Cmd) -> bool {
+        self.cache.as_ref().is_some_and(|cache| {
+            cache.tracking_mode() == TrackingMode::OptIn
+                && cmd
+                    .command()
+                    .is_some_and(|name| cacheable_cmd_type(&name).is_some())
+        })
+    }
+
+    /// Sends `CLIENT CACHING YES` immediately followed by `cmd`, and returns the reply of `cmd`.
+    /// With `CLIENT TRACKING ON OPTIN` the server only tracks keys read by the command that follows it.
+    async fn send_with_caching_opt_in(&mut self, cmd: &Cmd) -> RedisResult<Value> {
+        let mut pipeline = crate::pipe();
+        pipeline
+            .cmd("CLIENT")
+            .arg("CACHING")
+            .arg("YES")
+            .add_command(cmd.clone());
+
+        let value = self
+            .pipeline
+            .send_recv(
+                pipeline.get_packed_pipeline(),
+                Some(2),
+                self.response_timeout,
+                false,
+                false,
+            )
+            .await?;
+        match value {
+            Value::Array(mut values) => values
+                .pop()
+                .ok_or_else(|| {
+                    RedisError::from((
+                        crate::ErrorKind::ResponseError,
+                        "Missing response to cached command",
+                    ))
+                })?
+                .extract_error(),
+            value => value.extract_error(),
+        }
+    }
+
     /// Sends multiple already encoded (packed) command into the TCP socket
     /// and reads `count` responses from it.  This is used to implement
     /// pipelining.
@@ -926,6 +1012,10 @@ impl ConnectionLike for MultiplexedConnection {
     fn update_push_manager_node_address(&mut self, address: String) {
         MultiplexedConnection::update_push_manager_node_address(self, address);
     }
+
+    fn pending_requests(&self) -> usize {
+        self.pipeline.pending_requests()
+    }
 }
 impl MultiplexedConnection {
     /// Subscribes to a new channel.
@@ -130,7 +130,7 @@ impl RedisRuntime for Tokio {
 
         Ok(tls_connector
             .connect(
-                rustls_pki_types::ServerName::try_from(hostname)?.to_owned(),
+                crate::tls::server_name(hostname, tls_params.as_ref())?,
                 connect_tcp(&socket_addr, tcp_nodelay).await?,
             )
             .await
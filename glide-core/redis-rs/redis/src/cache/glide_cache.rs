@@ -2,6 +2,7 @@
 use tracing::{debug, warn};
 
 use crate::{
+    cache::TrackingMode,
     cluster_routing::{Routable, RoutingInfo},
     cmd::cacheable_cmd_type,
     Cmd, ErrorKind, RedisError, RedisResult, Value,
@@ -28,6 +29,9 @@ pub struct CacheConfig {
 
     /// Enable metrics collection (hits, misses, evictions, expirations)
     pub enable_metrics: bool,
+
+    /// Server-assisted invalidation mode used by connections sharing this cache
+    pub tracking_mode: TrackingMode,
 }
 
 // ==================== Metrics ====================
@@ -203,6 +207,9 @@ pub struct CacheCore {
 
     /// Performance statistics (None if metrics disabled)
     stats: Option<CacheMetrics>,
+
+    /// Number of invalidations applied, used to drop values read before an invalidation
+    invalidation_epoch: AtomicU64,
 }
 
 impl CacheCore {
@@ -214,6 +221,7 @@ impl CacheCore {
             config,
             current_memory: AtomicU64::new(0),
             stats,
+            invalidation_epoch: AtomicU64::new(0),
         }
     }
 
@@ -266,6 +274,18 @@ impl CacheCore {
             });
     }
 
+    // ==================== Invalidation ====================
+
+    /// Returns the number of invalidations applied so far
+    pub fn invalidation_epoch(&self) -> u64 {
+        self.invalidation_epoch.load(Ordering::Acquire)
+    }
+
+    /// Records an invalidation, so values read before it are no longer cached
+    pub fn advance_invalidation_epoch(&self) {
+        self.invalidation_epoch.fetch_add(1, Ordering::AcqRel);
+    }
+
     // ==================== Metrics ====================
 
     /// Returns a reference to the metrics if enabled
@@ -407,6 +427,73 @@ impl<S: EvictionStrategy> GlideCacheImpl<S> {
             );
         }
     }
+
+    /// Inserts an entry, evicting others to make space. Returns false if the entry is too big for the cache.
+    fn insert_locked(
+        &self,
+        store: &mut S,
+        key: Vec<u8>,
+        key_type: CachedKeyType,
+        value: Value,
+    ) -> bool {
+        let entry_size = calculate_entry_size(&key, &value);
+
+        if self.core.entry_too_big(entry_size) {
+            warn!(
+                "cache_insert - Entry too large for cache: {}B > {}B (max), skipping",
+                entry_size,
+                self.core.max_memory()
+            );
+            return false;
+        }
+
+        // Remove existing entry if present
+        if let Some(existing) = store.remove(&key) {
+            self.core.uncharge(existing.size);
+        }
+
+        // Evict until space available
+        self.evict_until_space_available(store, entry_size);
+
+        // Insert new entry
+        let expires_at = self.core.compute_expires_at();
+        let entry = CacheEntry::new(value, key_type, expires_at, entry_size);
+
+        store.insert(key, entry);
+        self.core.charge(entry_size);
+
+        debug!(
+            "cache_insert - [{}] Inserted entry (type={:?}, size={}B{})",
+            store.policy_name(),
+            key_type,
+            entry_size,
+            if expires_at.is_some() {
+                ", with TTL"
+            } else {
+                ""
+            }
+        );
+        true
+    }
+
+    /// Removes an invalidated entry if present, updating memory and stats.
+    fn invalidate_locked(&self, store: &mut S, key: &[u8]) {
+        if let Some(entry) = store.remove(key) {
+            self.core.uncharge(entry.size);
+
+            if let Some(stats) = self.core.stats() {
+                stats.record_invalidation();
+            }
+
+            debug!(
+                "cache_invalidate - [{}] Invalidated entry (type={:?}, size={}B, remaining_memory={}B)",
+                store.policy_name(),
+                entry.key_type,
+                entry.size,
+                self.core.current_memory()
+            );
+        }
+    }
 }
 
 // ==================== GlideCache Trait ====================
@@ -449,12 +536,45 @@ pub trait GlideCache: Send + Sync + Debug {
     /// * `value` - The value to associate with the key
     fn insert(&self, key: Vec<u8>, key_type: CachedKeyType, value: Value);
 
+    /// Inserts like [`GlideCache::insert`], unless an invalidation was applied since `epoch` was taken
+    ///
+    /// Take the epoch with [`GlideCache::invalidation_epoch`] before sending the read, so a value
+    /// invalidated while its reply was in flight isn't cached. Returns whether the entry was inserted.
+    fn insert_if_not_invalidated(
+        &self,
+        key: Vec<u8>,
+        key_type: CachedKeyType,
+        value: Value,
+        epoch: u64,
+    ) -> bool;
+
     /// Invalidates a key from the cache
     ///
     /// # Arguments
     /// * `key` - The key to invalidate
     fn invalidate(&self, key: &[u8]);
 
+    /// Invalidates the given keys
+    ///
+    /// Advances the invalidation epoch even if `keys` is empty, e.g. when a connection that tracked
+    /// no keys yet is lost while a read is in flight.
+    fn invalidate_keys(&self, keys: &[Vec<u8>]);
+
+    /// Invalidates every entry in the cache
+    fn invalidate_all(&self);
+
+    // ==================== Server-Assisted Invalidation ====================
+
+    /// Returns the `CLIENT TRACKING` mode connections using this cache should enable
+    fn tracking_mode(&self) -> TrackingMode {
+        self.core().config().tracking_mode
+    }
+
+    /// Returns the number of invalidations applied so far, see [`GlideCache::insert_if_not_invalidated`]
+    fn invalidation_epoch(&self) -> u64 {
+        self.core().invalidation_epoch()
+    }
+
     // ==================== Metrics ====================
 
     /// Returns current cache metrics (hits, misses, etc.)
@@ -565,65 +685,62 @@ impl<S: EvictionStrategy + 'static> GlideCache for GlideCacheImpl<S> {
     }
 
     fn insert(&self, key: Vec<u8>, key_type: CachedKeyType, value: Value) {
-        let entry_size = calculate_entry_size(&key, &value);
+        let mut store = self.store.lock().unwrap();
+        self.insert_locked(&mut store, key, key_type, value);
+    }
 
-        if self.core.entry_too_big(entry_size) {
-            warn!(
-                "cache_insert - Entry too large for cache: {}B > {}B (max), skipping",
-                entry_size,
-                self.core.max_memory()
+    fn insert_if_not_invalidated(
+        &self,
+        key: Vec<u8>,
+        key_type: CachedKeyType,
+        value: Value,
+        epoch: u64,
+    ) -> bool {
+        // Invalidations advance the epoch while holding the store lock, so none can slip in
+        // between the check and the insert.
+        let mut store = self.store.lock().unwrap();
+        if self.core.invalidation_epoch() != epoch {
+            debug!(
+                "cache_insert - [{}] Skipped entry invalidated while its value was read",
+                store.policy_name()
             );
-            return;
+            return false;
         }
+        self.insert_locked(&mut store, key, key_type, value)
+    }
 
+    fn invalidate(&self, key: &[u8]) {
         let mut store = self.store.lock().unwrap();
+        self.core.advance_invalidation_epoch();
+        self.invalidate_locked(&mut store, key);
+    }
 
-        // Remove existing entry if present
-        if let Some(existing) = store.remove(&key) {
-            self.core.uncharge(existing.size);
+    fn invalidate_keys(&self, keys: &[Vec<u8>]) {
+        let mut store = self.store.lock().unwrap();
+        self.core.advance_invalidation_epoch();
+        for key in keys {
+            self.invalidate_locked(&mut store, key);
         }
-
-        // Evict until space available
-        self.evict_until_space_available(&mut store, entry_size);
-
-        // Insert new entry
-        let expires_at = self.core.compute_expires_at();
-        let entry = CacheEntry::new(value, key_type, expires_at, entry_size);
-
-        store.insert(key, entry);
-        self.core.charge(entry_size);
-
-        debug!(
-            "cache_insert - [{}] Inserted entry (type={:?}, size={}B{})",
-            store.policy_name(),
-            key_type,
-            entry_size,
-            if expires_at.is_some() {
-                ", with TTL"
-            } else {
-                ""
-            }
-        );
     }
 
-    fn invalidate(&self, key: &[u8]) {
+    fn invalidate_all(&self) {
         let mut store = self.store.lock().unwrap();
+        self.core.advance_invalidation_epoch();
+        let mut invalidated = 0;
 
-        if let Some(entry) = store.remove(key) {
+        while let Some(entry) = store.evict_one() {
             self.core.uncharge(entry.size);
-
             if let Some(stats) = self.core.stats() {
                 stats.record_invalidation();
             }
-
-            debug!(
-                "cache_invalidate - [{}] Invalidated entry (type={:?}, size={}B, remaining_memory={}B)",
-                store.policy_name(),
-                entry.key_type,
-                entry.size,
-                self.core.current_memory()
-            );
+            invalidated += 1;
         }
+
+        debug!(
+            "cache_invalidate - [{}] Invalidated all {} entries",
+            store.policy_name(),
+            invalidated
+        );
     }
 
     fn entry_count(&self) -> u64 {
@@ -819,6 +936,7 @@ mod tests {
             max_memory_bytes: 1024,
             ttl: Some(Duration::from_secs(60)),
             enable_metrics: true,
+            tracking_mode: TrackingMode::Default,
         };
         let core = CacheCore::new(config);
         assert_eq!(core.max_memory(), 1024);
@@ -831,6 +949,7 @@ mod tests {
             max_memory_bytes: 1024,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         };
         let core = CacheCore::new(config);
         assert!(core.stats.is_none());
@@ -843,12 +962,14 @@ mod tests {
             max_memory_bytes: 1024,
             ttl: Some(Duration::from_secs(60)),
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         });
         assert!(with_ttl.compute_expires_at().is_some());
         let without_ttl = CacheCore::new(CacheConfig {
             max_memory_bytes: 1024,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         });
         assert!(without_ttl.compute_expires_at().is_none());
     }
@@ -858,6 +979,7 @@ mod tests {
             max_memory_bytes: 100,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         });
         assert!(!core.entry_too_big(50));
         assert!(!core.entry_too_big(100));
@@ -869,6 +991,7 @@ mod tests {
             max_memory_bytes: 1000,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         });
         assert_eq!(core.current_memory(), 0);
         core.charge(100);
@@ -887,6 +1010,7 @@ mod tests {
             max_memory_bytes: 100,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         });
         assert!(!core.needs_eviction(50));
         assert!(!core.needs_eviction(100));
@@ -901,6 +1025,7 @@ mod tests {
             max_memory_bytes: 1024,
             ttl: None,
             enable_metrics: true,
+            tracking_mode: TrackingMode::Default,
         });
         let stats = core.stats().unwrap();
         stats.record_hit();
@@ -923,11 +1048,95 @@ mod tests {
             max_memory_bytes: 1024,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         });
 
         assert!(core.stats().is_none());
     }
 
+    // ==================== Server-Assisted Invalidation ====================
+    fn make_tracked_cache() -> std::sync::Arc<dyn GlideCache> {
+        crate::cache::lru_cache::new_lru_cache(CacheConfig {
+            max_memory_bytes: 10_000,
+            ttl: None,
+            enable_metrics: true,
+            tracking_mode: TrackingMode::OptIn,
+        })
+    }
+
+    #[test]
+    fn test_tracking_mode_from_config() {
+        let cache = make_tracked_cache();
+        assert_eq!(cache.tracking_mode(), TrackingMode::OptIn);
+    }
+
+    #[test]
+    fn test_invalidate_keys() {
+        let cache = make_tracked_cache();
+        cache.insert(b"key1".to_vec(), CachedKeyType::String, Value::Int(1));
+        cache.insert(b"key2".to_vec(), CachedKeyType::String, Value::Int(2));
+
+        cache.invalidate_keys(&[b"key1".to_vec(), b"missing".to_vec()]);
+
+        assert!(cache.get(b"key1", CachedKeyType::String).is_none());
+        assert_eq!(
+            cache.get(b"key2", CachedKeyType::String),
+            Some(Value::Int(2))
+        );
+        assert_eq!(cache.metrics().unwrap().invalidations(), 1);
+    }
+
+    #[test]
+    fn test_invalidate_all() {
+        let cache = make_tracked_cache();
+        cache.insert(b"key1".to_vec(), CachedKeyType::String, Value::Int(1));
+        cache.insert(b"key2".to_vec(), CachedKeyType::Hash, Value::Int(2));
+
+        cache.invalidate_all();
+
+        assert_eq!(cache.entry_count(), 0);
+        assert_eq!(cache.core().current_memory(), 0);
+        assert_eq!(cache.metrics().unwrap().invalidations(), 2);
+    }
+
+    #[test]
+    fn test_insert_skipped_after_invalidation() {
+        let cache = make_tracked_cache();
+
+        let epoch = cache.invalidation_epoch();
+        // The key changed on the server while the read was in flight.
+        cache.invalidate(b"key1");
+        assert!(!cache.insert_if_not_invalidated(
+            b"key1".to_vec(),
+            CachedKeyType::String,
+            Value::Int(1),
+            epoch
+        ));
+        assert_eq!(cache.entry_count(), 0);
+
+        // Losing a connection that tracked no keys also drops the values read through it.
+        let epoch = cache.invalidation_epoch();
+        cache.invalidate_keys(&[]);
+        assert!(!cache.insert_if_not_invalidated(
+            b"key1".to_vec(),
+            CachedKeyType::String,
+            Value::Int(1),
+            epoch
+        ));
+
+        let epoch = cache.invalidation_epoch();
+        assert!(cache.insert_if_not_invalidated(
+            b"key1".to_vec(),
+            CachedKeyType::String,
+            Value::Int(1),
+            epoch
+        ));
+        assert_eq!(
+            cache.get(b"key1", CachedKeyType::String),
+            Some(Value::Int(1))
+        );
+    }
+
     // ==================== calculate_value_size ====================
 
     #[test]
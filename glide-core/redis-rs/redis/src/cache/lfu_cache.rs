@@ -190,6 +190,7 @@ pub fn new_lfu_cache(config: CacheConfig) -> Arc<dyn GlideCache> {
 #[cfg(test)]
 mod tests {
     use crate::cache::glide_cache::{CachedKeyType, GlideCache};
+    use crate::cache::TrackingMode;
     use crate::Value;
 
     use super::*;
@@ -201,6 +202,7 @@ mod tests {
             max_memory_bytes: max_memory,
             ttl: None,
             enable_metrics: true,
+            tracking_mode: TrackingMode::Default,
         }
     }
 
@@ -209,6 +211,7 @@ mod tests {
             max_memory_bytes: max_memory,
             ttl: Some(ttl),
             enable_metrics: true,
+            tracking_mode: TrackingMode::Default,
         }
     }
 
@@ -493,6 +496,7 @@ mod tests {
             max_memory_bytes: 10_000,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         };
         let cache = new_lfu_cache(config);
 
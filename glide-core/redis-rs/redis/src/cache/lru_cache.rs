@@ -59,6 +59,7 @@ pub fn new_lru_cache(config: CacheConfig) -> Arc<dyn GlideCache> {
 #[cfg(test)]
 mod tests {
     use crate::cache::glide_cache::CachedKeyType;
+    use crate::cache::TrackingMode;
     use crate::Value;
 
     use super::*;
@@ -70,6 +71,7 @@ mod tests {
             max_memory_bytes: max_memory,
             ttl: None,
             enable_metrics: true,
+            tracking_mode: TrackingMode::Default,
         }
     }
 
@@ -78,6 +80,7 @@ mod tests {
             max_memory_bytes: max_memory,
             ttl: Some(ttl),
             enable_metrics: true,
+            tracking_mode: TrackingMode::Default,
         }
     }
 
@@ -330,6 +333,7 @@ mod tests {
             max_memory_bytes: 10_000,
             ttl: None,
             enable_metrics: false,
+            tracking_mode: TrackingMode::Default,
         };
         let cache = new_lru_cache(config);
 
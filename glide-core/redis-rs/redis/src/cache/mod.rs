@@ -43,6 +43,18 @@ pub enum EvictionPolicy {
     Lfu,
 }
 
+/// Server-assisted invalidation mode, sent as `CLIENT TRACKING ON` on every connection that uses the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrackingMode {
+    /// The server remembers every key read by the connection and sends an `invalidate` push when it changes.
+    #[default]
+    Default,
+
+    /// The server only tracks keys read right after `CLIENT CACHING YES`.
+    /// The connection opts in for cacheable commands only, which keeps the server-side tracking table small.
+    OptIn,
+}
+
 /// Creates (or retrieves) a cache with the given ID.
 /// If the cache already exists, returns the existing one (new config is ignored).
 /// If it doesn't exist, creates a new one with the specified configuration.
@@ -53,6 +65,7 @@ pub enum EvictionPolicy {
 /// * `ttl_ms` - Time-to-live in milliseconds (0 = no expiration)
 /// * `eviction_policy` - Eviction policy (LRU or LFU, defaults to LRU)
 /// * `enable_metrics` - Whether to enable metrics tracking, such as hit/miss counts.
+/// * `tracking_mode` - Server-assisted invalidation mode (defaults to `TrackingMode::Default`)
 #[must_use]
 pub fn get_or_create_cache(
     cache_id: &str,
@@ -60,6 +73,7 @@ pub fn get_or_create_cache(
     ttl_ms: u64,
     eviction_policy: Option<EvictionPolicy>,
     enable_metrics: bool,
+    tracking_mode: Option<TrackingMode>,
 ) -> Arc<dyn GlideCache> {
     // Fast path: try to get existing cache with read lock
     if let Some(cache) = CACHE_REGISTRY
@@ -71,7 +85,8 @@ pub fn get_or_create_cache(
         debug!(
             "cache_lifetime - Cache `{cache_id}` already exists — returning existing instance. \
              New config parameters (max_cache_kb={max_cache_kb}, ttl_ms={ttl_ms}, \
-             eviction_policy={eviction_policy:?}, enable_metrics={enable_metrics}) are ignored. \
+             eviction_policy={eviction_policy:?}, enable_metrics={enable_metrics}, \
+             tracking_mode={tracking_mode:?}) are ignored. \
              Drop all references to recreate with different config."
         );
         return cache;
@@ -98,6 +113,7 @@ pub fn get_or_create_cache(
             None
         },
         enable_metrics,
+        tracking_mode: tracking_mode.unwrap_or_default(),
     };
 
     // Create cache based on eviction policy
@@ -204,30 +220,42 @@ mod tests {
 
     #[tokio::test]
     async fn test_create_lru_cache() {
-        let cache =
-            get_or_create_cache("test_lru_cache", 1024, 0, Some(EvictionPolicy::Lru), false);
+        let cache = get_or_create_cache(
+            "test_lru_cache",
+            1024,
+            0,
+            Some(EvictionPolicy::Lru),
+            false,
+            None,
+        );
         assert_eq!(cache.entry_count(), 0);
         cleanup_cache("test_lru_cache");
     }
 
     #[tokio::test]
     async fn test_create_lfu_cache() {
-        let cache =
-            get_or_create_cache("test_lfu_cache", 1024, 0, Some(EvictionPolicy::Lfu), false);
+        let cache = get_or_create_cache(
+            "test_lfu_cache",
+            1024,
+            0,
+            Some(EvictionPolicy::Lfu),
+            false,
+            None,
+        );
         assert_eq!(cache.entry_count(), 0);
         cleanup_cache("test_lfu_cache");
     }
 
     #[tokio::test]
     async fn test_create_cache_with_metrics() {
-        let cache = get_or_create_cache("test_metrics_cache", 1024, 0, None, true);
+        let cache = get_or_create_cache("test_metrics_cache", 1024, 0, None, true, None);
         assert!(cache.metrics().is_ok());
         cleanup_cache("test_metrics_cache");
     }
 
     #[tokio::test]
     async fn test_create_cache_without_metrics() {
-        let cache = get_or_create_cache("test_no_metrics_cache", 1024, 0, None, false);
+        let cache = get_or_create_cache("test_no_metrics_cache", 1024, 0, None, false, None);
         assert!(cache.metrics().is_err());
         cleanup_cache("test_no_metrics_cache");
     }
@@ -235,8 +263,15 @@ mod tests {
     #[tokio::test]
     async fn test_get_existing_cache() {
         let cache_id = "test_get_existing";
-        let cache1 = get_or_create_cache(cache_id, 1024, 0, None, false);
-        let cache2 = get_or_create_cache(cache_id, 2048, 30000, Some(EvictionPolicy::Lfu), true);
+        let cache1 = get_or_create_cache(cache_id, 1024, 0, None, false, None);
+        let cache2 = get_or_create_cache(
+            cache_id,
+            2048,
+            30000,
+            Some(EvictionPolicy::Lfu),
+            true,
+            Some(TrackingMode::OptIn),
+        );
 
         assert!(Arc::ptr_eq(&cache1, &cache2));
         cleanup_cache(cache_id);
@@ -244,8 +279,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_different_cache_ids_create_different_caches() {
-        let cache1 = get_or_create_cache("test_diff_1", 1024, 0, None, false);
-        let cache2 = get_or_create_cache("test_diff_2", 1024, 0, None, false);
+        let cache1 = get_or_create_cache("test_diff_1", 1024, 0, None, false, None);
+        let cache2 = get_or_create_cache("test_diff_2", 1024, 0, None, false, None);
 
         assert!(!Arc::ptr_eq(&cache1, &cache2));
         cleanup_cache("test_diff_1");
@@ -259,7 +294,7 @@ mod tests {
         let cache_id = "test_registered";
         let exists_before = CACHE_REGISTRY.read().unwrap().contains_key(cache_id);
 
-        let _cache = get_or_create_cache(cache_id, 1024, 0, None, false);
+        let _cache = get_or_create_cache(cache_id, 1024, 0, None, false, None);
 
         let exists_after = CACHE_REGISTRY.read().unwrap().contains_key(cache_id);
 
@@ -271,7 +306,7 @@ mod tests {
     #[tokio::test]
     async fn test_weak_reference_upgrades_while_cache_alive() {
         let cache_id = "test_weak_upgrade";
-        let cache = get_or_create_cache(cache_id, 1024, 0, None, false);
+        let cache = get_or_create_cache(cache_id, 1024, 0, None, false, None);
 
         let upgraded = CACHE_REGISTRY
             .read()
@@ -288,11 +323,11 @@ mod tests {
     async fn test_cache_recreated_after_drop() {
         let cache_id = "test_recreate";
 
-        let cache1 = get_or_create_cache(cache_id, 1024, 0, None, false);
+        let cache1 = get_or_create_cache(cache_id, 1024, 0, None, false, None);
         assert!(cache1.metrics().is_err());
         drop(cache1);
 
-        let cache2 = get_or_create_cache(cache_id, 1024, 0, None, true);
+        let cache2 = get_or_create_cache(cache_id, 1024, 0, None, true, None);
         assert!(cache2.metrics().is_ok());
         cleanup_cache(cache_id);
     }
@@ -304,7 +339,7 @@ mod tests {
         use crate::Value;
         use glide_cache::CachedKeyType;
 
-        let cache = get_or_create_cache("test_operations", 10_000, 0, None, false);
+        let cache = get_or_create_cache("test_operations", 10_000, 0, None, false, None);
 
         cache.insert(
             b"key1".to_vec(),
@@ -391,6 +426,7 @@ mod tests {
             0,
             Some(EvictionPolicy::Lru),
             true,
+            None,
         );
         run_concurrent_cache_test(cache);
         cleanup_cache("test_concurrent_lru");
@@ -404,6 +440,7 @@ mod tests {
             0,
             Some(EvictionPolicy::Lfu),
             true,
+            None,
         );
         run_concurrent_cache_test(cache);
         cleanup_cache("test_concurrent_lfu");
@@ -0,0 +1,482 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::types::{ErrorKind, RedisError, RedisResult};
+use logger_core::{log_error, log_info, log_warn};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use telemetrylib::{GlideOpenTelemetry, Telemetry};
+
+/// Configuration of the per-node circuit breaker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Percentage of failed requests in a window that opens the circuit of a node.
+    pub failure_rate_percent: u32,
+    /// Minimum number of requests in a window before its failure rate is considered.
+    pub minimum_requests: u32,
+    /// Length of the window the failure rate is measured over.
+    pub window: Duration,
+    /// How long an open circuit fails the requests to its node before letting probe requests through.
+    pub open_duration: Duration,
+    /// Number of probe requests that must succeed to close a half-open circuit.
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_rate_percent: 50,
+            minimum_requests: 20,
+            window: Duration::from_secs(10),
+            open_duration: Duration::from_secs(5),
+            half_open_probes: 3,
+        }
+    }
+}
+
+/// State of the circuit of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent to the node.
+    Closed,
+    /// Requests to the node fail immediately.
+    Open,
+    /// A limited number of probe requests are sent to the node, to check whether it recovered.
+    HalfOpen,
+}
+
+impl CircuitState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Returns true for the errors that indicate that the node itself is unhealthy, as opposed to errors about the request.
+pub fn is_node_failure(err: &RedisError) -> bool {
+    err.is_io_error()
+        || err.is_timeout()
+        || err.is_connection_dropped()
+        || err.is_connection_refusal()
+        || matches!(
+            err.kind(),
+            ErrorKind::BusyLoadingError | ErrorKind::FatalSendError | ErrorKind::FatalReceiveError
+        )
+}
+
+#[derive(Debug)]
+struct NodeCircuit {
+    state: CircuitState,
+    /// When the current window, open period or half-open period started.
+    since: Instant,
+    requests: u32,
+    failures: u32,
+    probes_sent: u32,
+    probes_succeeded: u32,
+}
+
+impl NodeCircuit {
+    fn new(now: Instant) -> Self {
+        Self {
+            state: CircuitState::Closed,
+            since: now,
+            requests: 0,
+            failures: 0,
+            probes_sent: 0,
+            probes_succeeded: 0,
+        }
+    }
+}
+
+/// Tracks the failure rate of the requests to each node, and fails the requests to the nodes whose circuit is open.
+///
+/// A node's circuit opens once the failure rate of its requests in a window reaches the configured threshold.
+/// After `open_duration` the circuit turns half-open and lets a few probe requests through: it closes once they all
+/// succeed, and opens again on the first failure.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: Mutex<HashMap<String, NodeCircuit>>,
+}
+
+impl CircuitBreaker {
+    /// Creates a circuit breaker where all the circuits are closed.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: Default::default(),
+        }
+    }
+
+    /// Returns the state of the circuit of `address`.
+    pub fn state(&self, address: &str) -> CircuitState {
+        self.circuits
+            .lock()
+            .unwrap()
+            .get(address)
+            .map_or(CircuitState::Closed, |circuit| circuit.state)
+    }
+
+    /// Returns true if a request to `address` would currently be failed, so reads can be sent to another node instead.
+    pub fn is_open(&self, address: &str) -> bool {
+        self.circuits
+            .lock()
+            .unwrap()
+            .get(address)
+            .is_some_and(|circuit| !self.allows_request(circuit))
+    }
+
+    /// Drops the circuit of `address`, e.g. after the node was removed.
+    pub fn remove(&self, address: &str) {
+        let removed = self.circuits.lock().unwrap().remove(address);
+        if removed.is_some_and(|circuit| circuit.state != CircuitState::Closed) {
+            Telemetry::decr_open_circuit_breakers(1);
+        }
+    }
+
+    fn allows_request(&self, circuit: &NodeCircuit) -> bool {
+        match circuit.state {
+            CircuitState::Closed => true,
+            CircuitState::HalfOpen if circuit.probes_sent < self.config.half_open_probes => true,
+            _ => circuit.since.elapsed() >= self.config.open_duration,
+        }
+    }
+
+    /// Asks for permission to send a request to `address`. Fails with [`ErrorKind::CircuitOpen`] if the circuit of
+    /// the node is open.
+    pub fn try_acquire(self: &Arc<Self>, address: &str) -> RedisResult<CircuitBreakerPermit> {
+        let mut circuits = self.circuits.lock().unwrap();
+        let now = Instant::now();
+        let circuit = circuits
+            .entry(address.to_string())
+            .or_insert_with(|| NodeCircuit::new(now));
+
+        let probe = match circuit.state {
+            CircuitState::Closed => false,
+            // An open circuit starts probing once its open period ends. A half-open circuit whose probes didn't all
+            // complete in that time starts probing again.
+            _ if now.duration_since(circuit.since) >= self.config.open_duration => {
+                Self::transition(address, circuit, CircuitState::HalfOpen, now);
+                true
+            }
+            CircuitState::HalfOpen if circuit.probes_sent < self.config.half_open_probes => true,
+            _ => {
+                return Err(RedisError::from((
+                    ErrorKind::CircuitOpen,
+                    "Circuit breaker is open",
+                    format!("Requests to {address} fail until the node recovers"),
+                )));
+            }
+        };
+        if probe {
+            circuit.probes_sent += 1;
+        }
+
+        Ok(CircuitBreakerPermit {
+            breaker: self.clone(),
+            address: address.to_string(),
+            probe,
+            recorded: false,
+        })
+    }
+
+    fn record(&self, address: &str, probe: bool, failed: bool) {
+        let mut circuits = self.circuits.lock().unwrap();
+        let Some(circuit) = circuits.get_mut(address) else {
+            return;
+        };
+        let now = Instant::now();
+
+        match circuit.state {
+            CircuitState::Closed => {
+                if now.duration_since(circuit.since) >= self.config.window {
+                    circuit.since = now;
+                    circuit.requests = 0;
+                    circuit.failures = 0;
+                }
+                circuit.requests += 1;
+                if failed {
+                    circuit.failures += 1;
+                }
+                if circuit.requests >= self.config.minimum_requests
+                    && circuit.failures as u64 * 100
+                        >= self.config.failure_rate_percent as u64 * circuit.requests as u64
+                {
+                    Self::transition(address, circuit, CircuitState::Open, now);
+                }
+            }
+            CircuitState::HalfOpen if probe => {
+                if failed {
+                    Self::transition(address, circuit, CircuitState::Open, now);
+                } else {
+                    circuit.probes_succeeded += 1;
+                    if circuit.probes_succeeded >= self.config.half_open_probes {
+                        Self::transition(address, circuit, CircuitState::Closed, now);
+                    }
+                }
+            }
+            // Requests that were sent before the circuit opened don't affect it.
+            _ => {}
+        }
+    }
+
+    /// Gives back the probe slot of a permit whose request was cancelled before completing.
+    fn release(&self, address: &str, probe: bool) {
+        let mut circuits = self.circuits.lock().unwrap();
+        if let Some(circuit) = circuits.get_mut(address) {
+            if probe && circuit.state == CircuitState::HalfOpen {
+                circuit.probes_sent = circuit.probes_sent.saturating_sub(1);
+            }
+        }
+    }
+
+    fn transition(address: &str, circuit: &mut NodeCircuit, state: CircuitState, now: Instant) {
+        let previous_state = circuit.state;
+        *circuit = NodeCircuit {
+            state,
+            ..NodeCircuit::new(now)
+        };
+        if previous_state == state {
+            return;
+        }
+
+        match state {
+            CircuitState::Open => {
+                log_warn(
+                    "circuit breaker",
+                    format!("Circuit of {address} opened, requests to it will fail fast"),
+                );
+                Telemetry::incr_circuit_breaker_opened();
+            }
+            CircuitState::HalfOpen => log_info(
+                "circuit breaker",
+                format!("Circuit of {address} is half-open, probing the node"),
+            ),
+            CircuitState::Closed => log_info(
+                "circuit breaker",
+                format!("Circuit of {address} closed, the node recovered"),
+            ),
+        }
+        match (previous_state, state) {
+            (CircuitState::Closed, _) => {
+                Telemetry::incr_open_circuit_breakers(1);
+            }
+            (_, CircuitState::Closed) => {
+                Telemetry::decr_open_circuit_breakers(1);
+            }
+            _ => {}
+        }
+        if let Err(e) =
+            GlideOpenTelemetry::record_circuit_breaker_state_change(address, state.as_str())
+        {
+            log_error(
+                "OpenTelemetry:circuit_breaker",
+                format!("Failed to record circuit breaker state change: {e}"),
+            );
+        }
+    }
+}
+
+impl Drop for CircuitBreaker {
+    fn drop(&mut self) {
+        let open_circuits = self
+            .circuits
+            .get_mut()
+            .map(|circuits| {
+                circuits
+                    .values()
+                    .filter(|circuit| circuit.state != CircuitState::Closed)
+                    .count()
+            })
+            .unwrap_or_default();
+        Telemetry::decr_open_circuit_breakers(open_circuits);
+    }
+}
+
+/// Permission to send a single request to a node, given by [`CircuitBreaker::try_acquire`].
+///
+/// The outcome of the request is reported with [`CircuitBreakerPermit::record`]. A permit that is dropped without
+/// an outcome, e.g. because the request timed out and was cancelled, counts as a failure. A request that was
+/// abandoned deliberately, e.g. the losing request of a hedged read, is released with
+/// [`CircuitBreakerPermit::cancel`] instead, so it doesn't affect the circuit.
+#[derive(Debug)]
+pub struct CircuitBreakerPermit {
+    breaker: Arc<CircuitBreaker>,
+    address: String,
+    probe: bool,
+    recorded: bool,
+}
+
+impl CircuitBreakerPermit {
+    /// Records the outcome of the request.
+    pub fn record<T>(mut self, result: &RedisResult<T>) {
+        self.recorded = true;
+        let failed = result.as_ref().is_err_and(is_node_failure);
+        self.breaker.record(&self.address, self.probe, failed);
+    }
+
+    /// Releases the permit without recording an outcome, for a request that was abandoned deliberately.
+    pub fn cancel(mut self) {
+        self.recorded = true;
+        self.breaker.release(&self.address, self.probe);
+    }
+}
+
+impl Drop for CircuitBreakerPermit {
+    fn drop(&mut self) {
+        if !self.recorded {
+            self.breaker.record(&self.address, self.probe, true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODE: &str = "node1:6379";
+
+    fn breaker(open_duration: Duration) -> Arc<CircuitBreaker> {
+        Arc::new(CircuitBreaker::new(CircuitBreakerConfig {
+            failure_rate_percent: 50,
+            minimum_requests: 4,
+            window: Duration::from_secs(60),
+            open_duration,
+            half_open_probes: 2,
+        }))
+    }
+
+    fn io_error() -> RedisResult<()> {
+        Err(std::io::Error::from(std::io::ErrorKind::ConnectionReset).into())
+    }
+
+    fn send(breaker: &Arc<CircuitBreaker>, result: RedisResult<()>) {
+        breaker.try_acquire(NODE).unwrap().record(&result);
+    }
+
+    #[test]
+    fn test_circuit_opens_after_failure_rate_is_reached() {
+        let breaker = breaker(Duration::from_secs(60));
+        send(&breaker, Ok(()));
+        send(&breaker, io_error());
+        send(&breaker, Ok(()));
+        assert_eq!(breaker.state(NODE), CircuitState::Closed);
+
+        // 2 failures out of 4 requests reach the 50% threshold.
+        send(&breaker, io_error());
+        assert_eq!(breaker.state(NODE), CircuitState::Open);
+        assert!(breaker.is_open(NODE));
+        let err = breaker.try_acquire(NODE).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CircuitOpen);
+        assert!(!breaker.is_open("node2:6379"));
+    }
+
+    #[test]
+    fn test_removed_circuit_starts_closed() {
+        let breaker = breaker(Duration::from_secs(60));
+        for _ in 0..4 {
+            send(&breaker, io_error());
+        }
+        assert!(breaker.is_open(NODE));
+
+        breaker.remove(NODE);
+        assert_eq!(breaker.state(NODE), CircuitState::Closed);
+        assert!(breaker.try_acquire(NODE).is_ok());
+    }
+
+    #[test]
+    fn test_request_errors_do_not_open_the_circuit() {
+        let breaker = breaker(Duration::from_secs(60));
+        for _ in 0..10 {
+            send(
+                &breaker,
+                Err(RedisError::from((ErrorKind::TypeError, "wrong type"))),
+            );
+        }
+        assert_eq!(breaker.state(NODE), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_dropped_permit_counts_as_failure() {
+        let breaker = breaker(Duration::from_secs(60));
+        for _ in 0..4 {
+            drop(breaker.try_acquire(NODE).unwrap());
+        }
+        assert_eq!(breaker.state(NODE), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_cancelled_permit_is_not_counted() {
+        let breaker = breaker(Duration::from_secs(60));
+        for _ in 0..4 {
+            breaker.try_acquire(NODE).unwrap().cancel();
+        }
+        assert_eq!(breaker.state(NODE), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_cancelled_probe_frees_its_slot() {
+        let breaker = breaker(Duration::from_millis(50));
+        for _ in 0..4 {
+            send(&breaker, io_error());
+        }
+        std::thread::sleep(Duration::from_millis(60));
+
+        breaker.try_acquire(NODE).unwrap().cancel();
+        breaker.try_acquire(NODE).unwrap().cancel();
+        let first_probe = breaker.try_acquire(NODE).unwrap();
+        let second_probe = breaker.try_acquire(NODE).unwrap();
+        assert_eq!(breaker.state(NODE), CircuitState::HalfOpen);
+        first_probe.record(&Ok::<(), RedisError>(()));
+        second_probe.record(&Ok::<(), RedisError>(()));
+        assert_eq!(breaker.state(NODE), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_circuit_closes_after_successful_probes() {
+        let breaker = breaker(Duration::ZERO);
+        for _ in 0..4 {
+            send(&breaker, io_error());
+        }
+        assert_eq!(breaker.state(NODE), CircuitState::Open);
+
+        let first_probe = breaker.try_acquire(NODE).unwrap();
+        assert_eq!(breaker.state(NODE), CircuitState::HalfOpen);
+        let second_probe = breaker.try_acquire(NODE).unwrap();
+        first_probe.record(&Ok::<(), RedisError>(()));
+        assert_eq!(breaker.state(NODE), CircuitState::HalfOpen);
+        second_probe.record(&Ok::<(), RedisError>(()));
+        assert_eq!(breaker.state(NODE), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_circuit_reopens_on_failed_probe() {
+        let breaker = breaker(Duration::ZERO);
+        for _ in 0..4 {
+            send(&breaker, io_error());
+        }
+
+        send(&breaker, io_error());
+        assert_eq!(breaker.state(NODE), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_half_open_circuit_limits_probes() {
+        let breaker = breaker(Duration::from_millis(50));
+        for _ in 0..4 {
+            send(&breaker, io_error());
+        }
+        std::thread::sleep(Duration::from_millis(60));
+
+        let _first_probe = breaker.try_acquire(NODE).unwrap();
+        let _second_probe = breaker.try_acquire(NODE).unwrap();
+        assert!(breaker.is_open(NODE));
+        assert_eq!(
+            breaker.try_acquire(NODE).unwrap_err().kind(),
+            ErrorKind::CircuitOpen
+        );
+    }
+}
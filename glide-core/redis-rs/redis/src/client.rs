@@ -9,9 +9,9 @@ use crate::{
     retry_strategies::RetryStrategy,
     types::{ProtocolVersion, RedisResult, Value},
 };
+use std::collections::HashSet;
 #[cfg(feature = "aio")]
 use std::net::IpAddr;
-#[cfg(feature = "aio")]
 use std::net::SocketAddr;
 #[cfg(feature = "aio")]
 use std::pin::Pin;
@@ -109,6 +109,9 @@ pub struct GlideConnectionOptions {
     /// The callback should return `Some(token)` if a valid token is available,
     /// or `None` if token retrieval failed.
     pub iam_token_provider: Option<Arc<dyn IAMTokenProvider>>,
+    /// Optional resolver of the hostnames that connections are opened to.
+    /// When `None`, the hostnames are resolved by the system resolver.
+    pub dns_resolver: Option<Arc<dyn DnsResolver>>,
 }
 
 /// Trait for providing IAM tokens to the reconnection path.
@@ -119,6 +122,27 @@ pub trait IAMTokenProvider: Send + Sync {
     async fn get_valid_token(&self) -> Option<String>;
 }
 
+/// Trait for resolving the hostnames that connections are opened to.
+/// Allows replacing the system resolver, e.g. with a fake one in tests.
+#[async_trait::async_trait]
+pub trait DnsResolver: Send + Sync {
+    /// Returns the socket addresses of `host`, in the order they should be tried.
+    async fn resolve(&self, host: &str, port: u16) -> RedisResult<Vec<SocketAddr>>;
+}
+
+impl std::fmt::Debug for dyn DnsResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DnsResolver")
+    }
+}
+
+/// Trait for being notified of the nodes of a cluster after its topology is refreshed.
+/// Allows releasing the resources kept for nodes that left the cluster.
+pub trait TopologyListener: Send + Sync {
+    /// Called with the `host:port` addresses of all the nodes in the refreshed topology.
+    fn on_topology_refresh(&self, addresses: &HashSet<Arc<String>>);
+}
+
 /// To enable async support you need to enable the feature: `tokio-comp`
 #[cfg(feature = "aio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
@@ -139,7 +163,7 @@ impl Client {
                 // Note: tcp_nodelay is hardcoded to true (default) since this deprecated API
                 // doesn't accept GlideConnectionOptions. Modern code should use
                 // get_multiplexed_async_connection which allows configuring tcp_nodelay.
-                self.get_simple_async_connection::<crate::aio::tokio::Tokio>(None, true)
+                self.get_simple_async_connection::<crate::aio::tokio::Tokio>(None, true, None)
                     .await?
             }
         };
@@ -452,7 +476,11 @@ impl Client {
         T: crate::aio::RedisRuntime,
     {
         let (con, ip) = self
-            .get_simple_async_connection::<T>(socket_addr, glide_connection_options.tcp_nodelay)
+            .get_simple_async_connection::<T>(
+                socket_addr,
+                glide_connection_options.tcp_nodelay,
+                glide_connection_options.dns_resolver.as_deref(),
+            )
             .await?;
         crate::aio::MultiplexedConnection::new_with_response_timeout(
             &self.connection_info,
@@ -468,6 +496,7 @@ impl Client {
         &self,
         socket_addr: Option<SocketAddr>,
         tcp_nodelay: bool,
+        dns_resolver: Option<&dyn DnsResolver>,
     ) -> RedisResult<(
         Pin<Box<dyn crate::aio::AsyncStream + Send + Sync>>,
         Option<IpAddr>,
@@ -475,9 +504,13 @@ impl Client {
     where
         T: crate::aio::RedisRuntime,
     {
-        let (conn, ip) =
-            crate::aio::connect_simple::<T>(&self.connection_info, socket_addr, tcp_nodelay)
-                .await?;
+        let (conn, ip) = crate::aio::connect_simple::<T>(
+            &self.connection_info,
+            socket_addr,
+            tcp_nodelay,
+            dns_resolver,
+        )
+        .await?;
         Ok((conn.boxed(), ip))
     }
 
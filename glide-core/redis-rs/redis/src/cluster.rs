@@ -35,7 +35,7 @@
 //!     .expire(key, 60).ignore()
 //!     .query(&mut connection).unwrap();
 //! ```
-pub use crate::cluster_client::{ClusterClient, ClusterClientBuilder};
+pub use crate::cluster_client::{ClusterClient, ClusterClientBuilder, ConnectionSelectionStrategy};
 use crate::cluster_pipeline::UNROUTABLE_ERROR;
 pub use crate::cluster_pipeline::{cluster_pipe, ClusterPipeline};
 use crate::cluster_routing::{
@@ -1,14 +1,19 @@
+use crate::aio::ConnectionLike;
+use crate::circuit_breaker::CircuitBreaker;
 use crate::cluster_async::ConnectionFuture;
+use crate::cluster_client::ConnectionSelectionStrategy;
 use crate::cluster_routing::{Route, ShardAddrs, SlotAddr};
 use crate::cluster_slotmap::{ReadFromReplicaStrategy, SlotMap, SlotMapValue};
 use crate::cluster_topology::TopologyHash;
+use crate::latency_tracker::LatencyTracker;
 use dashmap::DashMap;
 use futures::FutureExt;
 use rand::seq::IteratorRandom;
 use std::collections::HashMap;
 use std::net::IpAddr;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use telemetrylib::Telemetry;
 
 use tracing::debug;
@@ -21,11 +26,10 @@ macro_rules! count_connections {
     ($conn_map:expr) => {{
         let mut count = 0usize;
         for a in $conn_map {
-            count = count.saturating_add(if a.management_connection.is_some() {
-                2
-            } else {
-                1
-            });
+            count = count.saturating_add(
+                1 + a.additional_user_connections.len()
+                    + usize::from(a.management_connection.is_some()),
+            );
         }
         count
     }};
@@ -78,12 +82,44 @@ impl<Connection> From<ConnectionDetails<Connection>>
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Debug)]
+/// Reports how many requests sent on a connection are still waiting for their response.
+/// Used by [`ConnectionSelectionStrategy::LeastPending`].
+pub(crate) trait PendingRequests {
+    fn pending_requests(&self) -> usize;
+}
+
+impl<C> PendingRequests for ConnectionFuture<C>
+where
+    C: ConnectionLike + Clone,
+{
+    fn pending_requests(&self) -> usize {
+        // A connection that is still being established can't serve requests yet, so it's picked last.
+        self.peek()
+            .map_or(usize::MAX, |connection| connection.pending_requests())
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct ClusterNode<Connection> {
+    /// The first user connection. Subscriptions are always sent on it, so push notifications arrive on a single connection.
     pub user_connection: ConnectionDetails<Connection>,
     pub management_connection: Option<ConnectionDetails<Connection>>,
+    /// User connections opened in addition to `user_connection`, when more than one connection per node is configured.
+    pub additional_user_connections: Vec<ConnectionDetails<Connection>>,
+    // Index of the last user connection picked by the round robin selection, shared between clones of the node.
+    last_used_user_connection: Arc<AtomicUsize>,
 }
 
+impl<Connection: PartialEq> PartialEq for ClusterNode<Connection> {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_connection == other.user_connection
+            && self.management_connection == other.management_connection
+            && self.additional_user_connections == other.additional_user_connections
+    }
+}
+
+impl<Connection: Eq> Eq for ClusterNode<Connection> {}
+
 impl<Connection> ClusterNode<Connection>
 where
     Connection: Clone,
@@ -95,18 +131,37 @@ where
         Self {
             user_connection,
             management_connection,
+            additional_user_connections: Vec::new(),
+            last_used_user_connection: Default::default(),
         }
     }
 
+    /// Sets the user connections opened in addition to `user_connection`.
+    pub fn with_additional_user_connections(
+        mut self,
+        additional_user_connections: Vec<ConnectionDetails<Connection>>,
+    ) -> Self {
+        self.additional_user_connections = additional_user_connections;
+        self
+    }
+
     /// Return the number of underlying connections managed by this instance of ClusterNode
     pub fn connections_count(&self) -> usize {
-        if self.management_connection.is_some() {
-            2
-        } else {
-            1
-        }
+        self.user_connections_count() + usize::from(self.management_connection.is_some())
+    }
+
+    /// Return the number of user connections managed by this instance of ClusterNode
+    pub fn user_connections_count(&self) -> usize {
+        1 + self.additional_user_connections.len()
     }
 
+    /// Returns all the user connections of the node, starting with `user_connection`.
+    pub fn user_connections(&self) -> impl Iterator<Item = &ConnectionDetails<Connection>> {
+        std::iter::once(&self.user_connection).chain(self.additional_user_connections.iter())
+    }
+
+    /// Returns the connection of the given type. User connections are served by the first user connection; use
+    /// [`Self::select_user_connection`] to spread them over all the user connections.
     pub(crate) fn get_connection(&self, conn_type: &ConnectionType) -> Connection {
         match conn_type {
             ConnectionType::User => self.user_connection.conn.clone(),
@@ -116,6 +171,35 @@ where
             ),
         }
     }
+
+    /// Picks one of the node's user connections according to `strategy`.
+    pub(crate) fn select_user_connection(
+        &self,
+        strategy: ConnectionSelectionStrategy,
+    ) -> &ConnectionDetails<Connection>
+    where
+        Connection: PendingRequests,
+    {
+        if self.additional_user_connections.is_empty() {
+            return &self.user_connection;
+        }
+        match strategy {
+            ConnectionSelectionStrategy::RoundRobin => {
+                let index = self
+                    .last_used_user_connection
+                    .fetch_add(1, Ordering::Relaxed)
+                    .wrapping_add(1)
+                    % self.user_connections_count();
+                self.user_connections()
+                    .nth(index)
+                    .unwrap_or(&self.user_connection)
+            }
+            ConnectionSelectionStrategy::LeastPending => self
+                .user_connections()
+                .min_by_key(|connection| connection.conn.pending_requests())
+                .unwrap_or(&self.user_connection),
+        }
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -290,12 +374,33 @@ impl RefreshConnectionStates {
     }
 }
 
+/// Picks one of the node's user connections according to the strategy.
+type UserConnectionSelector<Connection> = for<'a> fn(
+    &'a ClusterNode<Connection>,
+    ConnectionSelectionStrategy,
+) -> &'a ConnectionDetails<Connection>;
+
+// Selector of containers that weren't created with a connection selection strategy.
+fn first_user_connection<Connection>(
+    node: &ClusterNode<Connection>,
+    _strategy: ConnectionSelectionStrategy,
+) -> &ConnectionDetails<Connection> {
+    &node.user_connection
+}
+
 pub(crate) struct ConnectionsContainer<Connection> {
     connection_map: DashMap<String, ClusterNode<Connection>>,
     pub(crate) slot_map: SlotMap,
     read_from_replica_strategy: ReadFromReplicaStrategy,
     topology_hash: TopologyHash,
     pub(crate) refresh_conn_state: RefreshConnectionStates,
+    /// Round-trip times of the nodes, shared across topology refreshes.
+    pub(crate) latency_tracker: Arc<LatencyTracker>,
+    connection_selection_strategy: ConnectionSelectionStrategy,
+    // Set by `new`, where the connection type is known to report its pending requests.
+    select_user_connection: UserConnectionSelector<Connection>,
+    /// Circuit breaker of the nodes, shared across topology refreshes.
+    pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
 }
 
 impl<Connection> Drop for ConnectionsContainer<Connection> {
@@ -313,21 +418,30 @@ impl<Connection> Default for ConnectionsContainer<Connection> {
             read_from_replica_strategy: ReadFromReplicaStrategy::AlwaysFromPrimary,
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            latency_tracker: Default::default(),
+            connection_selection_strategy: Default::default(),
+            select_user_connection: first_user_connection,
+            circuit_breaker: None,
         }
     }
 }
 
 pub(crate) type ConnectionAndAddress<Connection> = (String, Connection);
 
+// Creating a container needs the connections' pending requests, to support
+// `ConnectionSelectionStrategy::LeastPending`. Everything else only needs `Connection: Clone`.
 impl<Connection> ConnectionsContainer<Connection>
 where
-    Connection: Clone,
+    Connection: Clone + PendingRequests,
 {
     pub(crate) fn new(
         slot_map: SlotMap,
         connection_map: ConnectionsMap<Connection>,
         read_from_replica_strategy: ReadFromReplicaStrategy,
         topology_hash: TopologyHash,
+        latency_tracker: Arc<LatencyTracker>,
+        connection_selection_strategy: ConnectionSelectionStrategy,
+        circuit_breaker: Option<Arc<CircuitBreaker>>,
     ) -> Self {
         let connection_map = connection_map.0;
 
@@ -341,9 +455,18 @@ where
             read_from_replica_strategy,
             topology_hash,
             refresh_conn_state: Default::default(),
+            latency_tracker,
+            connection_selection_strategy,
+            select_user_connection: ClusterNode::select_user_connection,
+            circuit_breaker,
         }
     }
+}
 
+impl<Connection> ConnectionsContainer<Connection>
+where
+    Connection: Clone,
+{
     /// Returns an iterator over the nodes in the `slot_map`, yielding tuples of
     /// (node address, (optional IP address, shard addresses)).
     pub(crate) fn slot_map_nodes(
@@ -374,6 +497,13 @@ where
             .and_then(|item| item.value().user_connection.az.clone())
     }
 
+    fn select_user_connection<'a>(
+        &self,
+        node: &'a ClusterNode<Connection>,
+    ) -> &'a ConnectionDetails<Connection> {
+        (self.select_user_connection)(node, self.connection_selection_strategy)
+    }
+
     /// Returns true if the address represents a known primary node.
     pub(crate) fn is_primary(&self, address: &String) -> bool {
         self.connection_for_address(address).is_some() && self.slot_map.is_primary(address)
@@ -518,6 +648,41 @@ where
         self.round_robin_read_from_replica(slot_map_value)
     }
 
+    /// Returns the connection of the replica with the lowest measured latency.
+    /// The previously selected replica is kept unless another one is faster by more than `hysteresis`.
+    /// Falls back to round robin if no connected replica was measured yet.
+    fn read_from_lowest_latency_replica(
+        &self,
+        slot_map_value: &SlotMapValue,
+        hysteresis: Duration,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let replicas = slot_map_value.addrs.replicas();
+        let current_index =
+            slot_map_value.last_used_replica.load(Ordering::Relaxed) % replicas.len();
+
+        let selected = self
+            .latency_tracker
+            .select_lowest(
+                replicas.iter().map(|replica| replica.as_str()),
+                Some(current_index),
+                hysteresis,
+            )
+            .and_then(|index| {
+                self.connection_for_address(replicas[index].as_str())
+                    .map(|connection| (index, connection))
+            });
+
+        match selected {
+            Some((index, connection)) => {
+                slot_map_value
+                    .last_used_replica
+                    .store(index, Ordering::Relaxed);
+                Some(connection)
+            }
+            None => self.round_robin_read_from_replica(slot_map_value),
+        }
+    }
+
     fn lookup_route(&self, route: &Route) -> Option<ConnectionAndAddress<Connection>> {
         let slot_map_value = self.slot_map.slot_value_for_route(route)?;
         let addrs = &slot_map_value.addrs;
@@ -549,6 +714,9 @@ where
                         slot_map_value,
                         az.to_string(),
                     ),
+                ReadFromReplicaStrategy::LowestLatency(hysteresis) => {
+                    self.read_from_lowest_latency_replica(slot_map_value, *hysteresis)
+                }
             },
             // when the user strategy per command is replica_preffered
             SlotAddr::ReplicaRequired => match &self.read_from_replica_strategy {
@@ -562,6 +730,9 @@ where
                         slot_map_value,
                         az.to_string(),
                     ),
+                ReadFromReplicaStrategy::LowestLatency(hysteresis) => {
+                    self.read_from_lowest_latency_replica(slot_map_value, *hysteresis)
+                }
                 _ => self.round_robin_read_from_replica(slot_map_value),
             },
         }
@@ -571,13 +742,80 @@ where
         &self,
         route: &Route,
     ) -> Option<ConnectionAndAddress<Connection>> {
-        self.lookup_route(route).or_else(|| {
+        let connection = self.lookup_route(route).or_else(|| {
             if route.slot_addr() != SlotAddr::Master {
                 self.lookup_route(&Route::new(route.slot(), SlotAddr::Master))
             } else {
                 None
             }
-        })
+        })?;
+        if self.is_circuit_open(&connection.0) {
+            if let Some(connection) = self.read_connection_with_closed_circuit(route) {
+                return Some(connection);
+            }
+        }
+        Some(connection)
+    }
+
+    fn is_circuit_open(&self, address: &str) -> bool {
+        self.circuit_breaker
+            .as_ref()
+            .is_some_and(|circuit_breaker| circuit_breaker.is_open(address))
+    }
+
+    /// Returns true if the route is a read that the read strategy allows to serve from replicas.
+    fn reads_from_replicas(&self, route: &Route) -> bool {
+        match route.slot_addr() {
+            SlotAddr::Master => false,
+            SlotAddr::ReplicaOptional => !matches!(
+                self.read_from_replica_strategy,
+                ReadFromReplicaStrategy::AlwaysFromPrimary
+            ),
+            SlotAddr::ReplicaRequired => true,
+        }
+    }
+
+    /// Returns a connection to a node of the route's shard, other than `excluded_address`, whose circuit isn't open.
+    /// Replicas are preferred over the primary.
+    fn shard_connection_with_closed_circuit(
+        &self,
+        route: &Route,
+        excluded_address: Option<&str>,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let addrs = &self.slot_map.slot_value_for_route(route)?.addrs;
+        let primary = addrs.primary();
+        let replicas = addrs.replicas();
+        replicas
+            .iter()
+            .chain(std::iter::once(&primary))
+            .filter(|address| Some(address.as_str()) != excluded_address)
+            .filter(|address| !self.is_circuit_open(address))
+            .find_map(|address| self.connection_for_address(address))
+    }
+
+    /// Returns a connection to a node of the route's shard whose circuit isn't open, if the route is a read that the
+    /// read strategy allows to serve from replicas. Replicas are preferred over the primary.
+    fn read_connection_with_closed_circuit(
+        &self,
+        route: &Route,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        if !self.reads_from_replicas(route) {
+            return None;
+        }
+        self.shard_connection_with_closed_circuit(route, None)
+    }
+
+    /// Returns a connection to a second node of the route's shard for a hedged read, if the route is a read that the
+    /// read strategy allows to serve from replicas. Replicas are preferred over the primary.
+    pub(crate) fn hedge_connection_for_route(
+        &self,
+        route: &Route,
+        first_address: &str,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        if !self.reads_from_replicas(route) {
+            return None;
+        }
+        self.shard_connection_with_closed_circuit(route, Some(first_address))
     }
 
     // Fetches the master address for a given route.
@@ -630,7 +868,22 @@ where
     ) -> impl Iterator<Item = ConnectionAndAddress<Connection>> + '_ {
         self.connection_map.iter().map(move |item| {
             let (node, address) = (item.key(), item.value());
-            (node.clone(), address.user_connection.conn.clone())
+            (
+                node.clone(),
+                self.select_user_connection(address).conn.clone(),
+            )
+        })
+    }
+
+    /// Returns every user connection of every node, including the additional user connections.
+    pub(crate) fn all_node_user_connections(
+        &self,
+    ) -> impl Iterator<Item = ConnectionAndAddress<Connection>> + '_ {
+        self.connection_map.iter().flat_map(move |item| {
+            let (address, node) = (item.key(), item.value());
+            node.user_connections()
+                .map(|connection| (address.clone(), connection.conn.clone()))
+                .collect::<Vec<_>>()
         })
     }
 
@@ -643,6 +896,19 @@ where
             .flat_map(|addr| self.connection_for_address(&addr))
     }
 
+    /// Returns the management connection of every node, or its user connection if it has no management connection.
+    pub(crate) fn all_node_management_connections(
+        &self,
+    ) -> impl Iterator<Item = ConnectionAndAddress<Connection>> + '_ {
+        self.connection_map.iter().map(move |item| {
+            let (address, node) = (item.key(), item.value());
+            (
+                address.clone(),
+                node.get_connection(&ConnectionType::PreferManagement),
+            )
+        })
+    }
+
     pub(crate) fn node_for_address(&self, address: &str) -> Option<ClusterNode<Connection>> {
         self.connection_map
             .get(address)
@@ -652,6 +918,20 @@ where
     pub(crate) fn connection_for_address(
         &self,
         address: &str,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        self.connection_map.get(address).map(|item| {
+            let (address, conn) = (item.key(), item.value());
+            (
+                address.clone(),
+                self.select_user_connection(conn).conn.clone(),
+            )
+        })
+    }
+
+    /// Returns the first user connection of the node at the given address, which subscriptions are pinned to.
+    pub(crate) fn pinned_connection_for_address(
+        &self,
+        address: &str,
     ) -> Option<ConnectionAndAddress<Connection>> {
         self.connection_map.get(address).map(|item| {
             let (address, conn) = (item.key(), item.value());
@@ -680,7 +960,7 @@ where
     ) -> Option<ConnectionAndAddress<ConnectionDetails<Connection>>> {
         self.connection_map.get(address).map(|item| {
             let (address, conn) = (item.key(), item.value());
-            (address.clone(), conn.user_connection.clone())
+            (address.clone(), self.select_user_connection(conn).clone())
         })
     }
 
@@ -696,7 +976,10 @@ where
                 .into_iter()
                 .map(move |item| {
                     let (address, node) = (item.key(), item.value());
-                    let conn = node.get_connection(&conn_type);
+                    let conn = match conn_type {
+                        ConnectionType::User => self.select_user_connection(node).conn.clone(),
+                        ConnectionType::PreferManagement => node.get_connection(&conn_type),
+                    };
                     (address.clone(), conn)
                 })
                 .collect::<Vec<_>>()
@@ -752,6 +1035,7 @@ where
 mod tests {
     use std::collections::HashSet;
 
+    use crate::circuit_breaker::CircuitBreakerConfig;
     use crate::cluster_routing::Slot;
 
     use super::*;
@@ -762,12 +1046,32 @@ mod tests {
         pub(crate) fn new_only_with_user_conn(user_connection: Connection) -> Self {
             let ip = None;
             let az = None;
-            Self {
-                user_connection: (user_connection, ip, az).into(),
-                management_connection: None,
-            }
+            Self::new((user_connection, ip, az).into(), None)
         }
     }
+
+    // In these tests a connection is represented by a number, which doubles as its count of pending requests.
+    impl PendingRequests for usize {
+        fn pending_requests(&self) -> usize {
+            *self
+        }
+    }
+
+    fn create_container_with_multiple_user_connections(
+        strategy: ConnectionSelectionStrategy,
+    ) -> ConnectionsContainer<usize> {
+        let mut container = create_container();
+        container.connection_selection_strategy = strategy;
+        container.replace_or_add_connection_for_address(
+            "primary1",
+            ClusterNode::new((5, None, None).into(), Some((50, None, None).into()))
+                .with_additional_user_connections(vec![
+                    (7, None, None).into(),
+                    (3, None, None).into(),
+                ]),
+        );
+        container
+    }
     fn remove_nodes(container: &ConnectionsContainer<usize>, addresses: &[&str]) {
         for address in addresses {
             container.remove_node(&(*address).into());
@@ -875,6 +1179,10 @@ mod tests {
                 .unwrap_or(ReadFromReplicaStrategy::AZAffinity("use-1a".to_string())),
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            latency_tracker: Default::default(),
+            connection_selection_strategy: Default::default(),
+            select_user_connection: ClusterNode::select_user_connection,
+            circuit_breaker: None,
         }
     }
 
@@ -933,6 +1241,10 @@ mod tests {
             read_from_replica_strategy: strategy,
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            latency_tracker: Default::default(),
+            connection_selection_strategy: Default::default(),
+            select_user_connection: ClusterNode::select_user_connection,
+            circuit_breaker: None,
         }
     }
 
@@ -1714,4 +2026,234 @@ mod tests {
             "AllNodes should include replica"
         );
     }
+
+    #[test]
+    fn get_connection_for_lowest_latency_strategy() {
+        let container = create_container_with_strategy(
+            ReadFromReplicaStrategy::LowestLatency(Duration::from_millis(2)),
+            false,
+        );
+
+        // Without measurements, falls back to round robin between the replicas.
+        let mut addresses = HashSet::new();
+        for _ in 0..4 {
+            addresses.insert(
+                container
+                    .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                    .unwrap()
+                    .1,
+            );
+        }
+        assert_eq!(addresses, HashSet::from([31, 32]));
+
+        container
+            .latency_tracker
+            .record("replica3-1", Duration::from_millis(10));
+        container
+            .latency_tracker
+            .record("replica3-2", Duration::from_millis(3));
+        for _ in 0..4 {
+            assert_eq!(
+                32,
+                container
+                    .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                    .unwrap()
+                    .1
+            );
+        }
+
+        // replica3-1 becomes faster, but not by more than the hysteresis.
+        container.latency_tracker.remove("replica3-1");
+        container
+            .latency_tracker
+            .record("replica3-1", Duration::from_millis(2));
+        assert_eq!(
+            32,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+
+        container.remove_node(&"replica3-2".to_string());
+        assert_eq!(
+            31,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn round_robin_over_multiple_user_connections() {
+        let container = create_container_with_multiple_user_connections(
+            ConnectionSelectionStrategy::RoundRobin,
+        );
+
+        let mut connections: Vec<_> = (0..6)
+            .map(|_| container.connection_for_address("primary1").unwrap().1)
+            .collect();
+        assert_eq!(connections[..3], connections[3..]);
+        connections.truncate(3);
+        connections.sort();
+        assert_eq!(connections, vec![3, 5, 7]);
+
+        let mut route_connections: Vec<_> = (0..3)
+            .map(|_| {
+                container
+                    .connection_for_route(&Route::new(1, SlotAddr::Master))
+                    .unwrap()
+                    .1
+            })
+            .collect();
+        route_connections.sort();
+        assert_eq!(route_connections, vec![3, 5, 7]);
+    }
+
+    #[test]
+    fn least_pending_over_multiple_user_connections() {
+        let container = create_container_with_multiple_user_connections(
+            ConnectionSelectionStrategy::LeastPending,
+        );
+
+        for _ in 0..3 {
+            assert_eq!(
+                container.connection_for_address("primary1"),
+                Some(("primary1".to_string(), 3))
+            );
+        }
+        assert_eq!(
+            container.management_connection_for_address("primary1"),
+            Some(("primary1".to_string(), 50))
+        );
+    }
+
+    #[test]
+    fn subscriptions_are_pinned_to_the_first_user_connection() {
+        let container = create_container_with_multiple_user_connections(
+            ConnectionSelectionStrategy::LeastPending,
+        );
+
+        assert_eq!(
+            container.pinned_connection_for_address("primary1"),
+            Some(("primary1".to_string(), 5))
+        );
+        assert_eq!(
+            container.pinned_connection_for_address("primary2"),
+            Some(("primary2".to_string(), 2))
+        );
+    }
+
+    #[test]
+    fn all_node_user_connections_include_additional_connections() {
+        let container = create_container_with_multiple_user_connections(
+            ConnectionSelectionStrategy::RoundRobin,
+        );
+        assert_eq!(
+            container
+                .node_for_address("primary1")
+                .unwrap()
+                .connections_count(),
+            4
+        );
+
+        let mut connections: Vec<_> = container
+            .all_node_user_connections()
+            .map(|(_, connection)| connection)
+            .collect();
+        connections.sort();
+        assert_eq!(connections, vec![2, 3, 3, 5, 7, 21, 31, 32]);
+    }
+
+    fn open_circuits(container: &mut ConnectionsContainer<usize>, addresses: &[&str]) {
+        let circuit_breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig {
+            minimum_requests: 1,
+            open_duration: Duration::from_secs(60),
+            ..Default::default()
+        }));
+        for address in addresses {
+            // a permit dropped without an outcome counts as a failure
+            drop(circuit_breaker.try_acquire(address).unwrap());
+        }
+        container.circuit_breaker = Some(circuit_breaker);
+    }
+
+    #[test]
+    fn reads_avoid_nodes_with_open_circuit() {
+        let mut container = create_container();
+        open_circuits(&mut container, &["replica3-1"]);
+
+        for _ in 0..4 {
+            assert_eq!(
+                32,
+                container
+                    .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                    .unwrap()
+                    .1
+            );
+        }
+
+        open_circuits(&mut container, &["replica3-1", "replica3-2"]);
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn primary_reads_and_writes_keep_nodes_with_open_circuit() {
+        let mut container = create_container();
+        open_circuits(&mut container, &["primary3"]);
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::Master))
+                .unwrap()
+                .1
+        );
+
+        let mut container =
+            create_container_with_strategy(ReadFromReplicaStrategy::AlwaysFromPrimary, false);
+        open_circuits(&mut container, &["primary3"]);
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn hedge_connection_is_another_node_of_the_shard() {
+        let container = create_container();
+        let route = Route::new(2001, SlotAddr::ReplicaOptional);
+        assert_eq!(
+            32,
+            container
+                .hedge_connection_for_route(&route, "replica3-1")
+                .unwrap()
+                .1
+        );
+        assert_eq!(
+            31,
+            container
+                .hedge_connection_for_route(&route, "replica3-2")
+                .unwrap()
+                .1
+        );
+        assert!(container
+            .hedge_connection_for_route(&Route::new(2001, SlotAddr::Master), "primary3")
+            .is_none());
+
+        let container =
+            create_container_with_strategy(ReadFromReplicaStrategy::AlwaysFromPrimary, false);
+        assert!(container
+            .hedge_connection_for_route(&route, "primary3")
+            .is_none());
+    }
 }
@@ -30,11 +30,14 @@ pub enum RefreshConnectionType {
     OnlyManagementConnection,
     // Refresh all connections: both management and user connections.
     AllConnections,
+    // Refresh only the user connections opened in addition to the node's first user connection, which keeps its subscriptions.
+    AdditionalUserConnections,
 }
 
 fn failed_management_connection<C>(
     addr: &str,
     user_conn: ConnectionDetails<ConnectionFuture<C>>,
+    additional_user_conns: Vec<ConnectionDetails<ConnectionFuture<C>>>,
     err: RedisError,
 ) -> ConnectAndCheckResult<C>
 where
@@ -45,7 +48,8 @@ where
         addr, err
     );
     ConnectAndCheckResult::ManagementConnectionFailed {
-        node: AsyncClusterNode::new(user_conn, None),
+        node: AsyncClusterNode::new(user_conn, None)
+            .with_additional_user_connections(additional_user_conns),
         err,
     }
 }
@@ -62,7 +66,8 @@ where
 {
     if let Some(node) = node {
         // We won't check whether the DNS address of this node has changed and now points to a new IP.
-        // Instead, we depend on managed Redis services to close the connection for refresh if the node has changed.
+        // Instead, the DNS refresh task moves the connections when `dns_refresh_interval` is set. Otherwise, we depend
+        // on managed Redis services to close the connection for refresh if the node has changed.
         match check_node_connections(&node, params, conn_type, addr).await {
             None => Ok(node),
             Some(conn_type) => connect_and_check(
@@ -93,6 +98,7 @@ where
 fn create_async_node<C>(
     user_conn: ConnectionDetails<C>,
     management_conn: Option<ConnectionDetails<C>>,
+    additional_user_conns: Vec<ConnectionDetails<ConnectionFuture<C>>>,
 ) -> AsyncClusterNode<C>
 where
     C: ConnectionLike + Connect + Send + Sync + 'static + Clone,
@@ -101,37 +107,128 @@ where
         user_conn.into_future(),
         management_conn.map(|conn| conn.into_future()),
     )
+    .with_additional_user_connections(additional_user_conns)
 }
 
-pub(crate) async fn connect_and_check_all_connections<C>(
+/// Opens the user connections that are used alongside the node's first user connection, when more than one
+/// connection per node is configured. Connections that fail to open are left out, and are reopened by the
+/// connections validation.
+async fn create_additional_user_connections<C>(
     addr: &str,
-    params: ClusterParams,
+    params: &ClusterParams,
     socket_addr: Option<SocketAddr>,
-    glide_connection_options: GlideConnectionOptions,
-) -> ConnectAndCheckResult<C>
+    mut glide_connection_options: GlideConnectionOptions,
+    count: usize,
+) -> Vec<ConnectionDetails<ConnectionFuture<C>>>
 where
     C: ConnectionLike + Connect + Send + Sync + 'static + Clone,
 {
-    match future::join(
-        // User connection
-        create_connection(
+    if count == 0 {
+        return Vec::new();
+    }
+    // subscriptions are only sent on the first user connection
+    glide_connection_options.pubsub_synchronizer = None;
+    future::join_all((0..count).map(|_| {
+        create_and_setup_user_connection::<C>(
             addr,
             params.clone(),
             socket_addr,
-            false,
             glide_connection_options.clone(),
+        )
+    }))
+    .await
+    .into_iter()
+    .filter_map(|result| match result {
+        Ok(connection) => Some(connection.into_future()),
+        Err(err) => {
+            warn!(
+                "Failed to create an additional user connection for node `{:?}`. Error: `{:?}`",
+                addr, err
+            );
+            None
+        }
+    })
+    .collect()
+}
+
+/// Keeps the additional user connections that still answer, and opens new ones in place of the others, so that the
+/// requests in flight on the healthy connections, and the transactions watching keys on them, aren't disturbed.
+async fn refresh_additional_user_connections<C>(
+    addr: &str,
+    params: &ClusterParams,
+    socket_addr: Option<SocketAddr>,
+    glide_connection_options: GlideConnectionOptions,
+    connections: Vec<ConnectionDetails<ConnectionFuture<C>>>,
+) -> Vec<ConnectionDetails<ConnectionFuture<C>>>
+where
+    C: ConnectionLike + Connect + Send + Sync + 'static + Clone,
+{
+    let timeout = params.connection_timeout;
+    let mut healthy_connections: Vec<_> =
+        future::join_all(connections.into_iter().map(|connection| async move {
+            let mut conn = connection.conn.clone().await;
+            let healthy = !conn.is_closed() && check_connection(&mut conn, timeout).await.is_ok();
+            healthy.then_some(connection)
+        }))
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+    let missing = params
+        .connections_per_node
+        .saturating_sub(1)
+        .saturating_sub(healthy_connections.len());
+    healthy_connections.extend(
+        create_additional_user_connections(
+            addr,
+            params,
+            socket_addr,
+            glide_connection_options,
+            missing,
+        )
+        .await,
+    );
+    healthy_connections
+}
+
+pub(crate) async fn connect_and_check_all_connections<C>(
+    addr: &str,
+    params: ClusterParams,
+    socket_addr: Option<SocketAddr>,
+    glide_connection_options: GlideConnectionOptions,
+) -> ConnectAndCheckResult<C>
+where
+    C: ConnectionLike + Connect + Send + Sync + 'static + Clone,
+{
+    let (connections, additional_user_conns) = future::join(
+        future::join(
+            // User connection
+            create_connection(
+                addr,
+                params.clone(),
+                socket_addr,
+                false,
+                glide_connection_options.clone(),
+            ),
+            // Management connection
+            create_connection(
+                addr,
+                params.clone(),
+                socket_addr,
+                true,
+                glide_connection_options.clone(),
+            ),
         ),
-        // Management connection
-        create_connection(
+        create_additional_user_connections(
             addr,
-            params.clone(),
+            &params,
             socket_addr,
-            true,
             glide_connection_options,
+            params.connections_per_node.saturating_sub(1),
         ),
     )
-    .await
-    {
+    .await;
+    match connections {
         (Ok(conn_1), Ok(conn_2)) => {
             // Both connections were successfully established
             let mut user_conn: ConnectionDetails<C> = conn_1;
@@ -143,14 +240,25 @@ where
                 Ok(_) => ConnectAndCheckResult::Success(create_async_node(
                     user_conn,
                     Some(management_conn),
+                    additional_user_conns,
                 )),
-                Err(err) => failed_management_connection(addr, user_conn.into_future(), err),
+                Err(err) => failed_management_connection(
+                    addr,
+                    user_conn.into_future(),
+                    additional_user_conns,
+                    err,
+                ),
             }
         }
         (Ok(mut connection), Err(err)) | (Err(err), Ok(mut connection)) => {
             // Only a single connection was successfully established. Use it for the user connection
             match setup_user_connection(&mut connection, params).await {
-                Ok(_) => failed_management_connection(addr, connection.into_future(), err),
+                Ok(_) => failed_management_connection(
+                    addr,
+                    connection.into_future(),
+                    additional_user_conns,
+                    err,
+                ),
                 Err(err) => err.into(),
             }
         }
@@ -196,21 +304,32 @@ where
             tcp_nodelay: params.tcp_nodelay,
             pubsub_synchronizer: None,
             iam_token_provider: None,
+            dns_resolver: params.dns_resolver.clone(),
         },
     )
     .await
     {
-        Err(conn_err) => failed_management_connection(addr, prev_node.user_connection, conn_err),
+        Err(conn_err) => failed_management_connection(
+            addr,
+            prev_node.user_connection,
+            prev_node.additional_user_connections,
+            conn_err,
+        ),
 
         Ok(mut connection) => {
             if let Err(err) = setup_management_connection(&mut connection.conn).await {
-                return failed_management_connection(addr, prev_node.user_connection, err);
+                return failed_management_connection(
+                    addr,
+                    prev_node.user_connection,
+                    prev_node.additional_user_connections,
+                    err,
+                );
             }
 
-            ConnectAndCheckResult::Success(ClusterNode {
-                user_connection: prev_node.user_connection,
-                management_connection: Some(connection.into_future()),
-            })
+            ConnectAndCheckResult::Success(
+                ClusterNode::new(prev_node.user_connection, Some(connection.into_future()))
+                    .with_additional_user_connections(prev_node.additional_user_connections),
+            )
         }
     }
 }
@@ -282,19 +401,33 @@ where
 {
     match conn_type {
         RefreshConnectionType::OnlyUserConnection => {
-            let user_conn = match create_and_setup_user_connection(
-                addr,
-                params.clone(),
-                socket_addr,
-                glide_connection_options,
+            let (management_conn, additional_user_conns) = match node {
+                Some(node) => (node.management_connection, node.additional_user_connections),
+                None => (None, Vec::new()),
+            };
+            let (user_conn, additional_user_conns) = future::join(
+                create_and_setup_user_connection(
+                    addr,
+                    params.clone(),
+                    socket_addr,
+                    glide_connection_options.clone(),
+                ),
+                refresh_additional_user_connections(
+                    addr,
+                    &params,
+                    socket_addr,
+                    glide_connection_options,
+                    additional_user_conns,
+                ),
             )
-            .await
-            {
+            .await;
+            let user_conn = match user_conn {
                 Ok(tuple) => tuple,
                 Err(err) => return err.into(),
             };
-            let management_conn = node.and_then(|node| node.management_connection);
-            AsyncClusterNode::new(user_conn.into_future(), management_conn).into()
+            AsyncClusterNode::new(user_conn.into_future(), management_conn)
+                .with_additional_user_connections(additional_user_conns)
+                .into()
         }
         RefreshConnectionType::OnlyManagementConnection => {
             // Refreshing only the management connection requires the node to exist alongside a user connection. Otherwise, refresh all connections.
@@ -324,6 +457,30 @@ where
             connect_and_check_all_connections(addr, params, socket_addr, glide_connection_options)
                 .await
         }
+        RefreshConnectionType::AdditionalUserConnections => match node {
+            Some(node) => {
+                let additional_user_conns = refresh_additional_user_connections(
+                    addr,
+                    &params,
+                    socket_addr,
+                    glide_connection_options,
+                    node.additional_user_connections,
+                )
+                .await;
+                ClusterNode::new(node.user_connection, node.management_connection)
+                    .with_additional_user_connections(additional_user_conns)
+                    .into()
+            }
+            None => {
+                connect_and_check_all_connections(
+                    addr,
+                    params,
+                    socket_addr,
+                    glide_connection_options,
+                )
+                .await
+            }
+        },
     }
 }
 
@@ -395,10 +552,12 @@ where
 {
     let connection_timeout = params.connection_timeout;
     let response_timeout = params.response_timeout;
-    let info = get_connection_info(node, params)?;
+    let mut info = get_connection_info(node, params)?;
     // management connection does not require notifications or disconnect notifications
-    // or pubsub synchronizer (subscriptions only exist on user connections)
+    // or pubsub synchronizer (subscriptions only exist on user connections),
+    // and it never serves cacheable reads, so it doesn't enable client tracking
     if is_management {
+        info.redis.cache = None;
         glide_connection_options.disconnect_notifier = None;
         glide_connection_options.pubsub_synchronizer = None;
     }
@@ -429,11 +588,13 @@ where
     C: ConnectionLike + Send + 'static + Clone,
 {
     let timeout = params.connection_timeout;
-    let (check_mgmt_connection, check_user_connection) = match conn_type {
-        RefreshConnectionType::OnlyUserConnection => (false, true),
-        RefreshConnectionType::OnlyManagementConnection => (true, false),
-        RefreshConnectionType::AllConnections => (true, true),
-    };
+    let (check_mgmt_connection, check_user_connection, check_additional_user_connections) =
+        match conn_type {
+            RefreshConnectionType::OnlyUserConnection => (false, true, true),
+            RefreshConnectionType::OnlyManagementConnection => (true, false, false),
+            RefreshConnectionType::AllConnections => (true, true, true),
+            RefreshConnectionType::AdditionalUserConnections => (false, false, true),
+        };
     let check = |conn, timeout, conn_type| async move {
         match check_connection(&mut conn.await, timeout).await {
             Ok(_) => false,
@@ -446,7 +607,7 @@ where
             }
         }
     };
-    let (mgmt_failed, user_failed) = join!(
+    let (mgmt_failed, user_failed, additional_user_failed) = join!(
         async {
             if !check_mgmt_connection {
                 return false;
@@ -466,13 +627,38 @@ where
             let conn = node.user_connection.conn.clone();
             check(conn, timeout, "user").await
         },
+        async {
+            if !check_additional_user_connections {
+                return false;
+            }
+            // A missing connection is treated as unhealthy, so that it's reopened.
+            if node.user_connections_count() < params.connections_per_node {
+                warn!(
+                    "Node {} has {} of {} user connections",
+                    address,
+                    node.user_connections_count(),
+                    params.connections_per_node
+                );
+                return true;
+            }
+            future::join_all(
+                node.additional_user_connections
+                    .iter()
+                    .map(|connection| check(connection.conn.clone(), timeout, "user")),
+            )
+            .await
+            .into_iter()
+            .any(|failed| failed)
+        },
     );
 
-    match (mgmt_failed, user_failed) {
-        (true, true) => Some(RefreshConnectionType::AllConnections),
-        (true, false) => Some(RefreshConnectionType::OnlyManagementConnection),
-        (false, true) => Some(RefreshConnectionType::OnlyUserConnection),
-        (false, false) => None,
+    match (mgmt_failed, user_failed, additional_user_failed) {
+        (true, true, _) | (true, false, true) => Some(RefreshConnectionType::AllConnections),
+        (true, false, false) => Some(RefreshConnectionType::OnlyManagementConnection),
+        // reopening the first user connection also reopens the broken additional ones
+        (false, true, _) => Some(RefreshConnectionType::OnlyUserConnection),
+        (false, false, true) => Some(RefreshConnectionType::AdditionalUserConnections),
+        (false, false, false) => None,
     }
 }
 
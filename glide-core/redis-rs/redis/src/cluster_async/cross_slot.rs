@@ -0,0 +1,614 @@
+use crate::cluster_routing::{Routable, Route, SlotAddr};
+use crate::cluster_topology::get_slot;
+use crate::{cmd, Cmd, ErrorKind, ProtocolVersion, RedisResult, Value};
+use std::collections::{HashMap, HashSet};
+
+/// The set operation a cross-slot command performs on its keys.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SetOp {
+    Union,
+    Inter,
+    Diff,
+}
+
+/// How the scores of a member in several sorted sets are combined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScoreAggregate {
+    Sum,
+    Min,
+    Max,
+}
+
+/// The options of a sorted set command.
+#[derive(Debug, Clone, PartialEq)]
+struct SortedSetOptions {
+    /// One weight per key.
+    weights: Vec<f64>,
+    aggregate: ScoreAggregate,
+    with_scores: bool,
+}
+
+/// A read-only set or sorted set command whose keys are in different slots, which is emulated by fetching every key
+/// from its own shard and computing the result on the client.
+///
+/// Supported are SUNION, SINTER, SDIFF and SINTERCARD, which read sets, and ZUNION, ZINTER, ZDIFF and ZINTERCARD,
+/// which read sorted sets, or sets whose members all have a score of 1. Commands that write, like RENAME or
+/// SUNIONSTORE, are out of scope: their keys can't be written atomically from the client, so they still fail with
+/// `CROSSSLOT`.
+///
+/// Every fetch is sent as a regular single-key request, so a `TRYAGAIN`, `MOVED` or `ASK` answered during a slot
+/// migration is retried like for any other command.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CrossSlotCommand {
+    op: SetOp,
+    keys: Vec<Vec<u8>>,
+    /// Set for sorted set commands.
+    sorted_set: Option<SortedSetOptions>,
+    /// Set for SINTERCARD and ZINTERCARD, which return the cardinality of the result. 0 means no limit.
+    cardinality_limit: Option<usize>,
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &[u8]) -> Option<T> {
+    std::str::from_utf8(arg).ok()?.parse().ok()
+}
+
+/// Splits the arguments of a command that starts with the number of keys into the keys and the remaining arguments.
+fn split_counted_keys<'a>(args: &'a [&'a [u8]]) -> Option<(&'a [&'a [u8]], &'a [&'a [u8]])> {
+    let (numkeys, rest) = args.split_first()?;
+    let numkeys: usize = parse_number(numkeys)?;
+    if numkeys == 0 || numkeys > rest.len() {
+        return None;
+    }
+    Some(rest.split_at(numkeys))
+}
+
+/// Parses the optional `LIMIT limit` of SINTERCARD and ZINTERCARD.
+fn parse_limit(options: &[&[u8]]) -> Option<usize> {
+    match options {
+        [] => Some(0),
+        [limit, value] if limit.eq_ignore_ascii_case(b"LIMIT") => parse_number(value),
+        _ => None,
+    }
+}
+
+/// Parses the `WEIGHTS`, `AGGREGATE` and `WITHSCORES` options of ZUNION and ZINTER, or only `WITHSCORES` for ZDIFF.
+fn parse_sorted_set_options(
+    key_count: usize,
+    mut options: &[&[u8]],
+    with_weights: bool,
+) -> Option<SortedSetOptions> {
+    let mut parsed = SortedSetOptions {
+        weights: vec![1.0; key_count],
+        aggregate: ScoreAggregate::Sum,
+        with_scores: false,
+    };
+    while let Some((option, rest)) = options.split_first() {
+        options = if with_weights && option.eq_ignore_ascii_case(b"WEIGHTS") {
+            if rest.len() < key_count {
+                return None;
+            }
+            let (weights, rest) = rest.split_at(key_count);
+            parsed.weights = weights
+                .iter()
+                .map(|weight| parse_number(weight))
+                .collect::<Option<_>>()?;
+            rest
+        } else if with_weights && option.eq_ignore_ascii_case(b"AGGREGATE") {
+            let (aggregate, rest) = rest.split_first()?;
+            parsed.aggregate = match aggregate.to_ascii_uppercase().as_slice() {
+                b"SUM" => ScoreAggregate::Sum,
+                b"MIN" => ScoreAggregate::Min,
+                b"MAX" => ScoreAggregate::Max,
+                _ => return None,
+            };
+            rest
+        } else if option.eq_ignore_ascii_case(b"WITHSCORES") {
+            parsed.with_scores = true;
+            rest
+        } else {
+            return None;
+        };
+    }
+    Some(parsed)
+}
+
+impl CrossSlotCommand {
+    /// Returns the emulated command for `cmd`, or `None` if `cmd` isn't supported, is malformed, or all its keys are
+    /// in the same slot. In those cases the command is sent to the server as is.
+    pub(crate) fn parse(cmd: &Cmd) -> Option<Self> {
+        let command = cmd.command()?;
+        let args: Vec<&[u8]> = (1..).map_while(|index| cmd.arg_idx(index)).collect();
+        let (op, keys, sorted_set, cardinality_limit) = match command.as_slice() {
+            b"SUNION" | b"SINTER" | b"SDIFF" => {
+                let op = match command.as_slice() {
+                    b"SUNION" => SetOp::Union,
+                    b"SINTER" => SetOp::Inter,
+                    _ => SetOp::Diff,
+                };
+                (op, args.as_slice(), None, None)
+            }
+            b"SINTERCARD" => {
+                let (keys, options) = split_counted_keys(&args)?;
+                (SetOp::Inter, keys, None, Some(parse_limit(options)?))
+            }
+            b"ZUNION" | b"ZINTER" | b"ZDIFF" => {
+                let (keys, options) = split_counted_keys(&args)?;
+                let op = match command.as_slice() {
+                    b"ZUNION" => SetOp::Union,
+                    b"ZINTER" => SetOp::Inter,
+                    _ => SetOp::Diff,
+                };
+                let options = parse_sorted_set_options(keys.len(), options, op != SetOp::Diff)?;
+                (op, keys, Some(options), None)
+            }
+            b"ZINTERCARD" => {
+                let (keys, options) = split_counted_keys(&args)?;
+                let cardinality_limit = parse_limit(options)?;
+                let sorted_set = parse_sorted_set_options(keys.len(), &[], false)?;
+                (
+                    SetOp::Inter,
+                    keys,
+                    Some(sorted_set),
+                    Some(cardinality_limit),
+                )
+            }
+            _ => return None,
+        };
+
+        let first_slot = get_slot(keys.first()?);
+        if keys.iter().all(|key| get_slot(key) == first_slot) {
+            return None;
+        }
+        Some(Self {
+            op,
+            keys: keys.iter().map(|key| key.to_vec()).collect(),
+            sorted_set,
+            cardinality_limit,
+        })
+    }
+
+    /// Returns the commands that fetch the keys, each with the route of its key.
+    pub(crate) fn fetch_commands(&self) -> impl Iterator<Item = (Cmd, Route)> + '_ {
+        self.keys.iter().map(|key| {
+            let fetch = if self.sorted_set.is_some() {
+                let mut fetch = cmd("ZRANGE");
+                fetch.arg(key).arg(0).arg(-1).arg("WITHSCORES");
+                fetch
+            } else {
+                let mut fetch = cmd("SMEMBERS");
+                fetch.arg(key);
+                fetch
+            };
+            (fetch, Route::new(get_slot(key), SlotAddr::ReplicaOptional))
+        })
+    }
+
+    /// Returns the command that fetches the key at `index` of a sorted set command as a set, after fetching it as a
+    /// sorted set failed with `WRONGTYPE`. Returns `None` for set commands.
+    pub(crate) fn set_fetch_command(&self, index: usize) -> Option<(Cmd, Route)> {
+        self.sorted_set.as_ref()?;
+        let key = self.keys.get(index)?;
+        let mut fetch = cmd("SMEMBERS");
+        fetch.arg(key);
+        Some((fetch, Route::new(get_slot(key), SlotAddr::ReplicaOptional)))
+    }
+
+    /// Converts the response to [`Self::set_fetch_command`] into a set, which [`Self::compute`] reads as a sorted set
+    /// whose members all have a score of 1.
+    pub(crate) fn set_fetch_response(value: Value) -> Value {
+        match value {
+            Value::Array(members) => Value::Set(members),
+            value => value,
+        }
+    }
+
+    /// Computes the result of the command from the responses to [`Self::fetch_commands`], in the same order, in the
+    /// format of `protocol`.
+    pub(crate) fn compute(
+        &self,
+        values: Vec<Value>,
+        protocol: ProtocolVersion,
+    ) -> RedisResult<Value> {
+        let resp3 = protocol != ProtocolVersion::RESP2;
+        match &self.sorted_set {
+            None => self.compute_sets(values, resp3),
+            Some(options) => self.compute_sorted_sets(options, values, resp3),
+        }
+    }
+
+    fn compute_sets(&self, values: Vec<Value>, resp3: bool) -> RedisResult<Value> {
+        let sets = values
+            .iter()
+            .map(|value| match value {
+                Value::Array(members) | Value::Set(members) => members
+                    .iter()
+                    .map(member_bytes)
+                    .collect::<RedisResult<Vec<_>>>(),
+                _ => Err((
+                    ErrorKind::TypeError,
+                    "expected array of members as response",
+                )
+                    .into()),
+            })
+            .collect::<RedisResult<Vec<_>>>()?;
+        let lookups = sets
+            .iter()
+            .map(|members| members.iter().copied().collect::<HashSet<_>>())
+            .collect::<Vec<_>>();
+
+        let mut seen = HashSet::new();
+        let result = sets
+            .iter()
+            .flatten()
+            .filter(|member| seen.insert(**member))
+            .filter(|member| self.keeps_member(|index| lookups[index].contains(*member)))
+            .map(|member| Value::BulkString(member.to_vec()))
+            .collect::<Vec<_>>();
+
+        if let Some(limit) = self.cardinality_limit {
+            return Ok(Value::Int(capped_cardinality(result.len(), limit)));
+        }
+        Ok(if resp3 {
+            Value::Set(result)
+        } else {
+            Value::Array(result)
+        })
+    }
+
+    fn compute_sorted_sets(
+        &self,
+        options: &SortedSetOptions,
+        values: Vec<Value>,
+        resp3: bool,
+    ) -> RedisResult<Value> {
+        let sorted_sets = values
+            .into_iter()
+            .map(parse_sorted_set)
+            .collect::<RedisResult<Vec<_>>>()?;
+        let lookups = sorted_sets
+            .iter()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|(member, score)| (member.as_slice(), *score))
+                    .collect::<HashMap<_, _>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut seen = HashSet::new();
+        let mut result = Vec::new();
+        for (member, _) in sorted_sets.iter().flatten() {
+            if !seen.insert(member.as_slice())
+                || !self.keeps_member(|index| lookups[index].contains_key(member.as_slice()))
+            {
+                continue;
+            }
+            let score = if self.op == SetOp::Diff {
+                lookups[0][member.as_slice()]
+            } else {
+                lookups
+                    .iter()
+                    .zip(&options.weights)
+                    .filter_map(|(lookup, weight)| {
+                        lookup
+                            .get(member.as_slice())
+                            .map(|score| nan_to_zero(score * weight))
+                    })
+                    .reduce(|acc, score| match options.aggregate {
+                        ScoreAggregate::Sum => nan_to_zero(acc + score),
+                        ScoreAggregate::Min => acc.min(score),
+                        ScoreAggregate::Max => acc.max(score),
+                    })
+                    .unwrap_or_default()
+            };
+            result.push((member.clone(), score));
+        }
+
+        if let Some(limit) = self.cardinality_limit {
+            return Ok(Value::Int(capped_cardinality(result.len(), limit)));
+        }
+        result.sort_by(|(member_a, score_a), (member_b, score_b)| {
+            score_a
+                .total_cmp(score_b)
+                .then_with(|| member_a.cmp(member_b))
+        });
+        let result = result.into_iter().map(|(member, score)| {
+            let member = Value::BulkString(member);
+            match (options.with_scores, resp3) {
+                (false, _) => vec![member],
+                (true, false) => vec![member, Value::BulkString(score.to_string().into_bytes())],
+                (true, true) => vec![Value::Array(vec![member, Value::Double(score)])],
+            }
+        });
+        Ok(Value::Array(result.flatten().collect()))
+    }
+
+    /// Returns whether a member is part of the result, given whether each key contains it.
+    fn keeps_member(&self, key_contains: impl Fn(usize) -> bool) -> bool {
+        match self.op {
+            SetOp::Union => true,
+            SetOp::Inter => (0..self.keys.len()).all(key_contains),
+            SetOp::Diff => key_contains(0) && !(1..self.keys.len()).any(key_contains),
+        }
+    }
+}
+
+fn capped_cardinality(cardinality: usize, limit: usize) -> i64 {
+    if limit == 0 {
+        cardinality as i64
+    } else {
+        cardinality.min(limit) as i64
+    }
+}
+
+/// The server treats scores that aren't a number, e.g. the product of 0 and infinity, as 0.
+fn nan_to_zero(score: f64) -> f64 {
+    if score.is_nan() {
+        0.0
+    } else {
+        score
+    }
+}
+
+fn member_bytes(value: &Value) -> RedisResult<&[u8]> {
+    match value {
+        Value::BulkString(bytes) => Ok(bytes),
+        Value::SimpleString(string) => Ok(string.as_bytes()),
+        _ => Err((ErrorKind::TypeError, "expected string member").into()),
+    }
+}
+
+fn parse_score(value: &Value) -> RedisResult<f64> {
+    let score = match value {
+        Value::Double(score) => Some(*score),
+        Value::Int(score) => Some(*score as f64),
+        Value::BulkString(bytes) => parse_number(bytes),
+        Value::SimpleString(string) => string.parse().ok(),
+        _ => None,
+    };
+    score.ok_or_else(|| (ErrorKind::TypeError, "expected score").into())
+}
+
+/// Parses the response to `ZRANGE key 0 -1 WITHSCORES`, which is a flat array of members and scores in RESP2 and an
+/// array of member-score pairs in RESP3, or the members of a set from [`CrossSlotCommand::set_fetch_response`], which
+/// all have a score of 1.
+fn parse_sorted_set(value: Value) -> RedisResult<Vec<(Vec<u8>, f64)>> {
+    let entries = match value {
+        Value::Array(entries) => entries,
+        Value::Set(members) => {
+            return members
+                .iter()
+                .map(|member| Ok((member_bytes(member)?.to_vec(), 1.0)))
+                .collect();
+        }
+        _ => {
+            return Err((
+                ErrorKind::TypeError,
+                "expected array of members and scores as response",
+            )
+                .into());
+        }
+    };
+    let pairs = if matches!(entries.first(), Some(Value::Array(_))) {
+        entries
+            .iter()
+            .map(|entry| match entry {
+                Value::Array(pair) if pair.len() == 2 => Ok((&pair[0], &pair[1])),
+                _ => Err((ErrorKind::TypeError, "expected pair of member and score").into()),
+            })
+            .collect::<RedisResult<Vec<_>>>()?
+    } else {
+        if entries.len() % 2 != 0 {
+            return Err((ErrorKind::TypeError, "expected pairs of members and scores").into());
+        }
+        entries
+            .chunks_exact(2)
+            .map(|pair| (&pair[0], &pair[1]))
+            .collect()
+    };
+    pairs
+        .into_iter()
+        .map(|(member, score)| Ok((member_bytes(member)?.to_vec(), parse_score(score)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(value: &str) -> Value {
+        Value::BulkString(value.as_bytes().to_vec())
+    }
+
+    fn bulks(values: &[&str]) -> Value {
+        Value::Array(values.iter().map(|value| bulk(value)).collect())
+    }
+
+    #[test]
+    fn test_parse_only_commands_with_keys_in_different_slots() {
+        assert!(CrossSlotCommand::parse(cmd("SUNION").arg("{a}1").arg("{a}2")).is_none());
+        assert!(CrossSlotCommand::parse(cmd("SMEMBERS").arg("a")).is_none());
+        assert!(CrossSlotCommand::parse(cmd("ZUNION").arg(3).arg("a").arg("b")).is_none());
+        assert!(CrossSlotCommand::parse(
+            cmd("ZUNION").arg(2).arg("a").arg("b").arg("WEIGHTS").arg(1)
+        )
+        .is_none());
+
+        let parsed = CrossSlotCommand::parse(
+            cmd("zinter")
+                .arg(2)
+                .arg("a")
+                .arg("b")
+                .arg("WEIGHTS")
+                .arg(2)
+                .arg(3)
+                .arg("AGGREGATE")
+                .arg("max")
+                .arg("WITHSCORES"),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed,
+            CrossSlotCommand {
+                op: SetOp::Inter,
+                keys: vec![b"a".to_vec(), b"b".to_vec()],
+                sorted_set: Some(SortedSetOptions {
+                    weights: vec![2.0, 3.0],
+                    aggregate: ScoreAggregate::Max,
+                    with_scores: true,
+                }),
+                cardinality_limit: None,
+            }
+        );
+        let (fetch_command, route) = parsed.fetch_commands().nth(1).unwrap();
+        assert_eq!(
+            fetch_command.get_packed_command(),
+            cmd("ZRANGE")
+                .arg("b")
+                .arg(0)
+                .arg(-1)
+                .arg("WITHSCORES")
+                .get_packed_command()
+        );
+        assert_eq!(route, Route::new(get_slot(b"b"), SlotAddr::ReplicaOptional));
+    }
+
+    #[test]
+    fn test_compute_sets() {
+        let values = || {
+            vec![
+                bulks(&["x", "y", "z"]),
+                Value::Set(vec![bulk("y"), bulk("w")]),
+                bulks(&["y", "z"]),
+            ]
+        };
+        let compute = |command: &mut Cmd| {
+            let command = command.arg("a").arg("b").arg("c");
+            CrossSlotCommand::parse(command)
+                .unwrap()
+                .compute(values(), ProtocolVersion::RESP3)
+                .unwrap()
+        };
+        assert_eq!(
+            compute(&mut cmd("SUNION")),
+            Value::Set(vec![bulk("x"), bulk("y"), bulk("z"), bulk("w")])
+        );
+        assert_eq!(compute(&mut cmd("SINTER")), Value::Set(vec![bulk("y")]));
+        assert_eq!(compute(&mut cmd("SDIFF")), Value::Set(vec![bulk("x")]));
+
+        let intercard = CrossSlotCommand::parse(
+            cmd("SINTERCARD")
+                .arg(2)
+                .arg("a")
+                .arg("c")
+                .arg("LIMIT")
+                .arg(1),
+        )
+        .unwrap();
+        assert_eq!(
+            intercard
+                .compute(
+                    vec![bulks(&["x", "y", "z"]), bulks(&["y", "z"])],
+                    ProtocolVersion::RESP2,
+                )
+                .unwrap(),
+            Value::Int(1)
+        );
+    }
+
+    #[test]
+    fn test_compute_sorted_sets() {
+        let resp2_values = || vec![bulks(&["x", "1", "y", "2"]), bulks(&["y", "3", "z", "inf"])];
+        let union = CrossSlotCommand::parse(
+            cmd("ZUNION")
+                .arg(2)
+                .arg("a")
+                .arg("b")
+                .arg("WEIGHTS")
+                .arg(1)
+                .arg(2)
+                .arg("WITHSCORES"),
+        )
+        .unwrap();
+        assert_eq!(
+            union
+                .compute(resp2_values(), ProtocolVersion::RESP2)
+                .unwrap(),
+            bulks(&["x", "1", "y", "8", "z", "inf"])
+        );
+
+        let inter = CrossSlotCommand::parse(
+            cmd("ZINTER")
+                .arg(2)
+                .arg("a")
+                .arg("b")
+                .arg("AGGREGATE")
+                .arg("MIN"),
+        )
+        .unwrap();
+        assert_eq!(
+            inter
+                .compute(resp2_values(), ProtocolVersion::RESP2)
+                .unwrap(),
+            bulks(&["y"])
+        );
+
+        let diff = CrossSlotCommand::parse(cmd("ZDIFF").arg(2).arg("a").arg("b").arg("WITHSCORES"))
+            .unwrap();
+        let resp3_values = vec![
+            Value::Array(vec![
+                Value::Array(vec![bulk("x"), Value::Double(1.0)]),
+                Value::Array(vec![bulk("y"), Value::Double(2.0)]),
+            ]),
+            Value::Array(vec![Value::Array(vec![bulk("y"), Value::Double(3.0)])]),
+        ];
+        assert_eq!(
+            diff.compute(resp3_values, ProtocolVersion::RESP3).unwrap(),
+            Value::Array(vec![Value::Array(vec![bulk("x"), Value::Double(1.0)])])
+        );
+
+        let intercard =
+            CrossSlotCommand::parse(cmd("ZINTERCARD").arg(2).arg("a").arg("b")).unwrap();
+        assert_eq!(
+            intercard
+                .compute(resp2_values(), ProtocolVersion::RESP2)
+                .unwrap(),
+            Value::Int(1)
+        );
+    }
+
+    #[test]
+    fn test_compute_sorted_sets_with_sets() {
+        let union = CrossSlotCommand::parse(
+            cmd("ZUNION")
+                .arg(2)
+                .arg("a")
+                .arg("b")
+                .arg("WEIGHTS")
+                .arg(1)
+                .arg(2)
+                .arg("WITHSCORES"),
+        )
+        .unwrap();
+        assert!(union.set_fetch_command(2).is_none());
+        let (fetch_command, route) = union.set_fetch_command(1).unwrap();
+        assert_eq!(
+            fetch_command.get_packed_command(),
+            cmd("SMEMBERS").arg("b").get_packed_command()
+        );
+        assert_eq!(route, Route::new(get_slot(b"b"), SlotAddr::ReplicaOptional));
+
+        // The members of the set `b` have a score of 1, which is weighted by 2.
+        let values = vec![
+            bulks(&["x", "1", "y", "5"]),
+            CrossSlotCommand::set_fetch_response(bulks(&["y", "z"])),
+        ];
+        assert_eq!(
+            union.compute(values, ProtocolVersion::RESP2).unwrap(),
+            bulks(&["x", "1", "z", "2", "y", "7"])
+        );
+
+        let sunion = CrossSlotCommand::parse(cmd("SUNION").arg("a").arg("b")).unwrap();
+        assert!(sunion.set_fetch_command(0).is_none());
+    }
+}
@@ -24,6 +24,7 @@
 
 mod connections_container;
 mod connections_logic;
+mod cross_slot;
 mod pipeline_routing;
 /// Exposed only for testing.
 pub mod testing {
@@ -41,6 +42,7 @@ use crate::{
     },
     cmd,
     commands::cluster_scan::{cluster_scan, ClusterScanArgs, ScanStateRC},
+    latency_tracker::LATENCY_PROBE_INTERVAL,
     types::ServerError,
     FromRedisValue, InfoDict, PipelineRetryStrategy,
 };
@@ -64,7 +66,7 @@ use std::{
         Arc,
     },
     task::{self, Poll},
-    time::SystemTime,
+    time::{Instant, SystemTime},
 };
 use strum_macros::Display;
 #[cfg(feature = "tokio-comp")]
@@ -86,8 +88,8 @@ use crate::{
     },
     push_manager::PushInfo,
     types::ProtocolVersion,
-    Cmd, ConnectionInfo, ErrorKind, IntoConnectionInfo, RedisError, RedisFuture, RedisResult,
-    Value,
+    Cmd, ConnectionInfo, DnsResolver, ErrorKind, IntoConnectionInfo, RedisError, RedisFuture,
+    RedisResult, Value,
 };
 use futures::{
     future::Shared,
@@ -118,7 +120,10 @@ use tracing::{debug, info, trace, warn};
 use self::{
     connections_container::{ConnectionAndAddress, ConnectionType, ConnectionsMap},
     connections_logic::connect_and_check,
+    cross_slot::CrossSlotCommand,
 };
+use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerPermit};
+use crate::hedged_reads::HedgedReads;
 use crate::types::RetryMethod;
 
 /// Parses a `"host:port"` address string into its components.
@@ -138,6 +143,68 @@ fn set_routed_node_on_span(span: &GlideSpan, address: &str) {
     }
 }
 
+/// Returns true for the commands that change the subscriptions of a connection. These are always sent on the
+/// first user connection of a node, so that all push notifications of a node arrive on a single connection.
+fn is_subscription_command(cmd: &Cmd) -> bool {
+    matches!(
+        cmd.command().as_deref(),
+        Some(
+            b"SUBSCRIBE"
+                | b"PSUBSCRIBE"
+                | b"SSUBSCRIBE"
+                | b"UNSUBSCRIBE"
+                | b"PUNSUBSCRIBE"
+                | b"SUNSUBSCRIBE"
+        )
+    )
+}
+
+/// Returns true for the commands of optimistic transactions. `WATCH` only applies to the connection it was sent on, so
+/// these are always sent on the first user connection of a node, along with the transactions themselves.
+fn is_transaction_command(cmd: &Cmd) -> bool {
+    matches!(
+        cmd.command().as_deref(),
+        Some(b"WATCH" | b"UNWATCH" | b"MULTI" | b"EXEC" | b"DISCARD")
+    )
+}
+
+/// Returns true for the commands that are always sent on the first user connection of a node.
+fn is_pinned_to_first_connection(cmd: &Cmd) -> bool {
+    is_subscription_command(cmd) || is_transaction_command(cmd)
+}
+
+/// Returns true for the commands that change the state of the connection they are sent on, rather than of the node.
+fn is_connection_state_command(cmd: &Cmd) -> bool {
+    matches!(
+        cmd.command().as_deref(),
+        Some(b"AUTH" | b"READONLY" | b"READWRITE" | b"SELECT" | b"CLIENT SETNAME")
+    )
+}
+
+/// Records the latency of a request of `request_type` sent to the node at `address`.
+fn record_command_latency(request_type: &str, address: &str, latency: Duration) {
+    if let Err(e) = GlideOpenTelemetry::record_command_latency(request_type, address, latency) {
+        log_error(
+            "OpenTelemetry:command_latency",
+            format!("Failed to record command latency: {e}"),
+        );
+    }
+}
+
+/// Records the outcome of a request whose circuit breaker permit, if any, was acquired.
+fn record_permit<T>(permit: Option<CircuitBreakerPermit>, result: &RedisResult<T>) {
+    if let Some(permit) = permit {
+        permit.record(result);
+    }
+}
+
+/// Releases the circuit breaker permit, if any, of a request that was abandoned.
+fn cancel_permit(permit: Option<CircuitBreakerPermit>) {
+    if let Some(permit) = permit {
+        permit.cancel();
+    }
+}
+
 /// This represents an async Cluster connection. It stores the
 /// underlying connections maintained for each node in the cluster, as well
 /// as common parameters for connecting to nodes and executing commands.
@@ -263,7 +330,9 @@ where
             })
             .map(|response| match response {
                 Response::ClusterScanResult(new_scan_state_ref, key) => (new_scan_state_ref, key),
-                Response::Single(_) | Response::Multiple(_) => unreachable!(),
+                Response::Single(_) | Response::Multiple(_) | Response::ConnectionInfo(_) => {
+                    unreachable!()
+                }
             })
     }
 
@@ -302,7 +371,9 @@ where
             })
             .map(|response| match response {
                 Response::Single(value) => value,
-                Response::ClusterScanResult(..) | Response::Multiple(_) => unreachable!(),
+                Response::ClusterScanResult(..)
+                | Response::Multiple(_)
+                | Response::ConnectionInfo(_) => unreachable!(),
             })
     }
 
@@ -347,7 +418,9 @@ where
             })
             .map(|response| match response {
                 Response::Multiple(values) => values,
-                Response::ClusterScanResult(..) | Response::Single(_) => unreachable!(),
+                Response::ClusterScanResult(..)
+                | Response::Single(_)
+                | Response::ConnectionInfo(_) => unreachable!(),
             })
     }
     /// Update the password used to authenticate with all cluster servers
@@ -413,11 +486,41 @@ where
         self.route_operation_request(Operation::GetUsername).await
     }
 
+    /// Get the connection info of the primary node serving `route`, e.g. to open a dedicated connection to it.
+    /// The returned info reflects the current credentials, database and TLS settings of the cluster connections.
+    pub async fn get_connection_info_for_route(
+        &mut self,
+        route: Route,
+    ) -> RedisResult<ConnectionInfo> {
+        self.send_operation_request(Operation::GetConnectionInfoForRoute(route))
+            .await
+            .map(|response| match response {
+                Response::ConnectionInfo(connection_info) => *connection_info,
+                Response::Single(_) | Response::ClusterScanResult(..) | Response::Multiple(_) => {
+                    unreachable!()
+                }
+            })
+    }
+
     /// Routes an operation request to the appropriate handler.
     async fn route_operation_request(
         &mut self,
         operation_request: Operation,
     ) -> RedisResult<Value> {
+        self.send_operation_request(operation_request)
+            .await
+            .map(|response| match response {
+                Response::Single(values) => values,
+                Response::ClusterScanResult(..)
+                | Response::Multiple(_)
+                | Response::ConnectionInfo(_) => unreachable!(),
+            })
+    }
+
+    async fn send_operation_request(
+        &mut self,
+        operation_request: Operation,
+    ) -> RedisResult<Response> {
         let (sender, receiver) = oneshot::channel();
         self.0
             .send(Message {
@@ -427,18 +530,12 @@ where
             .await
             .map_err(|_| RedisError::from(io::Error::from(io::ErrorKind::BrokenPipe)))?;
 
-        receiver
-            .await
-            .unwrap_or_else(|err| {
-                Err(RedisError::from(io::Error::new(
-                    io::ErrorKind::BrokenPipe,
-                    err.to_string(),
-                )))
-            })
-            .map(|response| match response {
-                Response::Single(values) => values,
-                Response::ClusterScanResult(..) | Response::Multiple(_) => unreachable!(),
-            })
+        receiver.await.unwrap_or_else(|err| {
+            Err(RedisError::from(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                err.to_string(),
+            )))
+        })
     }
 }
 
@@ -493,6 +590,8 @@ pub(crate) struct InnerCore<C> {
     /// This prevents validation from removing connections that were just created
     /// during topology discovery but haven't been assigned slots yet.
     pub(crate) topology_refresh_lock: tokio::sync::Mutex<()>,
+    /// Set when hedged reads are enabled.
+    hedged_reads: Option<HedgedReads>,
 }
 
 pub(crate) type Core<C> = Arc<InnerCore<C>>;
@@ -584,6 +683,10 @@ pub(crate) struct ClusterConnInner<C> {
     periodic_checks_handler: Option<JoinHandle<()>>,
     // Handler of fast connection validation task
     connections_validation_handler: Option<JoinHandle<()>>,
+    // Handler of the latency probe task, used by the lowest-latency read strategy
+    latency_probe_handler: Option<JoinHandle<()>>,
+    // Handler of the task that re-resolves the nodes' hostnames
+    dns_refresh_handler: Option<JoinHandle<()>>,
 }
 
 impl<C> Dispose for ClusterConnInner<C> {
@@ -607,6 +710,16 @@ impl<C> Dispose for ClusterConnInner<C> {
             handle.abort()
         }
 
+        if let Some(handle) = self.latency_probe_handler {
+            #[cfg(feature = "tokio-comp")]
+            handle.abort()
+        }
+
+        if let Some(handle) = self.dns_refresh_handler {
+            #[cfg(feature = "tokio-comp")]
+            handle.abort()
+        }
+
         // Reduce the number of clients
         Telemetry::decr_total_clients(1);
     }
@@ -733,6 +846,7 @@ enum Operation {
     UpdateConnectionUsername(Option<String>),
     UpdateConnectionProtocol(ProtocolVersion),
     GetUsername,
+    GetConnectionInfoForRoute(Route),
 }
 
 fn boxed_sleep(duration: Duration) -> BoxFuture<'static, ()> {
@@ -744,6 +858,7 @@ pub(crate) enum Response {
     Single(Value),
     ClusterScanResult(ScanStateRC, Vec<Value>),
     Multiple(Vec<Value>),
+    ConnectionInfo(Box<ConnectionInfo>),
 }
 
 #[derive(Debug)]
@@ -925,6 +1040,7 @@ struct InflightSlotGuard(Arc<AtomicIsize>);
 impl Drop for InflightSlotGuard {
     fn drop(&mut self) {
         self.0.fetch_add(1, Ordering::SeqCst);
+        Telemetry::decr_total_inflight_requests(1);
     }
 }
 
@@ -949,6 +1065,7 @@ impl InflightRequestTracker {
                 .compare_exchange(current, current - 1, Ordering::SeqCst, Ordering::SeqCst)
                 .is_ok()
             {
+                Telemetry::incr_total_inflight_requests(1);
                 return Some(Self {
                     _guard: Arc::new(InflightSlotGuard(counter)),
                 });
@@ -1052,6 +1169,7 @@ mod iam_token_refresh_tests {
             tcp_nodelay: false,
             pubsub_synchronizer: None,
             iam_token_provider: provider,
+            dns_resolver: None,
         }
     }
 
@@ -1077,6 +1195,7 @@ mod iam_token_refresh_tests {
             initial_nodes: Vec::new(),
             glide_connection_options: options_with_provider(provider),
             topology_refresh_lock: tokio::sync::Mutex::new(()),
+            hedged_reads: None,
         })
     }
 
@@ -1438,6 +1557,7 @@ where
             tcp_nodelay: cluster_params.tcp_nodelay,
             pubsub_synchronizer,
             iam_token_provider,
+            dns_resolver: cluster_params.dns_resolver.clone(),
         };
 
         let connections = Self::create_initial_connections(
@@ -1456,6 +1576,11 @@ where
                 connections,
                 cluster_params.read_from_replicas.clone(),
                 0,
+                Default::default(),
+                cluster_params.connection_selection_strategy,
+                cluster_params
+                    .circuit_breaker
+                    .map(|config| Arc::new(CircuitBreaker::new(config))),
             )),
             cluster_params: ParkingLotRwLock::new(cluster_params.clone()),
             pending_requests_tx: pending_tx,
@@ -1464,6 +1589,7 @@ where
             initial_nodes: initial_nodes.to_vec(),
             glide_connection_options,
             topology_refresh_lock: tokio::sync::Mutex::new(()),
+            hedged_reads: cluster_params.hedged_reads.map(HedgedReads::new),
         });
         let mut connection = ClusterConnInner {
             inner,
@@ -1472,6 +1598,8 @@ where
             state: ConnectionState::PollComplete,
             periodic_checks_handler: None,
             connections_validation_handler: None,
+            latency_probe_handler: None,
+            dns_refresh_handler: None,
         };
         // Initial slots and subscriptions refresh
         Self::refresh_slots_and_subscriptions_with_retries(
@@ -1501,6 +1629,29 @@ where
             }
         }
 
+        if let Some(duration) = cluster_params.dns_refresh_interval {
+            let dns_refresh_task =
+                ClusterConnInner::dns_refresh_task(connection.inner.clone(), duration);
+            #[cfg(feature = "tokio-comp")]
+            {
+                connection.dns_refresh_handler = Some(tokio::spawn(dns_refresh_task));
+            }
+        }
+
+        if matches!(
+            cluster_params.read_from_replicas,
+            crate::cluster_slotmap::ReadFromReplicaStrategy::LowestLatency(_)
+        ) {
+            let latency_probe_task = ClusterConnInner::latency_probe_task(
+                connection.inner.clone(),
+                LATENCY_PROBE_INTERVAL,
+            );
+            #[cfg(feature = "tokio-comp")]
+            {
+                connection.latency_probe_handler = Some(tokio::spawn(latency_probe_task));
+            }
+        }
+
         // New client added
         Telemetry::incr_total_clients(1);
         Ok(Disposable::new(connection))
@@ -1511,6 +1662,7 @@ where
     /// Returns a vector of tuples, each containing a node's address (including the hostname) and its corresponding SocketAddr if retrieved.
     pub(crate) async fn try_to_expand_initial_nodes(
         initial_nodes: &[ConnectionInfo],
+        dns_resolver: Option<&dyn DnsResolver>,
     ) -> Vec<(String, Option<SocketAddr>)> {
         stream::iter(initial_nodes)
             .fold(
@@ -1530,7 +1682,7 @@ where
                             return acc;
                         }
                     };
-                    match get_socket_addrs(host, *port).await {
+                    match get_socket_addrs(host, *port, dns_resolver).await {
                         Ok(socket_addrs) => {
                             for addr in socket_addrs {
                                 acc.push((info.addr.to_string(), Some(addr)));
@@ -1552,8 +1704,11 @@ where
         params: &ClusterParams,
         glide_connection_options: GlideConnectionOptions,
     ) -> RedisResult<ConnectionMap<C>> {
-        let initial_nodes: Vec<(String, Option<SocketAddr>)> =
-            Self::try_to_expand_initial_nodes(initial_nodes).await;
+        let initial_nodes: Vec<(String, Option<SocketAddr>)> = Self::try_to_expand_initial_nodes(
+            initial_nodes,
+            glide_connection_options.dns_resolver.as_deref(),
+        )
+        .await;
         let connections =
             stream::iter(initial_nodes.iter().cloned())
                 .map(|(node_addr, socket_addr)| {
@@ -1691,7 +1846,7 @@ where
             }
         };
 
-        let mut all_valid_conns = HashMap::new();
+        let mut all_valid_conns: HashMap<String, Vec<_>> = HashMap::new();
         // prep connections and clean out these w/o assigned slots, as we might have established connections to unwanted hosts
         let mut nodes_to_delete = Vec::new();
         let all_nodes_with_slots: HashSet<Arc<String>>;
@@ -1700,13 +1855,14 @@ where
 
             all_nodes_with_slots = connections_container.slot_map.all_node_addresses();
 
+            // the first user connection of each node comes first
             connections_container
-                .all_node_connections()
+                .all_node_user_connections()
                 .for_each(|(addr, con)| {
                     if all_nodes_with_slots.contains(&addr) {
-                        all_valid_conns.insert(addr.clone(), con.clone());
+                        all_valid_conns.entry(addr).or_default().push(con);
                     } else {
-                        nodes_to_delete.push(addr.clone());
+                        nodes_to_delete.push(addr);
                     }
                 });
 
@@ -1716,16 +1872,34 @@ where
         }
 
         // identify nodes with closed connection
+        let connections_per_node = inner.get_cluster_param(|params| params.connections_per_node);
         let mut addrs_to_refresh = HashSet::new();
-        for (addr, con_fut) in &all_valid_conns {
-            let con = con_fut.clone().await;
-            // connection object might be present despite the transport being closed
-            if con.is_closed() {
+        let mut addrs_to_refresh_user_conns = HashSet::new();
+        for (addr, con_futs) in &all_valid_conns {
+            let mut closed = Vec::with_capacity(con_futs.len());
+            for con_fut in con_futs {
+                // connection object might be present despite the transport being closed
+                closed.push(con_fut.clone().await.is_closed());
+            }
+            if closed.first().copied().unwrap_or(true) {
                 // transport is closed, need to refresh
                 addrs_to_refresh.insert(addr.clone());
+            } else if closed.contains(&true) || con_futs.len() < connections_per_node {
+                // an additional user connection is closed or missing, reopen only the additional ones
+                addrs_to_refresh_user_conns.insert(addr.clone());
             }
         }
 
+        if !addrs_to_refresh_user_conns.is_empty() {
+            Self::trigger_refresh_connection_tasks(
+                inner.clone(),
+                addrs_to_refresh_user_conns,
+                RefreshConnectionType::AdditionalUserConnections,
+                true,
+            )
+            .await;
+        }
+
         // identify missing nodes
         addrs_to_refresh.extend(
             all_nodes_with_slots
@@ -1961,7 +2135,9 @@ where
         // Helper: extract a single Value from a Response::Single
         let extract_result = |response| match response {
             Response::Single(value) => value,
-            Response::Multiple(_) | Response::ClusterScanResult(_, _) => unreachable!(
+            Response::Multiple(_)
+            | Response::ClusterScanResult(_, _)
+            | Response::ConnectionInfo(_) => unreachable!(
                 "aggregate_results only handles `Response::Single` for multi-node commands"
             ),
         };
@@ -2441,6 +2617,116 @@ where
         }
     }
 
+    async fn dns_refresh_task(inner: Arc<InnerCore<C>>, interval_duration: Duration) {
+        loop {
+            let _ = boxed_sleep(interval_duration).await;
+            Self::migrate_connections_of_changed_hostnames(inner.clone()).await;
+        }
+    }
+
+    /// Resolves the hostnames that nodes are addressed by, and replaces the connections of the nodes whose IP isn't
+    /// among the resolved addresses anymore. The replaced connections are dropped once the requests that were sent on
+    /// them got their responses, so these requests are drained instead of failed.
+    async fn migrate_connections_of_changed_hostnames(inner: Arc<InnerCore<C>>) {
+        let nodes = inner
+            .conn_lock
+            .read()
+            .connection_map()
+            .iter()
+            .filter_map(|item| Some((item.key().clone(), item.value().user_connection.ip?)))
+            .collect::<Vec<_>>();
+        let dns_resolver = inner.glide_connection_options.dns_resolver.as_deref();
+
+        for (address, ip) in nodes {
+            let Some((host, port)) = get_host_and_port_from_addr(&address) else {
+                continue;
+            };
+            if host.parse::<IpAddr>().is_ok() {
+                continue;
+            }
+            let mut socket_addrs = match get_socket_addrs(host, port, dns_resolver).await {
+                Ok(socket_addrs) => socket_addrs,
+                Err(err) => {
+                    // The current connections are kept until the hostname resolves again.
+                    warn!(
+                        "Failed to resolve `{}` during DNS refresh: {:?}",
+                        address, err
+                    );
+                    continue;
+                }
+            };
+            if socket_addrs.any(|socket_addr| socket_addr.ip() == ip) {
+                continue;
+            }
+
+            info!(
+                "The address of node `{}` changed from {}, moving its connections",
+                address, ip
+            );
+            let cluster_params = inner.get_cluster_param(|params| params.clone());
+            match connect_and_check::<C>(
+                &address,
+                cluster_params,
+                None,
+                RefreshConnectionType::AllConnections,
+                None,
+                inner.glide_connection_options.clone(),
+            )
+            .await
+            .get_node()
+            {
+                Ok(node) => {
+                    inner
+                        .conn_lock
+                        .read()
+                        .replace_or_add_connection_for_address(&address, node);
+                }
+                Err(err) => warn!(
+                    "Failed to connect to the new address of node `{}`, retrying on the next DNS refresh: {:?}",
+                    address, err
+                ),
+            }
+        }
+    }
+
+    async fn latency_probe_task(inner: Arc<InnerCore<C>>, interval_duration: Duration) {
+        loop {
+            let _ = boxed_sleep(interval_duration).await;
+            Self::probe_node_latencies(inner.clone()).await;
+        }
+    }
+
+    /// Sends a PING to every node and records its round-trip time.
+    /// Nodes that fail to respond are dropped from the tracker, so reads move away from them.
+    async fn probe_node_latencies(inner: Arc<InnerCore<C>>) {
+        let (latency_tracker, connections) = {
+            let connections_container = inner.conn_lock.read();
+            (
+                connections_container.latency_tracker.clone(),
+                connections_container
+                    .all_node_management_connections()
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let probes = connections.into_iter().map(|(address, conn)| async move {
+            let mut conn = conn.await;
+            let start = Instant::now();
+            let result = conn.req_packed_command(&cmd("PING")).await;
+            (address, result.map(|_| start.elapsed()))
+        });
+
+        for (address, result) in futures::future::join_all(probes).await {
+            match result {
+                Ok(rtt) => latency_tracker.record(&address, rtt),
+                Err(err) => {
+                    debug!("Latency probe to {address} failed: {err:?}");
+                    latency_tracker.remove(&address);
+                }
+            }
+        }
+    }
+
     /// Queries log2n nodes (where n represents the number of cluster nodes) to determine whether their
     /// topology view differs from the one currently stored in the connection manager.
     /// Returns true if change was detected, otherwise false.
@@ -2544,21 +2830,25 @@ where
                             // If it's a DNS endpoint, it could have been stored in the existing connections vector
                             // using the resolved IP address instead of the DNS endpoint's name.
                             // We shall check if a connection already exists under the resolved IP name.
-                            let conn = if let Some((host, port)) =
-                                get_host_and_port_from_addr(&addr)
-                            {
-                                if let Ok(mut socket_addresses) = get_socket_addrs(host, port).await
-                                {
-                                    let conn_lock = inner.conn_lock.read();
-                                    socket_addresses.find_map(|socket_addr| {
-                                        conn_lock.node_for_address(&socket_addr.to_string())
-                                    })
+                            let conn =
+                                if let Some((host, port)) = get_host_and_port_from_addr(&addr) {
+                                    if let Ok(mut socket_addresses) = get_socket_addrs(
+                                        host,
+                                        port,
+                                        inner.glide_connection_options.dns_resolver.as_deref(),
+                                    )
+                                    .await
+                                    {
+                                        let conn_lock = inner.conn_lock.read();
+                                        socket_addresses.find_map(|socket_addr| {
+                                            conn_lock.node_for_address(&socket_addr.to_string())
+                                        })
+                                    } else {
+                                        None
+                                    }
                                 } else {
                                     None
-                                }
-                            } else {
-                                None
-                            };
+                                };
 
                             // If we found a connection by IP lookup, update the PushManager. This ensures
                             // the PushManager stores the DNS address (which matches the connection_map key)
@@ -2607,13 +2897,23 @@ where
         // Clear the refresh tasks of the prev instance
         // TODO - Maybe we can take the running refresh tasks and use them instead of running new connection creation
         write_guard.refresh_conn_state.clear_refresh_state();
-        let read_from_replicas =
-            inner.get_cluster_param(|params| params.read_from_replicas.clone());
+        let (read_from_replicas, connection_selection_strategy) =
+            inner.get_cluster_param(|params| {
+                (
+                    params.read_from_replicas.clone(),
+                    params.connection_selection_strategy,
+                )
+            });
+        let latency_tracker = write_guard.latency_tracker.clone();
+        let circuit_breaker = write_guard.circuit_breaker.clone();
         *write_guard = ConnectionsContainer::new(
             new_slots,
             new_connections,
             read_from_replicas,
             topology_hash,
+            latency_tracker,
+            connection_selection_strategy,
+            circuit_breaker,
         );
 
         // Notify the PubSub synchronizer about the new topology (using same lock)
@@ -2622,6 +2922,9 @@ where
         if let Some(sync) = &inner.glide_connection_options.pubsub_synchronizer {
             sync.handle_topology_refresh(&write_guard.slot_map);
         }
+        if let Some(listener) = inner.get_cluster_param(|params| params.topology_listener.clone()) {
+            listener.on_topology_refresh(&write_guard.slot_map.all_node_addresses());
+        }
 
         Ok(())
     }
@@ -2765,6 +3068,14 @@ where
             }
 
             (receivers, requests) = match routing {
+                // the state set by these commands is per connection, so every user connection of every node gets it
+                MultipleNodeRoutingInfo::AllNodes if is_connection_state_command(cmd) => {
+                    into_channels(
+                        connections_container
+                            .all_node_user_connections()
+                            .map(|tuple| Some((cmd.clone(), tuple))),
+                    )
+                }
                 MultipleNodeRoutingInfo::AllNodes => into_channels(
                     connections_container
                         .all_node_connections()
@@ -2780,11 +3091,12 @@ where
                         connections_container
                             .connection_for_route(route)
                             .map(|tuple| {
-                                let new_cmd =
+                                let mut new_cmd =
                                     crate::cluster_routing::command_for_multi_slot_indices(
                                         cmd.as_ref(),
                                         indices.iter(),
                                     );
+                                new_cmd.set_request_type(cmd.request_type());
                                 (Arc::new(new_cmd), tuple)
                             })
                     }))
@@ -2819,26 +3131,263 @@ where
 
             InternalRoutingInfo::SingleNode(routing) => routing,
         };
+        if matches!(routing, InternalSingleNodeRouting::SpecificNode(_))
+            && core.get_cluster_param(|params| params.cross_slot_emulation)
+        {
+            if let Some(cross_slot_cmd) = CrossSlotCommand::parse(&cmd) {
+                return Self::execute_cross_slot_command(cross_slot_cmd, core).await;
+            }
+        }
         trace!("route request to single node");
 
-        let (address, mut conn) = Self::get_connection(routing, core, Some(cmd.clone()))
-            .await
-            .map_err(|err| (OperationTarget::NotFound, err))?;
+        let hedged_route = match &routing {
+            InternalSingleNodeRouting::SpecificNode(route)
+                if core.hedged_reads.is_some()
+                    && cmd
+                        .command()
+                        .is_some_and(|command| cluster_routing::is_readonly_cmd(&command)) =>
+            {
+                Some(*route)
+            }
+            _ => None,
+        };
+        let pin_to_first_connection = is_pinned_to_first_connection(&cmd);
+        let (address, mut conn) = Self::get_connection(
+            routing,
+            core.clone(),
+            Some(cmd.clone()),
+            pin_to_first_connection,
+        )
+        .await
+        .map_err(|err| (OperationTarget::NotFound, err))?;
         if let Some(span) = cmd.span() {
             set_routed_node_on_span(&span, &address);
         }
 
-        conn.req_packed_command(&cmd)
-            .await
+        let permit = Self::acquire_circuit_breaker_permit(&core, &address)
+            .map_err(|err| (address.clone().into(), err))?;
+        let start = Instant::now();
+        let (address, result) = match hedged_route.zip(core.hedged_reads.as_ref()) {
+            Some((route, hedged_reads)) => {
+                Self::send_hedged_read(&cmd, &route, &core, hedged_reads, (address, conn), permit)
+                    .await
+            }
+            None => {
+                let result = conn.req_packed_command(&cmd).await;
+                if let Some(permit) = permit {
+                    permit.record(&result);
+                }
+                (address, result)
+            }
+        };
+        if let Some(request_type) = cmd.request_type() {
+            record_command_latency(&request_type, &address, start.elapsed());
+        }
+        result
             .map(Response::Single)
             .map_err(|err| (address.into(), err))
     }
 
+    /// Emulates a set or sorted set command whose keys are in different slots. Every key is fetched by its own request,
+    /// which is retried like any other request, and the result is computed once all keys were fetched.
+    ///
+    /// The result depends on every key, so the first fetch that still fails after its retries fails the command with
+    /// its error, and the other fetches are dropped.
+    async fn execute_cross_slot_command(
+        cross_slot_cmd: CrossSlotCommand,
+        core: Core<C>,
+    ) -> OperationResult {
+        trace!("execute_cross_slot_command");
+        let fetches =
+            cross_slot_cmd
+                .fetch_commands()
+                .enumerate()
+                .map(|(index, (fetch_cmd, route))| {
+                    let (cross_slot_cmd, core) = (&cross_slot_cmd, &core);
+                    async move {
+                        match Self::fetch_cross_slot_key(core, fetch_cmd, route).await {
+                            // Sorted set commands also read sets, whose members all have a score of 1.
+                            Err(err) if err.code() == Some("WRONGTYPE") => {
+                                match cross_slot_cmd.set_fetch_command(index) {
+                                    Some((set_cmd, route)) => {
+                                        Self::fetch_cross_slot_key(core, set_cmd, route)
+                                            .await
+                                            .map(CrossSlotCommand::set_fetch_response)
+                                    }
+                                    None => Err(err),
+                                }
+                            }
+                            result => result,
+                        }
+                    }
+                });
+        let values = future::try_join_all(fetches)
+            .await
+            .map_err(|err| (OperationTarget::FanOut, err))?;
+
+        let protocol = core.get_cluster_param(|params| params.protocol);
+        cross_slot_cmd
+            .compute(values, protocol)
+            .map(Response::Single)
+            .map_err(|err| (OperationTarget::FanOut, err))
+    }
+
+    /// Fetches a key of a cross-slot command with a request that is retried like any other request.
+    async fn fetch_cross_slot_key(core: &Core<C>, cmd: Cmd, route: Route) -> RedisResult<Value> {
+        let (sender, receiver) = oneshot::channel();
+        let _ = core.pending_requests_tx.send(PendingRequest {
+            retry: 0,
+            sender,
+            info: RequestInfo {
+                cmd: CmdArg::Cmd {
+                    cmd: Arc::new(cmd),
+                    routing: InternalSingleNodeRouting::SpecificNode(route).into(),
+                },
+            },
+        });
+        match receiver.await {
+            Ok(Ok(Response::Single(value))) => Ok(value),
+            Ok(Ok(_)) => Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Unexpected response to a cross-slot key fetch",
+            ))),
+            Ok(Err(err)) => Err(err),
+            Err(_) => Err(RedisError::from((
+                ErrorKind::ResponseError,
+                "Internal failure: receiver was dropped before delivering a response",
+            ))),
+        }
+    }
+
+    /// Sends a read to its node and, if it isn't answered within the hedging delay, also to another node of the
+    /// route's shard. Returns the first successful answer with the address of the node that sent it.
+    ///
+    /// The request that lost is dropped, which releases its pending request on the connection and its reference to
+    /// `cmd`, and with it the request's inflight slot. Each request holds its own circuit breaker permit: the
+    /// winner's outcome is recorded, while the loser's permit is cancelled, so that abandoning it doesn't count as a
+    /// failure of its node. When the first request to complete fails, the other one is awaited for at most the rest
+    /// of the response timeout, after which it counts as a failure of its node. Only the latencies of successful
+    /// reads are recorded for the hedging delay.
+    async fn send_hedged_read(
+        cmd: &Arc<Cmd>,
+        route: &Route,
+        core: &Core<C>,
+        hedged_reads: &HedgedReads,
+        (address, mut conn): (String, C),
+        permit: Option<CircuitBreakerPermit>,
+    ) -> (String, RedisResult<Value>) {
+        let start = Instant::now();
+        let mut first = std::pin::pin!(conn.req_packed_command(cmd));
+
+        let delay = std::pin::pin!(tokio::time::sleep(hedged_reads.delay()));
+        if let future::Either::Left((result, _)) = future::select(first.as_mut(), delay).await {
+            if result.is_ok() {
+                hedged_reads.record(start.elapsed());
+            }
+            record_permit(permit, &result);
+            return (address, result);
+        }
+
+        let hedge_connection = core
+            .conn_lock
+            .read()
+            .hedge_connection_for_route(route, &address);
+        let hedge_connection = match hedge_connection {
+            Some((hedge_address, hedge_conn)) => {
+                // A node whose circuit is open isn't used for hedging.
+                Self::acquire_circuit_breaker_permit(core, &hedge_address)
+                    .ok()
+                    .map(|hedge_permit| (hedge_address, hedge_conn, hedge_permit))
+            }
+            None => None,
+        };
+        let Some((hedge_address, hedge_conn, hedge_permit)) = hedge_connection else {
+            let result = first.await;
+            if result.is_ok() {
+                hedged_reads.record(start.elapsed());
+            }
+            record_permit(permit, &result);
+            return (address, result);
+        };
+        debug!("Hedging read to `{address}` with `{hedge_address}`");
+        let hedge = std::pin::pin!(async move {
+            let mut hedge_conn = hedge_conn.await;
+            hedge_conn.req_packed_command(cmd).await
+        });
+
+        let response_timeout = core.get_cluster_param(|params| params.response_timeout);
+        let remaining_timeout = || response_timeout.saturating_sub(start.elapsed());
+        match future::select(first, hedge).await {
+            future::Either::Left((Ok(value), _)) => {
+                hedged_reads.record(start.elapsed());
+                let result = Ok(value);
+                record_permit(permit, &result);
+                cancel_permit(hedge_permit);
+                (address, result)
+            }
+            future::Either::Left((Err(err), hedge)) => {
+                let result = Err(err);
+                record_permit(permit, &result);
+                match tokio::time::timeout(remaining_timeout(), hedge).await {
+                    Ok(Ok(value)) => {
+                        hedged_reads.record(start.elapsed());
+                        let hedge_result = Ok(value);
+                        record_permit(hedge_permit, &hedge_result);
+                        (hedge_address, hedge_result)
+                    }
+                    Ok(hedge_result) => {
+                        record_permit(hedge_permit, &hedge_result);
+                        (address, result)
+                    }
+                    Err(elapsed) => {
+                        record_permit(hedge_permit, &Err::<Value, _>(elapsed.into()));
+                        (address, result)
+                    }
+                }
+            }
+            future::Either::Right((Ok(value), _)) => {
+                hedged_reads.record(start.elapsed());
+                let result = Ok(value);
+                record_permit(hedge_permit, &result);
+                cancel_permit(permit);
+                (hedge_address, result)
+            }
+            future::Either::Right((hedge_result, first)) => {
+                record_permit(hedge_permit, &hedge_result);
+                match tokio::time::timeout(remaining_timeout(), first).await {
+                    Ok(result) => {
+                        if result.is_ok() {
+                            hedged_reads.record(start.elapsed());
+                        }
+                        record_permit(permit, &result);
+                        (address, result)
+                    }
+                    Err(elapsed) => {
+                        record_permit(permit, &Err::<Value, _>(elapsed.into()));
+                        (hedge_address, hedge_result)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Asks the circuit breaker, if enabled, for permission to send a request to `address`.
+    fn acquire_circuit_breaker_permit(
+        core: &Core<C>,
+        address: &str,
+    ) -> RedisResult<Option<CircuitBreakerPermit>> {
+        let circuit_breaker = core.conn_lock.read().circuit_breaker.clone();
+        circuit_breaker
+            .map(|circuit_breaker| circuit_breaker.try_acquire(address))
+            .transpose()
+    }
+
     async fn try_pipeline_request(
         pipeline: Arc<crate::Pipeline>,
         offset: usize,
         count: usize,
         conn: impl Future<Output = RedisResult<(String, C)>>,
+        core: Core<C>,
     ) -> OperationResult {
         trace!("try_pipeline_request");
         let (address, mut conn) = conn.await.map_err(|err| (OperationTarget::NotFound, err))?;
@@ -2846,8 +3395,17 @@ where
             set_routed_node_on_span(&span, &address);
         }
 
-        conn.req_packed_commands(&pipeline, offset, count, None)
-            .await
+        let permit = Self::acquire_circuit_breaker_permit(&core, &address)
+            .map_err(|err| (address.clone().into(), err))?;
+        let start = Instant::now();
+        let result = conn
+            .req_packed_commands(&pipeline, offset, count, None)
+            .await;
+        record_command_latency(pipeline.request_type(), &address, start.elapsed());
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        result
             .map(Response::Multiple)
             .map_err(|err| (OperationTarget::Node { address }, err))
     }
@@ -2863,16 +3421,19 @@ where
                 sub_pipeline,
                 pipeline_retry_strategy,
             } => {
-                if pipeline.is_atomic() || sub_pipeline {
+                let is_atomic = pipeline.is_atomic();
+                if is_atomic || sub_pipeline {
                     Self::try_pipeline_request(
                         pipeline,
                         offset,
                         count,
                         Self::get_connection(
                             route.unwrap_or(InternalSingleNodeRouting::Random),
-                            core,
+                            core.clone(),
                             None,
+                            is_atomic,
                         ),
+                        core,
                     )
                     .await
                 } else {
@@ -2929,6 +3490,21 @@ where
                     };
                     Ok(Response::Single(username))
                 }
+                Operation::GetConnectionInfoForRoute(route) => {
+                    let Some(address) = core.conn_lock.read().address_for_route(&route) else {
+                        return Err((
+                            OperationTarget::FatalError,
+                            RedisError::from((
+                                ErrorKind::ConnectionNotFoundForRoute,
+                                "No node found for the requested route",
+                            )),
+                        ));
+                    };
+                    let params = core.get_cluster_param(|params| params.clone());
+                    crate::cluster::get_connection_info(&address, params)
+                        .map(|connection_info| Response::ConnectionInfo(Box::new(connection_info)))
+                        .map_err(|err| (OperationTarget::FatalError, err))
+                }
             },
         }
     }
@@ -3073,10 +3649,13 @@ where
         final_responses
     }
 
+    /// Returns the connection the request is sent on. With `pin_to_first_connection`, it is the node's first user
+    /// connection, even when the node has more than one.
     async fn get_connection(
         routing: InternalSingleNodeRouting<C>,
         core: Core<C>,
         cmd: Option<Arc<Cmd>>,
+        pin_to_first_connection: bool,
     ) -> RedisResult<(String, C)> {
         let mut asking = false;
 
@@ -3184,7 +3763,13 @@ where
                 return Ok((address, conn.await));
             }
             InternalSingleNodeRouting::ByAddress(address) => {
-                let conn_option = core.conn_lock.read().connection_for_address(&address);
+                let conn_option = if pin_to_first_connection {
+                    core.conn_lock
+                        .read()
+                        .pinned_connection_for_address(&address)
+                } else {
+                    core.conn_lock.read().connection_for_address(&address)
+                };
                 if let Some((address, conn)) = conn_option {
                     return Ok((address, conn.await));
                 } else {
@@ -3264,6 +3849,16 @@ where
             }
         };
 
+        if pin_to_first_connection {
+            let pinned_conn = core
+                .conn_lock
+                .read()
+                .pinned_connection_for_address(&address);
+            if let Some((_, pinned_conn)) = pinned_conn {
+                conn = pinned_conn.await;
+            }
+        }
+
         if asking {
             let _ = conn.req_packed_command(&crate::cmd::cmd("ASKING")).await;
         }
@@ -3775,8 +4370,11 @@ where
 
     // Resolve initial nodes and select random addresses for topology query.
     let selected_pairs = {
-        let resolved =
-            ClusterConnInner::<C>::try_to_expand_initial_nodes(&inner.initial_nodes).await;
+        let resolved = ClusterConnInner::<C>::try_to_expand_initial_nodes(
+            &inner.initial_nodes,
+            inner.glide_connection_options.dns_resolver.as_deref(),
+        )
+        .await;
         let mut rng = rand::rng();
         resolved
             .into_iter()
@@ -4136,7 +4734,7 @@ mod pipeline_routing_tests {
 
     use futures::executor::block_on;
 
-    use super::pipeline_routing::route_for_pipeline;
+    use super::pipeline_routing::{is_idempotent_cmd, route_for_pipeline};
     use crate::{
         aio::MultiplexedConnection,
         cluster_async::{pipeline_routing::PipelineResponses, ClusterConnInner},
@@ -4165,6 +4763,29 @@ mod pipeline_routing_tests {
         );
     }
 
+    #[test]
+    fn test_idempotent_commands() {
+        assert!(is_idempotent_cmd(cmd("GET").arg("foo")));
+        assert!(is_idempotent_cmd(cmd("SET").arg("foo").arg("bar")));
+        assert!(is_idempotent_cmd(
+            cmd("SET").arg("foo").arg("bar").arg("EX").arg(10)
+        ));
+        // The key and the value aren't options.
+        assert!(is_idempotent_cmd(cmd("SET").arg("get").arg("nx")));
+        assert!(is_idempotent_cmd(
+            cmd("MSET").arg("a").arg(1).arg("b").arg(2)
+        ));
+
+        assert!(!is_idempotent_cmd(
+            cmd("SET").arg("foo").arg("bar").arg("GET")
+        ));
+        assert!(!is_idempotent_cmd(
+            cmd("SET").arg("foo").arg("bar").arg("nx")
+        ));
+        assert!(!is_idempotent_cmd(cmd("INCR").arg("foo")));
+        assert!(!is_idempotent_cmd(cmd("LPUSH").arg("foo").arg("bar")));
+    }
+
     #[test]
     fn test_numerical_response_aggregation_logic() {
         let pipeline_responses: PipelineResponses = vec![
@@ -1,6 +1,7 @@
 use crate::aio::ConnectionLike;
 use crate::cluster_async::ClusterConnInner;
 use crate::cluster_async::Connect;
+use crate::cluster_routing::Routable;
 use crate::cluster_routing::RoutingInfo;
 use crate::cluster_routing::SlotAddr;
 use crate::cluster_routing::{
@@ -8,6 +9,7 @@ use crate::cluster_routing::{
 };
 use crate::types::{RetryMethod, ServerError};
 use crate::Pipeline;
+use crate::NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE;
 use crate::{cluster_routing, RedisResult, Value};
 use crate::{cluster_routing::Route, Cmd, ErrorKind, RedisError};
 use cluster_routing::RoutingInfo::{MultiNode, SingleNode};
@@ -22,6 +24,7 @@ use tokio::sync::oneshot;
 use tokio::sync::oneshot::error::RecvError;
 
 use super::boxed_sleep;
+use super::is_pinned_to_first_connection;
 use super::testing::RefreshConnectionType;
 use super::CmdArg;
 use super::PendingRequest;
@@ -166,9 +169,10 @@ where
     if let Some(route) = route {
         // If we have a route, we will use it to route the commands to the given route, instead of finding the route for each command
 
-        let (addr, conn) = ClusterConnInner::get_connection(route, core, None)
-            .await
-            .map_err(|err| (OperationTarget::NotFound, err))?;
+        let (addr, conn) =
+            ClusterConnInner::get_connection(route, core, None, pipeline.is_atomic())
+                .await
+                .map_err(|err| (OperationTarget::NotFound, err))?;
 
         let entry = pipelines_per_node
             .entry(addr)
@@ -288,9 +292,11 @@ where
         }
     }
 
-    let (address, conn) = ClusterConnInner::get_connection(routing, core, Some(cmd.clone()))
-        .await
-        .map_err(|err| (OperationTarget::NotFound, err))?;
+    let pin_to_first_connection = is_pinned_to_first_connection(&cmd);
+    let (address, conn) =
+        ClusterConnInner::get_connection(routing, core, Some(cmd.clone()), pin_to_first_connection)
+            .await
+            .map_err(|err| (OperationTarget::NotFound, err))?;
 
     add_command_to_node_pipeline_map(pipeline_map, address, conn, cmd, index, None, false, false);
     Ok(())
@@ -531,6 +537,58 @@ fn add_pipeline_result(
     }
 }
 
+/// Returns `true` if executing the command twice leaves the same data and returns the same response as executing it
+/// once, so it's safe to retry after a connection error even though the server may already have executed it.
+///
+/// Read-only commands are idempotent, and so are the commands that only overwrite values, such as `SET` without `GET`.
+/// Commands whose effect depends on the existing data, such as `INCR` or `LPUSH`, are not.
+pub(crate) fn is_idempotent_cmd(cmd: &Cmd) -> bool {
+    let Some(command) = cmd.command() else {
+        return false;
+    };
+    if cluster_routing::is_readonly_cmd(&command) {
+        return true;
+    }
+    match command.as_slice() {
+        // `GET` returns the previous value, and `NX` fails once the key exists.
+        b"SET" => !(3..)
+            .map_while(|index| cmd.arg_idx(index))
+            .any(|arg| arg.eq_ignore_ascii_case(b"GET") || arg.eq_ignore_ascii_case(b"NX")),
+        b"MSET" | b"SETEX" | b"PSETEX" | b"HMSET" => true,
+        _ => false,
+    }
+}
+
+/// Returns the retry method of a pipeline command that failed with `retry_method`, according to the retry strategy
+/// for connection errors.
+fn connection_error_retry_method(
+    retry_method: RetryMethod,
+    is_idempotent: bool,
+    pipeline_retry_strategy: PipelineRetryStrategy,
+) -> RetryMethod {
+    match retry_method {
+        RetryMethod::Reconnect
+            if pipeline_retry_strategy.retry_connection_error
+                || (pipeline_retry_strategy.retry_idempotent_connection_error && is_idempotent) =>
+        {
+            RetryMethod::ReconnectAndRetry
+        }
+        _ => retry_method,
+    }
+}
+
+/// Returns the response of a command that isn't retried after `error` because it isn't idempotent.
+fn not_retried_error(error: &ServerError) -> ServerError {
+    ServerError::ExtensionError {
+        code: NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE.to_string(),
+        detail: Some(format!(
+            "Command isn't idempotent and wasn't retried after a connection error: {} {}",
+            error.err_code(),
+            error.details().unwrap_or_default()
+        )),
+    }
+}
+
 /// A retry entry representing a failed command in the pipeline.
 ///
 /// This tuple contains:
@@ -563,9 +621,11 @@ type RetryMap = HashMap<RetryMethod, Vec<RetryEntry>>;
 ///   `RedisResult<Response>` or a `RecvError`.
 /// - `addresses_and_indices`: A collection of pairs where each pair associates a node address with the indices
 ///   of commands in the pipeline that were sent to that node.
+/// - `pipeline`: The original pipeline, used to check whether the failed commands are idempotent.
 /// - `pipeline_retry_strategy`: Configures retry behavior for pipeline commands.  
 ///   - `retry_server_error`: If `true`, retries commands on server errors (may cause reordering).  
 ///   - `retry_connection_error`: If `true`, retries on connection errors (may lead to duplicate executions).  
+///   - `retry_idempotent_connection_error`: If `true`, retries only the idempotent commands on connection errors.  
 ///
 /// # Returns
 ///
@@ -576,9 +636,15 @@ fn process_pipeline_responses(
     pipeline_responses: &mut PipelineResponses,
     responses: Vec<Result<RedisResult<Response>, RecvError>>,
     addresses_and_indices: AddressAndIndices,
+    pipeline: &Pipeline,
     pipeline_retry_strategy: PipelineRetryStrategy,
 ) -> Result<RetryMap, (OperationTarget, RedisError)> {
     let mut retry_map: RetryMap = HashMap::new();
+    let is_idempotent = |index: usize| {
+        pipeline
+            .get_command(index)
+            .is_some_and(|cmd| is_idempotent_cmd(&cmd))
+    };
     for ((address, command_indices), response_result) in
         addresses_and_indices.into_iter().zip(responses)
     {
@@ -590,7 +656,11 @@ fn process_pipeline_responses(
                     // If the commands response is not marked to be ignored
                     if let Value::ServerError(error) = &value {
                         // Convert error and determine retry method
-                        let retry_method = RedisError::from(error.clone()).retry_method();
+                        let retry_method = connection_error_retry_method(
+                            RedisError::from(error.clone()).retry_method(),
+                            is_idempotent(index),
+                            pipeline_retry_strategy,
+                        );
                         update_retry_map(
                             &mut retry_map,
                             retry_method,
@@ -634,6 +704,14 @@ fn process_pipeline_responses(
                 },
                 RetryMethod::NoRetry,
             ),
+            // We are not supposed to get in here, but it's better than using unreachable!()
+            Ok(Ok(Response::ConnectionInfo(_))) => (
+                ServerError::ExtensionError {
+                    code: ("ConnectionInfoError".to_string()),
+                    detail: (Some("Received a connection info inside a pipeline.".to_string())),
+                },
+                RetryMethod::NoRetry,
+            ),
 
             // If we received a redis error, we will convert it to a ServerError and append it to the relevant indices
             Ok(Err(err)) => {
@@ -659,21 +737,34 @@ fn process_pipeline_responses(
 
         // Add the error to the matching indices in the pipeline_responses
         for (index, inner_index, ignore) in command_indices {
-            // If the commands response is not marked to be ignored
+            let command_retry_method = connection_error_retry_method(
+                retry_method,
+                is_idempotent(index),
+                pipeline_retry_strategy,
+            );
+            // Only idempotent commands are retried, the other ones are reported as not retried.
+            let not_retried = pipeline_retry_strategy.retry_idempotent_connection_error
+                && command_retry_method == RetryMethod::Reconnect;
             update_retry_map(
                 &mut retry_map,
-                retry_method,
+                command_retry_method,
                 (index, inner_index),
                 address.clone(),
                 server_error.clone(),
                 pipeline_retry_strategy,
             );
+            // If the commands response is not marked to be ignored
             if !ignore {
+                let error = if not_retried {
+                    not_retried_error(&server_error)
+                } else {
+                    server_error.clone()
+                };
                 add_pipeline_result(
                     pipeline_responses,
                     index,
                     inner_index,
-                    Value::ServerError(server_error.clone()),
+                    Value::ServerError(error),
                     address.clone(),
                 )?;
             }
@@ -698,19 +789,12 @@ fn update_retry_map(
             // Do nothing
         }
         RetryMethod::Reconnect | RetryMethod::ReconnectAndRetry => {
-            // If we the retry method is reconnect and the user has set the retry_connection_error flag to true,
-            // we will retry the commands, if not, we will trigger reconnection.
-            let effective_retry_method = if pipeline_retry_strategy.retry_connection_error {
-                RetryMethod::ReconnectAndRetry
-            } else {
-                retry_method
-            };
-
-            retry_map.entry(effective_retry_method).or_default().push((
-                (index, inner_index),
-                address,
-                error,
-            ));
+            // The retry method was already adjusted by `connection_error_retry_method`: commands with
+            // `ReconnectAndRetry` are retried, and the other ones only trigger a reconnection.
+            retry_map
+                .entry(retry_method)
+                .or_default()
+                .push(((index, inner_index), address, error));
         }
         RetryMethod::AskRedirect | RetryMethod::MovedRedirect => {
             // If the error is a redirect, we add it to the retry map regardless
@@ -781,6 +865,7 @@ where
             &mut pipeline_responses,
             responses,
             addresses_and_indices,
+            pipeline,
             pipeline_retry_strategy,
         ) {
             Ok(retry_map) => {
@@ -1084,8 +1169,14 @@ where
             // Retrieve the original command and attempt to get a new connection.
             match get_original_cmd(pipeline, index, inner_index, Some(response_policies)) {
                 Ok(cmd) => {
-                    match ClusterConnInner::get_connection(routing, core.clone(), Some(cmd.clone()))
-                        .await
+                    let pin_to_first_connection = is_pinned_to_first_connection(&cmd);
+                    match ClusterConnInner::get_connection(
+                        routing,
+                        core.clone(),
+                        Some(cmd.clone()),
+                        pin_to_first_connection,
+                    )
+                    .await
                     {
                         Ok((address, conn)) => {
                             // Add the command to the node pipeline map to retry.
@@ -1200,7 +1291,10 @@ where
         };
 
         let routing = InternalSingleNodeRouting::ByAddress(address.clone());
-        let connection = ClusterConnInner::get_connection(routing, core.clone(), None).await;
+        let pin_to_first_connection = is_pinned_to_first_connection(&cmd);
+        let connection =
+            ClusterConnInner::get_connection(routing, core.clone(), None, pin_to_first_connection)
+                .await;
 
         // Add the command to the node pipeline map for retry. Otherwise, append the error.
         match connection {
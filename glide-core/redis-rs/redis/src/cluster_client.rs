@@ -1,13 +1,15 @@
 use crate::cache::glide_cache::GlideCache;
+use crate::circuit_breaker::CircuitBreakerConfig;
 use crate::cluster_slotmap::ReadFromReplicaStrategy;
 #[cfg(feature = "cluster-async")]
 use crate::cluster_topology::{
     DEFAULT_SLOTS_REFRESH_MAX_JITTER_MILLI, DEFAULT_SLOTS_REFRESH_WAIT_DURATION,
 };
 use crate::connection::{ConnectionAddr, ConnectionInfo, IntoConnectionInfo};
+use crate::hedged_reads::HedgingConfig;
 use crate::types::{ErrorKind, ProtocolVersion, RedisError, RedisResult};
 use crate::{cluster, cluster::TlsMode};
-use crate::{PushInfo, RetryStrategy};
+use crate::{DnsResolver, PushInfo, RetryStrategy, TopologyListener};
 use rand::Rng;
 #[cfg(feature = "cluster-async")]
 use std::ops::Add;
@@ -33,6 +35,7 @@ struct BuilderParams {
     read_from_replicas: ReadFromReplicaStrategy,
     tls: Option<TlsMode>,
     certs: Option<TlsCertificates>,
+    tls_params: Option<TlsConnParams>,
     retries_configuration: RetryParams,
     connection_timeout: Option<Duration>,
     #[cfg(feature = "cluster-async")]
@@ -40,6 +43,8 @@ struct BuilderParams {
     #[cfg(feature = "cluster-async")]
     connections_validation_interval: Option<Duration>,
     #[cfg(feature = "cluster-async")]
+    dns_refresh_interval: Option<Duration>,
+    #[cfg(feature = "cluster-async")]
     slots_refresh_rate_limit: SlotsRefreshRateLimit,
     client_name: Option<String>,
     lib_name: Option<String>,
@@ -50,6 +55,24 @@ struct BuilderParams {
     database_id: i64,
     tcp_nodelay: bool,
     cache: Option<Arc<dyn GlideCache>>,
+    dns_resolver: Option<Arc<dyn DnsResolver>>,
+    topology_listener: Option<Arc<dyn TopologyListener>>,
+    connections_per_node: Option<usize>,
+    connection_selection_strategy: ConnectionSelectionStrategy,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    hedged_reads: Option<HedgingConfig>,
+    cross_slot_emulation: bool,
+}
+
+/// Strategy for picking which of a node's user connections serves a request, when more than one
+/// connection per node is configured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConnectionSelectionStrategy {
+    /// Rotate over the node's user connections.
+    #[default]
+    RoundRobin,
+    /// Use the connection with the fewest requests waiting for a response.
+    LeastPending,
 }
 
 #[derive(Clone)]
@@ -143,6 +166,10 @@ pub struct ClusterParams {
     pub(crate) slots_refresh_rate_limit: SlotsRefreshRateLimit,
     #[cfg(feature = "cluster-async")]
     pub(crate) connections_validation_interval: Option<Duration>,
+    /// When set, the hostnames of the nodes are resolved again per this interval, and the connections of the nodes
+    /// whose addresses changed are moved to the new addresses.
+    #[cfg(feature = "cluster-async")]
+    pub(crate) dns_refresh_interval: Option<Duration>,
     pub(crate) tls_params: Option<TlsConnParams>,
     pub(crate) client_name: Option<String>,
     pub(crate) lib_name: Option<String>,
@@ -154,14 +181,29 @@ pub struct ClusterParams {
     pub(crate) database_id: i64,
     pub(crate) tcp_nodelay: bool,
     pub(crate) cache: Option<Arc<dyn GlideCache>>,
+    pub(crate) dns_resolver: Option<Arc<dyn DnsResolver>>,
+    /// Notified of the nodes of the cluster after each refresh of the topology.
+    pub(crate) topology_listener: Option<Arc<dyn TopologyListener>>,
+    /// Number of user connections opened to each node. Subscriptions are always sent on the first one.
+    pub(crate) connections_per_node: usize,
+    pub(crate) connection_selection_strategy: ConnectionSelectionStrategy,
+    /// When set, requests to a node whose circuit is open fail fast instead of waiting for it.
+    pub(crate) circuit_breaker: Option<CircuitBreakerConfig>,
+    /// When set, slow reads that may be served by replicas are also sent to a second node of the shard.
+    pub(crate) hedged_reads: Option<HedgingConfig>,
+    /// When set, read-only set and sorted set commands whose keys are in different slots are computed on the client.
+    pub(crate) cross_slot_emulation: bool,
 }
 
 impl ClusterParams {
     fn from(value: BuilderParams) -> RedisResult<Self> {
-        let tls_params = {
-            let retrieved_tls_params = value.certs.clone().map(retrieve_tls_certificates);
+        let tls_params = match value.tls_params {
+            Some(tls_params) => Some(tls_params),
+            None => {
+                let retrieved_tls_params = value.certs.clone().map(retrieve_tls_certificates);
 
-            retrieved_tls_params.transpose()?
+                retrieved_tls_params.transpose()?
+            }
         };
 
         Ok(Self {
@@ -177,6 +219,8 @@ impl ClusterParams {
             slots_refresh_rate_limit: value.slots_refresh_rate_limit,
             #[cfg(feature = "cluster-async")]
             connections_validation_interval: value.connections_validation_interval,
+            #[cfg(feature = "cluster-async")]
+            dns_refresh_interval: value.dns_refresh_interval,
             tls_params,
             client_name: value.client_name,
             lib_name: value.lib_name,
@@ -187,6 +231,13 @@ impl ClusterParams {
             database_id: value.database_id,
             tcp_nodelay: value.tcp_nodelay,
             cache: value.cache,
+            dns_resolver: value.dns_resolver,
+            topology_listener: value.topology_listener,
+            connections_per_node: value.connections_per_node.unwrap_or(1).max(1),
+            connection_selection_strategy: value.connection_selection_strategy,
+            circuit_breaker: value.circuit_breaker,
+            hedged_reads: value.hedged_reads,
+            cross_slot_emulation: value.cross_slot_emulation,
         })
     }
 }
@@ -208,6 +259,8 @@ impl ClusterParams {
             slots_refresh_rate_limit: Default::default(),
             #[cfg(feature = "cluster-async")]
             connections_validation_interval: None,
+            #[cfg(feature = "cluster-async")]
+            dns_refresh_interval: None,
             tls_params: None,
             client_name: None,
             lib_name: None,
@@ -218,6 +271,13 @@ impl ClusterParams {
             database_id: 0,
             tcp_nodelay: false,
             cache: None,
+            dns_resolver: None,
+            topology_listener: None,
+            connections_per_node: 1,
+            connection_selection_strategy: ConnectionSelectionStrategy::RoundRobin,
+            circuit_breaker: None,
+            hedged_reads: None,
+            cross_slot_emulation: false,
         }
     }
 }
@@ -420,6 +480,16 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Sets already parsed TLS parameters, which take precedence over [`ClusterClientBuilder::certs`].
+    /// Unlike `certs`, the TLS mode set by [`ClusterClientBuilder::tls`] is kept.
+    ///
+    /// Parameters from [`crate::ReloadableTlsConnParams::conn_params`] let new connections pick up rotated
+    /// certificates.
+    pub fn tls_params(mut self, tls_params: TlsConnParams) -> ClusterClientBuilder {
+        self.builder_params.tls_params = Some(tls_params);
+        self
+    }
+
     /// Enables reading from replicas for all new connections (default is disabled).
     ///
     /// If enabled, then read queries will go to the replica nodes & write queries will go to the
@@ -438,6 +508,8 @@ impl ClusterClientBuilder {
     ///  prioritizing local replicas, then the local primary, and falling back to any replica or the primary if needed.
     /// `ReadFromReplicaStrategy::RoundRobin` - reads are distributed across replicas for load balancing using round-robin algorithm. Falling back to primary if needed.
     /// `ReadFromReplicaStrategy::AlwaysFromPrimary` ensures all read and write queries are directed to the primary node.
+    /// `ReadFromReplicaStrategy::LowestLatency(hysteresis)` - reads go to the replica with the lowest average PING latency,
+    /// switching only when another replica is faster by more than `hysteresis`. Falling back to round-robin until latencies are measured.
     ///
     /// # Parameters
     /// - `read_strategy`: defines the replica routing strategy.
@@ -483,6 +555,17 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Enables periodic DNS refreshes for this client.
+    ///
+    /// If enabled, the hostnames that nodes are addressed by are resolved again per the configured interval. The
+    /// connections of a node whose IP isn't among the resolved addresses anymore are replaced by connections to the
+    /// new addresses, while requests already sent on the replaced connections still get their responses.
+    #[cfg(feature = "cluster-async")]
+    pub fn dns_refresh_interval(mut self, interval: Option<Duration>) -> ClusterClientBuilder {
+        self.builder_params.dns_refresh_interval = interval;
+        self
+    }
+
     /// Sets the rate limit for slot refresh operations in the cluster.
     ///
     /// This method configures the interval duration between consecutive slot
@@ -541,6 +624,62 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Sets the number of user connections opened to each node.
+    ///
+    /// Requests to a node are spread over its user connections according to the
+    /// [`ConnectionSelectionStrategy`], while subscriptions are always sent on the first connection.
+    /// Values lower than 1 are treated as 1. Defaults to 1 if not set.
+    pub fn connections_per_node(mut self, connections_per_node: usize) -> ClusterClientBuilder {
+        self.builder_params.connections_per_node = Some(connections_per_node);
+        self
+    }
+
+    /// Sets how a node's user connection is picked for each request, when more than one connection per node is used.
+    ///
+    /// Defaults to [`ConnectionSelectionStrategy::RoundRobin`] if not set.
+    pub fn connection_selection_strategy(
+        mut self,
+        connection_selection_strategy: ConnectionSelectionStrategy,
+    ) -> ClusterClientBuilder {
+        self.builder_params.connection_selection_strategy = connection_selection_strategy;
+        self
+    }
+
+    /// Enables a circuit breaker for each node.
+    ///
+    /// Once the failure rate of the requests to a node reaches the configured threshold, its circuit opens and
+    /// requests to it fail immediately with [`ErrorKind::CircuitOpen`], while reads that may be served by replicas
+    /// are sent to another node of the shard. The circuit closes again once probe requests to the node succeed.
+    /// Disabled if not set.
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> ClusterClientBuilder {
+        self.builder_params.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Enables hedged reads.
+    ///
+    /// A read-only command that the read strategy allows to serve from replicas, and that wasn't answered within the
+    /// configured delay, is also sent to another node of the same shard. The first answer is returned and the slower
+    /// request is cancelled. Disabled if not set.
+    pub fn hedged_reads(mut self, config: HedgingConfig) -> ClusterClientBuilder {
+        self.builder_params.hedged_reads = Some(config);
+        self
+    }
+
+    /// Enables cross-slot emulation.
+    ///
+    /// SUNION, SINTER, SDIFF, SINTERCARD, ZUNION, ZINTER, ZDIFF and ZINTERCARD with keys in different slots then fetch
+    /// every key from its own shard, and the union, intersection or difference is computed on the client, instead of
+    /// failing with a `CROSSSLOT` error. The result isn't atomic, since the keys are read at different times. Like on
+    /// the server, the keys of sorted set commands may also hold sets, whose members have a score of 1. Every key is
+    /// fetched by a request that is retried like any other, e.g. after `TRYAGAIN`, and the command fails if a key
+    /// can't be fetched. Commands that write, like RENAME or SUNIONSTORE, aren't emulated and still fail with
+    /// `CROSSSLOT`. Disabled by default.
+    pub fn cross_slot_emulation(mut self, enabled: bool) -> ClusterClientBuilder {
+        self.builder_params.cross_slot_emulation = enabled;
+        self
+    }
+
     /// Enables timing out on slow connection time.
     ///
     /// If enabled, the cluster will only wait the given time on each connection attempt to each node.
@@ -578,6 +717,25 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Sets the resolver of the nodes' hostnames for the new ClusterClient.
+    /// When not set, the hostnames are resolved by the system resolver.
+    pub fn dns_resolver(
+        mut self,
+        dns_resolver: Option<Arc<dyn DnsResolver>>,
+    ) -> ClusterClientBuilder {
+        self.builder_params.dns_resolver = dns_resolver;
+        self
+    }
+
+    /// Sets a listener that is notified of the nodes of the cluster after each refresh of the topology.
+    pub fn topology_listener(
+        mut self,
+        topology_listener: Option<Arc<dyn TopologyListener>>,
+    ) -> ClusterClientBuilder {
+        self.builder_params.topology_listener = topology_listener;
+        self
+    }
+
     /// Use `build()`.
     #[deprecated(since = "0.22.0", note = "Use build()")]
     pub fn open(self) -> RedisResult<ClusterClient> {
@@ -719,7 +877,10 @@ mod tests {
         DEFAULT_SLOTS_REFRESH_MAX_JITTER_MILLI, DEFAULT_SLOTS_REFRESH_WAIT_DURATION,
     };
 
-    use super::{ClusterClient, ClusterClientBuilder, ConnectionInfo, IntoConnectionInfo};
+    use super::{
+        ClusterClient, ClusterClientBuilder, ConnectionInfo, IntoConnectionInfo, TlsConnParams,
+        TlsMode,
+    };
 
     fn get_connection_data() -> Vec<ConnectionInfo> {
         vec![
@@ -807,6 +968,17 @@ mod tests {
         assert_eq!(client.cluster_params.username, Some("user1".to_string()));
     }
 
+    #[test]
+    fn tls_params_keep_the_tls_mode() {
+        let client = ClusterClientBuilder::new(get_connection_data())
+            .tls(TlsMode::Insecure)
+            .tls_params(TlsConnParams::default())
+            .build()
+            .unwrap();
+        assert!(matches!(client.cluster_params.tls, Some(TlsMode::Insecure)));
+        assert!(client.cluster_params.tls_params.is_some());
+    }
+
     #[test]
     fn give_empty_initial_nodes() {
         let client = ClusterClient::new(Vec::<String>::new());
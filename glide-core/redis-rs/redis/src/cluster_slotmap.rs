@@ -3,6 +3,7 @@ use std::{
     fmt::Display,
     net::IpAddr,
     sync::{atomic::AtomicUsize, Arc},
+    time::Duration,
 };
 
 use dashmap::DashMap;
@@ -44,6 +45,10 @@ pub enum ReadFromReplicaStrategy {
     AZAffinityReplicasAndPrimary(String),
     /// Spread the read requests between all nodes (primary and replicas) in a round robin manner.
     AllNodes,
+    /// Send the read requests to the replica with the lowest average latency, measured by periodic PINGs.
+    /// Reads only move to another replica once it is faster by more than the given hysteresis.
+    /// If no replica was measured yet, the requests are spread in a round robin manner.
+    LowestLatency(Duration),
 }
 
 #[derive(Debug, Default)]
@@ -65,7 +70,8 @@ fn get_address_from_slot(
     }
     match read_from_replica {
         ReadFromReplicaStrategy::AlwaysFromPrimary => addrs.primary(),
-        ReadFromReplicaStrategy::RoundRobin => {
+        // The sync client doesn't measure latencies, so it falls back to round robin.
+        ReadFromReplicaStrategy::RoundRobin | ReadFromReplicaStrategy::LowestLatency(_) => {
             let index = slot
                 .last_used_replica
                 .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
@@ -6,6 +6,7 @@ use futures_util::{
 };
 #[cfg(feature = "aio")]
 use std::pin::Pin;
+use std::sync::Arc;
 use std::{borrow::Borrow, fmt, io};
 
 use crate::pipeline::Pipeline;
@@ -33,6 +34,8 @@ pub struct Cmd {
     no_response: bool,
     /// The span associated with this command
     span: Option<GlideSpan>,
+    /// The request type the command was created for, used as the label of its metrics
+    request_type: Option<Arc<str>>,
     //  A flag indicating whether this is a fenced command  (will have PING appended to ensure ordering)
     is_fenced: bool,
     /// Inflight slot tracker. When set, the slot is released when the last
@@ -359,6 +362,7 @@ impl Cmd {
             cursor: None,
             no_response: false,
             span: None,
+            request_type: None,
             is_fenced: false,
             #[cfg(feature = "cluster-async")]
             inflight_tracker: None,
@@ -373,6 +377,7 @@ impl Cmd {
             cursor: None,
             no_response: false,
             span: None,
+            request_type: None,
             #[cfg(feature = "cluster-async")]
             inflight_tracker: None,
             is_fenced: false,
@@ -655,6 +660,20 @@ impl Cmd {
         self.span.clone()
     }
 
+    /// Set the request type the command was created for, e.g. `Get`.
+    /// Only commands with a request type are recorded in the latency metrics.
+    #[inline]
+    pub fn set_request_type(&mut self, request_type: Option<Arc<str>>) -> &mut Cmd {
+        self.request_type = request_type;
+        self
+    }
+
+    /// Return the request type the command was created for
+    #[inline]
+    pub fn request_type(&self) -> Option<Arc<str>> {
+        self.request_type.clone()
+    }
+
     /// Mark this command as fenced. A PING command will be appended after it
     /// to ensure proper ordering of response processing.
     #[inline]
@@ -54,6 +54,11 @@ const BITS_ARRAY_SIZE: u16 = NUM_OF_SLOTS / BITS_PER_U64;
 const END_OF_SCAN: u16 = NUM_OF_SLOTS;
 type SlotsBitsArray = [u64; BITS_ARRAY_SIZE as usize];
 
+/// Prefix of the string produced by [`ScanStateRC::to_serialized_cursor`].
+const SERIALIZED_CURSOR_PREFIX: &str = "scan:";
+/// Version of the serialized cursor format, bumped whenever the layout changes.
+const SERIALIZED_CURSOR_VERSION: u8 = 1;
+
 /// Holds configuration for a cluster scan operation.
 ///
 /// # Fields
@@ -325,6 +330,111 @@ impl ScanStateRC {
             self.scan_state_rc.as_ref().clone()
         }
     }
+
+    /// Encodes the scan state into an opaque, versioned string.
+    ///
+    /// The string holds the scanned slots, the address being scanned with its epoch and the cursor on that address,
+    /// so it can be stored and later passed to [`ScanStateRC::from_serialized_cursor`] to resume the scan, possibly
+    /// from another process. Topology changes that happened in the meantime are handled the same way as they are
+    /// between two iterations of the same scan.
+    pub fn to_serialized_cursor(&self) -> String {
+        let mut bytes = vec![SERIALIZED_CURSOR_VERSION];
+        match (&self.status, self.scan_state_rc.as_ref()) {
+            (ScanStateStage::InProgress, Some(scan_state)) => {
+                bytes.push(1);
+                scan_state.encode(&mut bytes);
+            }
+            (ScanStateStage::Finished, _) => bytes.push(2),
+            _ => bytes.push(0),
+        }
+        let mut cursor = String::with_capacity(SERIALIZED_CURSOR_PREFIX.len() + bytes.len() * 2);
+        cursor.push_str(SERIALIZED_CURSOR_PREFIX);
+        for byte in bytes {
+            cursor.push_str(&format!("{byte:02x}"));
+        }
+        cursor
+    }
+
+    /// Decodes a string produced by [`ScanStateRC::to_serialized_cursor`].
+    pub fn from_serialized_cursor(cursor: &str) -> RedisResult<Self> {
+        let hex = cursor
+            .strip_prefix(SERIALIZED_CURSOR_PREFIX)
+            .ok_or_else(|| invalid_serialized_cursor("missing prefix"))?;
+        if hex.len() % 2 != 0 || !hex.is_ascii() {
+            return Err(invalid_serialized_cursor("malformed encoding"));
+        }
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| invalid_serialized_cursor("malformed encoding"))?;
+
+        let mut reader = CursorReader { bytes: &bytes };
+        let version = reader.read_u8()?;
+        if version != SERIALIZED_CURSOR_VERSION {
+            return Err(invalid_serialized_cursor(&format!(
+                "unsupported version {version}"
+            )));
+        }
+        let scan_state_rc = match reader.read_u8()? {
+            0 => Self::new(),
+            1 => Self::from_scan_state(ScanState::decode(&mut reader)?),
+            2 => Self::create_finished(),
+            stage => {
+                return Err(invalid_serialized_cursor(&format!(
+                    "unknown scan stage {stage}"
+                )))
+            }
+        };
+        if !reader.bytes.is_empty() {
+            return Err(invalid_serialized_cursor("trailing data"));
+        }
+        Ok(scan_state_rc)
+    }
+
+    /// Returns `true` if `cursor` looks like a string produced by [`ScanStateRC::to_serialized_cursor`].
+    pub fn is_serialized_cursor(cursor: &str) -> bool {
+        cursor.starts_with(SERIALIZED_CURSOR_PREFIX)
+    }
+}
+
+fn invalid_serialized_cursor(detail: &str) -> RedisError {
+    RedisError::from((
+        ErrorKind::ClientError,
+        "Invalid serialized scan cursor",
+        detail.to_string(),
+    ))
+}
+
+/// Reads big-endian fields from a decoded cursor, failing on truncated input.
+struct CursorReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> CursorReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> RedisResult<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(invalid_serialized_cursor("truncated data"));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_u8(&mut self) -> RedisResult<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> RedisResult<u16> {
+        let bytes = self.read_bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u64(&mut self) -> RedisResult<u64> {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(self.read_bytes(8)?);
+        Ok(u64::from_be_bytes(buf))
+    }
 }
 
 /// Represents the state of a cluster scan operation.
@@ -418,6 +528,64 @@ impl ScanState {
         }
     }
 
+    /// Appends the cursor, epoch, address and the scanned slots, as inclusive ranges, to `bytes`.
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.cursor.to_be_bytes());
+        bytes.extend_from_slice(&self.address_epoch.to_be_bytes());
+        bytes.extend_from_slice(&(self.address_in_scan.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(self.address_in_scan.as_bytes());
+
+        let mut ranges: Vec<(u16, u16)> = Vec::new();
+        for slot in 0..NUM_OF_SLOTS {
+            if !is_slot_scanned(&self.scanned_slots_map, slot) {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == slot => *end = slot,
+                _ => ranges.push((slot, slot)),
+            }
+        }
+        bytes.extend_from_slice(&(ranges.len() as u16).to_be_bytes());
+        for (start, end) in ranges {
+            bytes.extend_from_slice(&start.to_be_bytes());
+            bytes.extend_from_slice(&end.to_be_bytes());
+        }
+    }
+
+    fn decode(reader: &mut CursorReader) -> RedisResult<Self> {
+        let cursor = reader.read_u64()?;
+        let address_epoch = reader.read_u64()?;
+        let address_len = reader.read_u16()? as usize;
+        let address = std::str::from_utf8(reader.read_bytes(address_len)?)
+            .map_err(|_| invalid_serialized_cursor("address is not valid UTF-8"))?;
+        if address.is_empty() {
+            return Err(invalid_serialized_cursor("empty address"));
+        }
+
+        let mut scanned_slots_map: SlotsBitsArray = [0; BITS_ARRAY_SIZE as usize];
+        let ranges_count = reader.read_u16()?;
+        for _ in 0..ranges_count {
+            let start = reader.read_u16()?;
+            let end = reader.read_u16()?;
+            if start > end || end >= NUM_OF_SLOTS {
+                return Err(invalid_serialized_cursor(&format!(
+                    "invalid slot range {start}-{end}"
+                )));
+            }
+            for slot in start..=end {
+                mark_slot_as_scanned(&mut scanned_slots_map, slot);
+            }
+        }
+
+        Ok(ScanState::new(
+            cursor,
+            scanned_slots_map,
+            Arc::new(address.to_string()),
+            address_epoch,
+            ScanStateStage::InProgress,
+        ))
+    }
+
     /// Update the scan state without updating the scanned slots map.
     /// This method is used when the address epoch has changed, and we can't determine which slots are new.
     /// In this case, we skip updating the scanned slots map and only update the address and cursor.
@@ -504,6 +672,12 @@ fn mark_slot_as_scanned(scanned_slots_map: &mut SlotsBitsArray, slot: u16) {
     scanned_slots_map[slot_index] |= 1 << slot_bit;
 }
 
+fn is_slot_scanned(scanned_slots_map: &SlotsBitsArray, slot: u16) -> bool {
+    let slot_index = (slot as u64 / BITS_PER_U64 as u64) as usize;
+    let slot_bit = slot as u64 % (BITS_PER_U64 as u64);
+    scanned_slots_map[slot_index] & (1 << slot_bit) != 0
+}
+
 #[derive(PartialEq, Debug, Clone)]
 /// The address type representing a connection address
 ///
@@ -877,4 +1051,63 @@ mod tests {
 
         assert_eq!(next_slot, Some(0));
     }
+
+    #[test]
+    fn test_serialized_cursor_round_trip() {
+        let mut scanned_slots_map = [0; BITS_ARRAY_SIZE as usize];
+        for slot in (0..5461).chain([8000, 8002]).chain(16000..NUM_OF_SLOTS) {
+            mark_slot_as_scanned(&mut scanned_slots_map, slot);
+        }
+        let scan_state = ScanState::new(
+            1234,
+            scanned_slots_map,
+            Arc::new("127.0.0.1:6380".to_string()),
+            7,
+            ScanStateStage::InProgress,
+        );
+        let cursor = ScanStateRC::from_scan_state(scan_state.clone()).to_serialized_cursor();
+        assert!(ScanStateRC::is_serialized_cursor(&cursor));
+
+        let restored = ScanStateRC::from_serialized_cursor(&cursor).unwrap();
+        assert!(!restored.is_finished());
+        assert_eq!(restored.state_from_wrapper(), Some(scan_state));
+
+        let initiating =
+            ScanStateRC::from_serialized_cursor(&ScanStateRC::new().to_serialized_cursor())
+                .unwrap();
+        assert_eq!(initiating.status, ScanStateStage::Initiating);
+        assert_eq!(initiating.state_from_wrapper(), None);
+
+        let finished = ScanStateRC::from_serialized_cursor(
+            &ScanStateRC::create_finished().to_serialized_cursor(),
+        )
+        .unwrap();
+        assert!(finished.is_finished());
+    }
+
+    #[test]
+    fn test_serialized_cursor_rejects_invalid_input() {
+        let scan_state = ScanState::new(
+            0,
+            [0; BITS_ARRAY_SIZE as usize],
+            Arc::new("127.0.0.1:6379".to_string()),
+            1,
+            ScanStateStage::InProgress,
+        );
+        let cursor = ScanStateRC::from_scan_state(scan_state).to_serialized_cursor();
+
+        for invalid in [
+            "",
+            "V1StGXR8_Z5jdHi6B-myT",
+            "scan:0",
+            "scan:zz01",
+            "scan:0201",
+            "scan:0103",
+            &cursor[..cursor.len() - 2],
+            format!("{cursor}00").as_str(),
+        ] {
+            let err = ScanStateRC::from_serialized_cursor(invalid).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ClientError, "{invalid}");
+        }
+    }
 }
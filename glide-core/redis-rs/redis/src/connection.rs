@@ -608,15 +608,7 @@ impl ActualConnection {
             } => {
                 let host: &str = host;
                 let config = create_rustls_config(insecure, tls_params.as_ref().cloned())?;
-                let server_name = rustls_pki_types::ServerName::try_from(host)
-                    .map_err(|e| {
-                        RedisError::from((
-                            ErrorKind::InvalidClientConfig,
-                            "Invalid hostname for TLS",
-                            format!("{e}"),
-                        ))
-                    })?
-                    .to_owned();
+                let server_name = crate::tls::server_name(host, tls_params.as_ref())?;
                 let conn =
                     rustls::ClientConnection::new(Arc::new(config), server_name).map_err(|e| {
                         RedisError::from((
@@ -776,6 +768,27 @@ pub(crate) fn create_rustls_config(
     use crate::tls::ClientTlsParams;
     use rustls_platform_verifier::BuilderVerifierExt;
 
+    let tls_params = tls_params.map(TlsConnParams::current);
+    let options = tls_params
+        .as_ref()
+        .map(|tls_params| tls_params.options.clone())
+        .unwrap_or_default();
+    let provider = Arc::new(crate::tls::crypto_provider(&options)?);
+    let config_builder = || {
+        rustls::ClientConfig::builder_with_provider(provider.clone())
+            .with_protocol_versions(crate::tls::protocol_versions(&options))
+            .map_err(|err| tls_config_error("Failed to configure the TLS protocol versions", err))
+    };
+    let pinned_root_cert_store = if options.pinned_spki_sha256.is_empty() {
+        None
+    } else {
+        Some(
+            tls_params
+                .as_ref()
+                .and_then(|tls_params| tls_params.root_cert_store.clone()),
+        )
+    };
+
     // Build the TLS configuration following rustls best practices:
     // 1. Prefer platform verifier (recommended by rustls team for maximum compatibility)
     // 2. Fall back to custom root certificates only when explicitly provided
@@ -785,7 +798,7 @@ pub(crate) fn create_rustls_config(
             // Custom root certificates explicitly provided - use them instead of platform verifier
             // This is for cases where specific certificate validation is required
             let root_cert_store = tls_params.root_cert_store.unwrap();
-            let config = rustls::ClientConfig::builder().with_root_certificates(root_cert_store);
+            let config = config_builder()?.with_root_certificates(root_cert_store);
 
             match tls_params.client_tls_params {
                 Some(ClientTlsParams {
@@ -805,7 +818,7 @@ pub(crate) fn create_rustls_config(
         Some(tls_params) => {
             // TLS params provided but no custom root certificates - use platform verifier (recommended)
             // Platform verifier provides live trust information and matches user expectations
-            let config = rustls::ClientConfig::builder()
+            let config = config_builder()?
                 .with_platform_verifier()
                 .map_err(|err| {
                     tls_config_error(
@@ -832,7 +845,7 @@ pub(crate) fn create_rustls_config(
         None => {
             // Default case: use platform verifier with no client authentication
             // This is the recommended default configuration for most applications
-            rustls::ClientConfig::builder()
+            config_builder()?
                 .with_platform_verifier()
                 .map_err(|err| {
                     tls_config_error(
@@ -845,7 +858,7 @@ pub(crate) fn create_rustls_config(
     };
 
     // Handle insecure configurations (only when explicitly requested and feature enabled)
-    match (insecure, cfg!(feature = "tls-rustls-insecure")) {
+    let mut config = match (insecure, cfg!(feature = "tls-rustls-insecure")) {
         #[cfg(feature = "tls-rustls-insecure")]
         (true, true) => {
             // WARNING: This disables certificate verification - use only for testing!
@@ -862,7 +875,7 @@ pub(crate) fn create_rustls_config(
                         .signature_verification_algorithms,
                 }));
 
-            Ok(config)
+            config
         }
         (true, false) => {
             // Insecure mode requested but feature not enabled - this is a configuration error
@@ -874,9 +887,41 @@ pub(crate) fn create_rustls_config(
         }
         (false, _) => {
             // Secure mode (default) - return the properly configured client
-            Ok(config)
+            config
         }
+    };
+
+    // Public key pinning checks the chain on top of the verification above, so that verifier is built again and
+    // wrapped, since the config doesn't expose it
+    if let Some(root_cert_store) = pinned_root_cert_store {
+        let verifier: Arc<dyn rustls::client::danger::ServerCertVerifier> = match root_cert_store {
+            #[cfg(feature = "tls-rustls-insecure")]
+            _ if insecure => Arc::new(NoCertificateVerification {
+                supported: provider.signature_verification_algorithms,
+            }),
+            Some(root_cert_store) => rustls::client::WebPkiServerVerifier::builder_with_provider(
+                Arc::new(root_cert_store),
+                provider.clone(),
+            )
+            .build()
+            .map_err(|err| {
+                tls_config_error(
+                    "Failed to configure certificate verification with custom root store",
+                    err,
+                )
+            })?,
+            None => Arc::new(
+                rustls_platform_verifier::Verifier::new(provider.clone()).map_err(|err| {
+                    tls_config_error("Failed to configure platform certificate verifier", err)
+                })?,
+            ),
+        };
+        config.dangerous().set_certificate_verifier(Arc::new(
+            crate::tls::SpkiPinningVerifier::new(verifier, options.pinned_spki_sha256)?,
+        ));
     }
+
+    Ok(config)
 }
 
 /// Helper function to create consistent TLS configuration errors
@@ -0,0 +1,148 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Number of recent read latencies the percentile delay is computed from.
+const LATENCY_SAMPLES: usize = 1000;
+
+/// Number of new samples after which the percentile delay is recomputed.
+const RECOMPUTE_INTERVAL: usize = 64;
+
+/// Default percentile of the recent read latencies that a read waits before it's hedged.
+pub const DEFAULT_HEDGE_PERCENTILE: f64 = 95.0;
+
+/// Default delay before a read is hedged, used until enough reads were measured.
+pub const DEFAULT_HEDGE_FALLBACK_DELAY: Duration = Duration::from_millis(10);
+
+/// How long a read waits for its first node before it's also sent to a second node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HedgeDelay {
+    /// A fixed delay.
+    Fixed(Duration),
+    /// The given percentile, between 0 and 100, of the latencies of recent reads.
+    /// `fallback` is used until enough reads were measured.
+    Percentile {
+        /// The percentile of the recent read latencies.
+        percentile: f64,
+        /// The delay used until enough reads were measured.
+        fallback: Duration,
+    },
+}
+
+/// Configuration of hedged reads.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HedgingConfig {
+    /// How long a read waits for its first node before it's also sent to a second node.
+    pub delay: HedgeDelay,
+}
+
+impl Default for HedgingConfig {
+    fn default() -> Self {
+        Self {
+            delay: HedgeDelay::Percentile {
+                percentile: DEFAULT_HEDGE_PERCENTILE,
+                fallback: DEFAULT_HEDGE_FALLBACK_DELAY,
+            },
+        }
+    }
+}
+
+/// Measures the latency of reads and computes how long a read waits before it's hedged.
+#[derive(Debug)]
+pub struct HedgedReads {
+    config: HedgingConfig,
+    samples: Mutex<LatencySamples>,
+    /// The current percentile delay in microseconds, or 0 if not computed yet.
+    percentile_delay_micros: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct LatencySamples {
+    latencies: VecDeque<Duration>,
+    since_recompute: usize,
+}
+
+impl HedgedReads {
+    /// Creates a tracker without measured reads.
+    pub fn new(config: HedgingConfig) -> Self {
+        Self {
+            config,
+            samples: Default::default(),
+            percentile_delay_micros: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns how long a read waits for its first node before it's also sent to a second node.
+    pub fn delay(&self) -> Duration {
+        match self.config.delay {
+            HedgeDelay::Fixed(delay) => delay,
+            HedgeDelay::Percentile { fallback, .. } => {
+                match self.percentile_delay_micros.load(Ordering::Relaxed) {
+                    0 => fallback,
+                    micros => Duration::from_micros(micros),
+                }
+            }
+        }
+    }
+
+    /// Records the latency of a read. Only used by the percentile delay.
+    pub fn record(&self, latency: Duration) {
+        let HedgeDelay::Percentile { percentile, .. } = self.config.delay else {
+            return;
+        };
+        let mut samples = self.samples.lock().unwrap();
+        if samples.latencies.len() == LATENCY_SAMPLES {
+            samples.latencies.pop_front();
+        }
+        samples.latencies.push_back(latency);
+        samples.since_recompute += 1;
+        if samples.since_recompute < RECOMPUTE_INTERVAL {
+            return;
+        }
+        samples.since_recompute = 0;
+
+        let mut sorted: Vec<Duration> = samples.latencies.iter().copied().collect();
+        drop(samples);
+        sorted.sort_unstable();
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64).round();
+        let micros = (sorted[rank as usize].as_micros() as u64).max(1);
+        self.percentile_delay_micros
+            .store(micros, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_delay() {
+        let hedged_reads = HedgedReads::new(HedgingConfig {
+            delay: HedgeDelay::Fixed(Duration::from_millis(3)),
+        });
+        hedged_reads.record(Duration::from_millis(50));
+        assert_eq!(hedged_reads.delay(), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_percentile_delay() {
+        let hedged_reads = HedgedReads::new(HedgingConfig {
+            delay: HedgeDelay::Percentile {
+                percentile: 90.0,
+                fallback: Duration::from_millis(7),
+            },
+        });
+        for millis in 1..RECOMPUTE_INTERVAL as u64 {
+            hedged_reads.record(Duration::from_millis(millis));
+        }
+        // Not enough reads were measured yet.
+        assert_eq!(hedged_reads.delay(), Duration::from_millis(7));
+
+        hedged_reads.record(Duration::from_millis(RECOMPUTE_INTERVAL as u64));
+        // The 90th percentile of 1..=64 milliseconds.
+        assert_eq!(hedged_reads.delay(), Duration::from_millis(58));
+    }
+}
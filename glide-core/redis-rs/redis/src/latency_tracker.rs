@@ -0,0 +1,149 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// Weight of the newest sample in the moving average.
+const SMOOTHING_FACTOR: f64 = 0.3;
+
+/// Interval between two latency probes of the same node.
+pub const LATENCY_PROBE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default margin a node must beat the currently selected node by before reads move to it.
+pub const DEFAULT_LATENCY_HYSTERESIS: Duration = Duration::from_millis(1);
+
+/// Tracks an exponentially weighted moving average of the round-trip time to each node.
+/// Used by the lowest-latency read strategy to pick the fastest node.
+#[derive(Debug, Default)]
+pub struct LatencyTracker {
+    averages: RwLock<HashMap<String, Duration>>,
+}
+
+impl LatencyTracker {
+    /// Records a round-trip time sample for `address`.
+    pub fn record(&self, address: &str, rtt: Duration) {
+        let mut averages = self.averages.write().unwrap();
+        match averages.get_mut(address) {
+            Some(average) => {
+                *average = average.mul_f64(1.0 - SMOOTHING_FACTOR) + rtt.mul_f64(SMOOTHING_FACTOR);
+            }
+            None => {
+                averages.insert(address.to_string(), rtt);
+            }
+        }
+    }
+
+    /// Returns the average round-trip time to `address`, if it was measured.
+    pub fn latency(&self, address: &str) -> Option<Duration> {
+        self.averages.read().unwrap().get(address).copied()
+    }
+
+    /// Drops the measurements of `address`, e.g. after the node was removed or its connection failed.
+    pub fn remove(&self, address: &str) {
+        self.averages.write().unwrap().remove(address);
+    }
+
+    /// Returns the index of the candidate with the lowest average latency.
+    ///
+    /// The candidate at `current` is kept unless another candidate is faster by more than `hysteresis`,
+    /// so that reads don't flap between nodes with similar latencies.
+    /// Candidates that were never measured are skipped. Returns `None` if no candidate was measured.
+    pub fn select_lowest<'a>(
+        &self,
+        candidates: impl IntoIterator<Item = &'a str>,
+        current: Option<usize>,
+        hysteresis: Duration,
+    ) -> Option<usize> {
+        let averages = self.averages.read().unwrap();
+        let measured: Vec<(usize, Duration)> = candidates
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, address)| averages.get(address).map(|latency| (index, *latency)))
+            .collect();
+
+        let (fastest_index, fastest_latency) = measured
+            .iter()
+            .min_by_key(|(_, latency)| *latency)
+            .copied()?;
+
+        match current.and_then(|current| measured.iter().find(|(index, _)| *index == current)) {
+            Some((current_index, current_latency))
+                if *current_latency <= fastest_latency + hysteresis =>
+            {
+                Some(*current_index)
+            }
+            _ => Some(fastest_index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_sample_is_the_average() {
+        let tracker = LatencyTracker::default();
+        assert_eq!(tracker.latency("node1:6379"), None);
+
+        tracker.record("node1:6379", Duration::from_millis(10));
+        assert_eq!(
+            tracker.latency("node1:6379"),
+            Some(Duration::from_millis(10))
+        );
+    }
+
+    #[test]
+    fn test_average_moves_towards_new_samples() {
+        let tracker = LatencyTracker::default();
+        tracker.record("node1:6379", Duration::from_millis(10));
+        tracker.record("node1:6379", Duration::from_millis(20));
+
+        let latency = tracker.latency("node1:6379").unwrap();
+        assert!(latency > Duration::from_millis(10));
+        assert!(latency < Duration::from_millis(20));
+
+        tracker.remove("node1:6379");
+        assert_eq!(tracker.latency("node1:6379"), None);
+    }
+
+    #[test]
+    fn test_select_lowest_skips_unmeasured_nodes() {
+        let tracker = LatencyTracker::default();
+        assert_eq!(
+            tracker.select_lowest(["a", "b"], None, Duration::ZERO),
+            None
+        );
+
+        tracker.record("b", Duration::from_millis(5));
+        tracker.record("c", Duration::from_millis(2));
+        assert_eq!(
+            tracker.select_lowest(["a", "b", "c"], None, Duration::ZERO),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_select_lowest_hysteresis() {
+        let tracker = LatencyTracker::default();
+        tracker.record("a", Duration::from_millis(5));
+        tracker.record("b", Duration::from_millis(4));
+
+        // `b` is faster, but not by more than the hysteresis, so `a` is kept.
+        assert_eq!(
+            tracker.select_lowest(["a", "b"], Some(0), Duration::from_millis(2)),
+            Some(0)
+        );
+        // Without a current selection, the fastest node is picked.
+        assert_eq!(
+            tracker.select_lowest(["a", "b"], None, Duration::from_millis(2)),
+            Some(1)
+        );
+        // `b` is faster by more than the hysteresis.
+        assert_eq!(
+            tracker.select_lowest(["a", "b"], Some(0), Duration::ZERO),
+            Some(1)
+        );
+    }
+}
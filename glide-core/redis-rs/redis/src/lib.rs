@@ -337,8 +337,10 @@ assert_eq!(result, Ok(("foo".to_string(), b"bar".to_vec())));
 
 // public api
 pub use crate::client::Client;
+pub use crate::client::DnsResolver;
 pub use crate::client::GlideConnectionOptions;
 pub use crate::client::IAMTokenProvider;
+pub use crate::client::TopologyListener;
 pub use crate::cmd::{cmd, fenced_cmd, pack_command, pipe, Arg, Cmd, Iter};
 pub use crate::commands::{
     Commands, ControlFlow, Direction, LposOptions, PubSubCommands, SetOptions,
@@ -349,7 +351,9 @@ pub use crate::connection::{
     PubSubSubscriptionKind, RedisConnectionInfo, TlsMode,
 };
 pub use crate::parser::{parse_redis_value, Parser};
-pub use crate::pipeline::{Pipeline, PipelineRetryStrategy};
+pub use crate::pipeline::{
+    Pipeline, PipelineRetryStrategy, NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE,
+};
 pub use crate::pubsub_synchronizer::PubSubSynchronizer;
 pub use push_manager::{PushInfo, PushManager};
 pub use retry_strategies::RetryStrategy;
@@ -449,14 +453,23 @@ pub mod sentinel;
 
 mod tls;
 
-pub use crate::tls::{retrieve_tls_certificates, ClientTlsConfig, TlsCertificates, TlsConnParams};
+pub use crate::tls::{
+    retrieve_tls_certificates, ClientTlsConfig, ReloadableTlsConnParams, TlsCertificates,
+    TlsConnParams, TlsOptions, TlsProtocolVersion,
+};
 
 /// Cache module
 pub mod cache;
+/// Per-node circuit breaker, which fails the requests to nodes that keep failing.
+pub mod circuit_breaker;
 mod client;
 mod cmd;
 mod commands;
 mod connection;
+/// Hedged reads, which also send slow reads to a second node.
+pub mod hedged_reads;
+/// Per-node latency tracking used by the lowest-latency read strategy.
+pub mod latency_tracker;
 mod parser;
 mod pubsub_synchronizer;
 mod push_manager;
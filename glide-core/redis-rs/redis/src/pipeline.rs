@@ -234,6 +234,15 @@ impl Pipeline {
         self.transaction_mode
     }
 
+    /// Returns the request type of the pipeline, used as the label of its metrics.
+    pub fn request_type(&self) -> &'static str {
+        if self.transaction_mode {
+            "Transaction"
+        } else {
+            "Pipeline"
+        }
+    }
+
     /// Returns the number of commands in the pipeline.
     pub fn len(&self) -> usize {
         self.commands.len()
@@ -380,6 +389,10 @@ macro_rules! implement_pipeline_commands {
 
 implement_pipeline_commands!(Pipeline);
 
+/// Error code of the response to a pipeline command that wasn't retried after a connection error because it isn't
+/// idempotent, see [`PipelineRetryStrategy::retry_idempotent_connection_error`].
+pub const NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE: &str = "NotRetriedAfterConnectionError";
+
 #[derive(Debug, Clone, Copy, Default)]
 /// Defines a retry strategy for pipeline requests, allowing control over retries in case of server or connection errors.
 ///
@@ -402,6 +415,13 @@ pub struct PipelineRetryStrategy {
     /// - Since a connection error does not indicate which commands succeeded or failed, retrying may lead to duplicate executions.
     /// - This is particularly risky for non-idempotent commands like `INCR`, which modify state irreversibly.
     pub retry_connection_error: bool,
+    /// If `true`, only the idempotent commands of a sub-pipeline, such as read-only commands or `SET` without `GET`,
+    /// are retried in case of connection errors. Has no effect if `retry_connection_error` is `true`.
+    ///
+    /// # Effect
+    /// - The other commands, such as `INCR` or `LPUSH`, aren't retried, and their response is an error with the
+    ///   [`NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE`] code.
+    pub retry_idempotent_connection_error: bool,
 }
 
 impl PipelineRetryStrategy {
@@ -410,6 +430,14 @@ impl PipelineRetryStrategy {
         Self {
             retry_server_error,
             retry_connection_error,
+            retry_idempotent_connection_error: false,
         }
     }
+
+    /// Retries only the idempotent commands in case of connection errors, see
+    /// [`PipelineRetryStrategy::retry_idempotent_connection_error`].
+    pub fn with_idempotent_connection_error_retries(mut self) -> Self {
+        self.retry_idempotent_connection_error = true;
+        self
+    }
 }
@@ -57,11 +57,22 @@ pub trait PubSubSynchronizer: Send + Sync {
         desired == actual
     }
 
+    /// Add patterns that are subscribed on every primary instead of a single node, because the messages they match are
+    /// only published on the node they originate from, like keyspace notifications
+    fn add_node_local_patterns(&self, _patterns: HashSet<PubSubChannelOrPattern>) {
+        // Default: no-op
+    }
+
     /// Remove all current subscriptions associated with specific addresses
     fn remove_current_subscriptions_for_addresses(&self, _addresses: &HashSet<String>) {
         // Default: no-op
     }
 
+    /// Unsubscribe from all current subscriptions and subscribe to the desired ones again
+    fn resubscribe_all(&self) {
+        // Default: no-op
+    }
+
     /// Handle a topology refresh event
     fn handle_topology_refresh(&self, _new_slot_map: &SlotMap) {
         // Default: no-op
@@ -1,9 +1,11 @@
+use crate::cache::glide_cache::GlideCache;
 use crate::connection::PubSubSubscriptionKind;
 use crate::{PubSubSynchronizer, PushKind, RedisResult, Value};
 use arc_swap::ArcSwap;
 use std::collections::HashSet;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
+use tracing::warn;
 
 /// Holds information about received Push data
 #[derive(Debug, Clone)]
@@ -20,6 +22,9 @@ pub struct PushManager {
     sender: Arc<ArcSwap<Option<mpsc::UnboundedSender<PushInfo>>>>,
     pubsub_synchronizer: Option<Arc<dyn PubSubSynchronizer>>,
     address: Option<String>,
+    cache: Option<Arc<dyn GlideCache>>,
+    /// Keys cached from replies of this connection, which the server tracks for it
+    tracked_keys: Arc<Mutex<HashSet<Vec<u8>>>>,
 }
 
 impl PushManager {
@@ -33,9 +38,17 @@ impl PushManager {
             sender: Arc::new(ArcSwap::new(Arc::new(sender))),
             pubsub_synchronizer: synchronizer,
             address,
+            cache: None,
+            tracked_keys: Default::default(),
         }
     }
 
+    /// Attach a client-side cache that is kept in sync with the server's `invalidate` push messages
+    pub fn with_cache(mut self, cache: Option<Arc<dyn GlideCache>>) -> Self {
+        self.cache = cache;
+        self
+    }
+
     /// It checks if value's type is Push
     /// then invokes `try_send_raw` method
     pub(crate) fn try_send(&self, value: &RedisResult<Value>) {
@@ -48,6 +61,15 @@ impl PushManager {
     /// then creates PushInfo and invokes `send` method of sender
     pub(crate) fn try_send_raw(&self, value: &Value) {
         if let Value::Push { kind, data } = value {
+            if let Some(cache) = &self.cache {
+                match kind {
+                    PushKind::Invalidate => self.handle_invalidation(cache, data),
+                    // Invalidations sent while the connection was down are lost, so the keys it tracked can't be trusted.
+                    PushKind::Disconnection => self.invalidate_tracked_keys(cache),
+                    _ => {}
+                }
+            }
+
             let guard = self.sender.load();
             if let Some(sender) = guard.as_ref() {
                 let push_info = PushInfo {
@@ -65,6 +87,46 @@ impl PushManager {
         }
     }
 
+    /// Records a key cached from a reply of this connection, so it's invalidated if the connection is lost.
+    /// Call it before inserting the value, so a disconnection racing with the insert can't miss the key.
+    pub(crate) fn track_cached_key(&self, key: Vec<u8>) {
+        self.tracked_keys.lock().unwrap().insert(key);
+    }
+
+    /// Applies the payload of an `invalidate` push message sent by the server.
+    ///
+    /// The payload is either an array of keys, or `Nil` when the server flushed
+    /// its tracking table (e.g. on `FLUSHALL`), which drops the keys tracked by this connection.
+    fn handle_invalidation(&self, cache: &Arc<dyn GlideCache>, data: &[Value]) {
+        match data.first() {
+            Some(Value::Array(keys)) => {
+                let keys: Vec<Vec<u8>> = keys
+                    .iter()
+                    .filter_map(|key| match key {
+                        Value::BulkString(key) => Some(key.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                {
+                    let mut tracked_keys = self.tracked_keys.lock().unwrap();
+                    for key in &keys {
+                        tracked_keys.remove(key);
+                    }
+                }
+                cache.invalidate_keys(&keys);
+            }
+            Some(Value::Nil) => self.invalidate_tracked_keys(cache),
+            other => {
+                warn!("cache_invalidate - Unexpected invalidation payload: {other:?}");
+            }
+        }
+    }
+
+    fn invalidate_tracked_keys(&self, cache: &Arc<dyn GlideCache>) {
+        let keys: Vec<Vec<u8>> = self.tracked_keys.lock().unwrap().drain().collect();
+        cache.invalidate_keys(&keys);
+    }
+
     fn handle_pubsub_push(
         sync: &Arc<dyn PubSubSynchronizer>,
         kind: &PushKind,
@@ -120,12 +182,14 @@ impl PushManager {
         self.pubsub_synchronizer.clone()
     }
 
-    /// Create a new PushManager with an updated address, preserving sender and synchronizer
+    /// Create a new PushManager with an updated address, preserving sender, synchronizer, cache and tracked keys
     pub fn with_address(&self, address: String) -> PushManager {
         PushManager {
             sender: self.sender.clone(),
             pubsub_synchronizer: self.pubsub_synchronizer.clone(),
             address: Some(address),
+            cache: self.cache.clone(),
+            tracked_keys: self.tracked_keys.clone(),
         }
     }
 }
@@ -306,4 +370,73 @@ mod tests {
         assert_eq!(count1 + count2 + count3 + count4, 1000);
         assert_eq!(received_sum, expected_sum);
     }
+
+    #[tokio::test]
+    async fn test_push_manager_applies_invalidations_to_cache() {
+        use crate::cache::glide_cache::CachedKeyType;
+
+        let cache_id = "test_push_manager_invalidation";
+        let cache = crate::cache::get_or_create_cache(cache_id, 1024, 0, None, false, None);
+        let push_manager = PushManager::new(None, None, None).with_cache(Some(cache.clone()));
+        for (key, value) in [(b"key1", 1), (b"key2", 2), (b"key3", 3)] {
+            push_manager.track_cached_key(key.to_vec());
+            cache.insert(key.to_vec(), CachedKeyType::String, Value::Int(value));
+        }
+        // Cached through another connection, which the server tracks separately.
+        cache.insert(b"other".to_vec(), CachedKeyType::String, Value::Int(4));
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        push_manager.replace_sender(tx);
+
+        push_manager.try_send_raw(&Value::Push {
+            kind: PushKind::Invalidate,
+            data: vec![Value::Array(vec![Value::BulkString(b"key1".to_vec())])],
+        });
+        assert!(cache.get(b"key1", CachedKeyType::String).is_none());
+        assert_eq!(cache.entry_count(), 3);
+        // The push is still forwarded to the subscriber.
+        assert_eq!(rx.try_recv().unwrap().kind, PushKind::Invalidate);
+
+        push_manager
+            .with_address("127.0.0.1:6379".to_string())
+            .try_send_raw(&Value::Push {
+                kind: PushKind::Disconnection,
+                data: vec![],
+            });
+        assert_eq!(cache.entry_count(), 1);
+        assert_eq!(
+            cache.get(b"other", CachedKeyType::String),
+            Some(Value::Int(4))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_manager_flush_invalidates_tracked_keys() {
+        use crate::cache::glide_cache::CachedKeyType;
+
+        let cache_id = "test_push_manager_flush_invalidation";
+        let cache = crate::cache::get_or_create_cache(cache_id, 1024, 0, None, false, None);
+        let push_manager = PushManager::new(None, None, None).with_cache(Some(cache.clone()));
+        push_manager.track_cached_key(b"key1".to_vec());
+        cache.insert(b"key1".to_vec(), CachedKeyType::String, Value::Int(1));
+        cache.insert(b"other".to_vec(), CachedKeyType::String, Value::Int(2));
+
+        let epoch = cache.invalidation_epoch();
+        push_manager.try_send_raw(&Value::Push {
+            kind: PushKind::Invalidate,
+            data: vec![Value::Nil],
+        });
+        assert!(cache.get(b"key1", CachedKeyType::String).is_none());
+        assert_eq!(
+            cache.get(b"other", CachedKeyType::String),
+            Some(Value::Int(2))
+        );
+        // A value read before the flush isn't cached afterwards.
+        assert!(!cache.insert_if_not_invalidated(
+            b"key1".to_vec(),
+            CachedKeyType::String,
+            Value::Int(1),
+            epoch
+        ));
+    }
 }
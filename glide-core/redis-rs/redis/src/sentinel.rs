@@ -104,6 +104,11 @@
 
 use std::{collections::HashMap, num::NonZeroUsize};
 
+#[cfg(feature = "tokio-comp")]
+use std::pin::Pin;
+
+#[cfg(feature = "tokio-comp")]
+use futures_util::Stream;
 #[cfg(feature = "aio")]
 use futures_util::StreamExt;
 use rand::Rng;
@@ -186,6 +191,16 @@ fn sentinel_replicas_cmd(master_name: &str) -> crate::Cmd {
     cmd
 }
 
+fn sentinel_master_addr_cmd(master_name: &str) -> crate::Cmd {
+    let mut cmd = crate::cmd("SENTINEL");
+    cmd.arg("GET-MASTER-ADDR-BY-NAME");
+    cmd.arg(master_name);
+    cmd
+}
+
+/// The channel on which sentinels announce that a master was failed over.
+const SWITCH_MASTER_CHANNEL: &str = "+switch-master";
+
 fn is_master_valid(master_info: &HashMap<String, String>, service_name: &str) -> bool {
     master_info.get("name").map(|s| s.as_str()) == Some(service_name)
         && master_info.contains_key("ip")
@@ -683,6 +698,105 @@ impl Sentinel {
         }
         try_connect_to_first_replica(&addresses, Some(self.replica_start_index))
     }
+
+    /// Returns the address of the master with the given name, as reported by the
+    /// `SENTINEL GET-MASTER-ADDR-BY-NAME` command. Unlike [Sentinel::async_master_for],
+    /// the role of the returned node isn't verified.
+    pub async fn async_master_address_for(
+        &mut self,
+        service_name: &str,
+    ) -> RedisResult<(String, u16)> {
+        let address: Option<(String, u16)> = self
+            .async_try_all_sentinels(sentinel_master_addr_cmd(service_name))
+            .await?;
+        match address {
+            Some(address) => Ok(address),
+            None => fail!((
+                ErrorKind::MasterNameNotFoundBySentinel,
+                "Master with given name not found in sentinel",
+            )),
+        }
+    }
+
+    /// Returns the addresses of the replicas of the given master which the sentinels
+    /// don't consider to be down. Unlike [Sentinel::async_replica_for], the roles of the
+    /// returned nodes aren't verified.
+    pub async fn async_replica_addresses_for(
+        &mut self,
+        service_name: &str,
+    ) -> RedisResult<Vec<(String, u16)>> {
+        let replicas = self.async_get_sentinel_replicas(service_name).await?;
+        Ok(valid_addrs(replicas, is_replica_valid).collect())
+    }
+
+    /// Subscribes to the `+switch-master` channel of the first reachable sentinel, and
+    /// returns the stream of failover events it publishes. The stream ends when the
+    /// connection to the sentinel is lost.
+    #[cfg(feature = "tokio-comp")]
+    pub async fn async_switch_master_events(
+        &self,
+    ) -> RedisResult<Pin<Box<dyn Stream<Item = SwitchMasterEvent> + Send>>> {
+        let mut last_err = None;
+        for connection_info in self.sentinels_connection_info.iter() {
+            match async_subscribe_to_switch_master(connection_info).await {
+                Ok(pubsub) => {
+                    return Ok(Box::pin(pubsub.into_on_message().filter_map(|msg| {
+                        futures_util::future::ready(
+                            msg.get_payload::<String>()
+                                .ok()
+                                .and_then(|payload| SwitchMasterEvent::parse(&payload)),
+                        )
+                    })));
+                }
+                Err(err) => {
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        // We can unwrap here because we know there is at least one connection info.
+        Err(last_err.expect("There should be at least one connection info"))
+    }
+}
+
+#[cfg(feature = "tokio-comp")]
+async fn async_subscribe_to_switch_master(
+    connection_info: &ConnectionInfo,
+) -> RedisResult<crate::aio::PubSub> {
+    let client = Client::open(connection_info.clone())?;
+    let mut pubsub = client.get_async_pubsub().await?;
+    pubsub.subscribe(SWITCH_MASTER_CHANNEL).await?;
+    Ok(pubsub)
+}
+
+/// A failover announced by a sentinel on the `+switch-master` channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitchMasterEvent {
+    /// The name of the master that was failed over.
+    pub master_name: String,
+    /// The address of the previous master.
+    pub old_address: (String, u16),
+    /// The address of the new master.
+    pub new_address: (String, u16),
+}
+
+impl SwitchMasterEvent {
+    /// Parses the payload of a `+switch-master` message, which has the format
+    /// `<master name> <old ip> <old port> <new ip> <new port>`.
+    pub fn parse(payload: &str) -> Option<Self> {
+        let mut parts = payload.split_whitespace();
+        let master_name = parts.next()?.to_string();
+        let old_address = (parts.next()?.to_string(), parts.next()?.parse().ok()?);
+        let new_address = (parts.next()?.to_string(), parts.next()?.parse().ok()?);
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(SwitchMasterEvent {
+            master_name,
+            old_address,
+            new_address,
+        })
+    }
 }
 
 /// Enum defining the server types from a sentinel's point of view.
@@ -775,3 +889,37 @@ impl SentinelClient {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_switch_master_event() {
+        assert_eq!(
+            SwitchMasterEvent::parse("mymaster 10.0.0.1 6379 10.0.0.2 6380"),
+            Some(SwitchMasterEvent {
+                master_name: "mymaster".to_string(),
+                old_address: ("10.0.0.1".to_string(), 6379),
+                new_address: ("10.0.0.2".to_string(), 6380),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_switch_master_event() {
+        assert_eq!(SwitchMasterEvent::parse(""), None);
+        assert_eq!(
+            SwitchMasterEvent::parse("mymaster 10.0.0.1 6379 10.0.0.2"),
+            None
+        );
+        assert_eq!(
+            SwitchMasterEvent::parse("mymaster 10.0.0.1 port 10.0.0.2 6380"),
+            None
+        );
+        assert_eq!(
+            SwitchMasterEvent::parse("mymaster 10.0.0.1 6379 10.0.0.2 6380 extra"),
+            None
+        );
+    }
+}
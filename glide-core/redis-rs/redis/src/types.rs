@@ -162,6 +162,9 @@ pub enum ErrorKind {
     /// Response synchronization lost between commands and responses.
     /// The connection protocol is broken and must be reestablished.
     ProtocolDesync,
+
+    /// The circuit breaker of the node is open, so the request was failed without being sent.
+    CircuitOpen,
 }
 
 #[derive(PartialEq, Debug, Clone, Display, Copy)]
@@ -441,6 +444,9 @@ pub enum PushKind {
     PSubscribe,
     /// `ssubscribe` is received when client subscribed to a shard channel.
     SSubscribe,
+    /// `KeyspaceEvent` is sent from the **library** for a keyspace notification it decoded, with the database, key
+    /// and event as data.
+    KeyspaceEvent,
 }
 
 impl PushKind {
@@ -490,6 +496,7 @@ impl fmt::Display for PushKind {
             PushKind::PSubscribe => write!(f, "psubscribe"),
             PushKind::SSubscribe => write!(f, "ssubscribe"),
             PushKind::Disconnection => write!(f, "disconnection"),
+            PushKind::KeyspaceEvent => write!(f, "keyspace_event"),
         }
     }
 }
@@ -967,6 +974,7 @@ impl RedisError {
             ErrorKind::NotAllSlotsCovered => "not all slots are covered",
             ErrorKind::UserOperationError => "Wrong usage of management operation",
             ErrorKind::ProtocolDesync => "Response processing has goten out of sync",
+            ErrorKind::CircuitOpen => "circuit breaker is open",
         }
     }
 
@@ -1174,6 +1182,7 @@ impl RedisError {
             ErrorKind::FatalSendError => RetryMethod::ReconnectAndRetry,
             ErrorKind::UserOperationError => RetryMethod::NoRetry,
             ErrorKind::ProtocolDesync => RetryMethod::NoRetry,
+            ErrorKind::CircuitOpen => RetryMethod::NoRetry,
         }
     }
 }
@@ -35,8 +35,12 @@ pub struct MockConnectionBehavior {
     pub connection_id_provider: AtomicUsize,
     pub returned_ip_type: ConnectionIPReturnType,
     pub return_connection_err: ShouldReturnConnectionError,
+    /// How long the async connections to each port wait before returning their responses.
+    pub response_delays: ResponseDelays,
 }
 
+pub type ResponseDelays = Arc<std::sync::Mutex<HashMap<u16, Duration>>>;
+
 impl MockConnectionBehavior {
     fn new(id: &str, handler: Handler) -> Self {
         Self {
@@ -45,6 +49,7 @@ impl MockConnectionBehavior {
             connection_id_provider: AtomicUsize::new(0),
             returned_ip_type: ConnectionIPReturnType::default(),
             return_connection_err: ShouldReturnConnectionError::default(),
+            response_delays: Default::default(),
         }
     }
 
@@ -85,6 +90,7 @@ pub fn get_mock_connection_with_port(name: &str, id: usize, port: u16) -> MockCo
         id,
         handler: get_mock_connection_handler(name),
         port,
+        response_delays: Default::default(),
     }
 }
 
@@ -123,6 +129,7 @@ pub struct MockConnection {
     pub id: usize,
     pub handler: Handler,
     pub port: u16,
+    pub response_delays: ResponseDelays,
 }
 
 #[cfg(feature = "cluster-async")]
@@ -178,6 +185,7 @@ impl cluster_async::Connect for MockConnection {
                     .fetch_add(1, Ordering::SeqCst),
                 handler: conn_utils.get_handler(),
                 port,
+                response_delays: conn_utils.response_delays.clone(),
             },
             ip,
         )))
@@ -205,6 +213,7 @@ impl cluster::Connect for MockConnection {
                 .fetch_add(1, Ordering::SeqCst),
             handler: conn_utils.get_handler(),
             port,
+            response_delays: conn_utils.response_delays.clone(),
         })
     }
 
@@ -349,11 +358,22 @@ pub fn respond_startup_with_config(
 #[cfg(feature = "cluster-async")]
 impl aio::ConnectionLike for MockConnection {
     fn req_packed_command<'a>(&'a mut self, cmd: &'a redis::Cmd) -> RedisFuture<'a, Value> {
-        Box::pin(future::ready(
-            (self.handler)(&cmd.get_packed_command(), self.port)
-                .map_err(|err| err.and_then(|v| v.extract_error()))
-                .expect_err("Handler did not specify a response"),
-        ))
+        let response = (self.handler)(&cmd.get_packed_command(), self.port)
+            .map_err(|err| err.and_then(|v| v.extract_error()))
+            .expect_err("Handler did not specify a response");
+        let delay = self
+            .response_delays
+            .lock()
+            .unwrap()
+            .get(&self.port)
+            .copied();
+        match delay {
+            Some(delay) => Box::pin(async move {
+                tokio::time::sleep(delay).await;
+                response
+            }),
+            None => Box::pin(future::ready(response)),
+        }
     }
 
     fn req_packed_commands<'a>(
@@ -5,14 +5,14 @@ mod support;
 #[cfg(test)]
 mod cluster_async {
     use std::{
-        collections::HashMap,
+        collections::{HashMap, HashSet},
         net::{IpAddr, SocketAddr},
         str::from_utf8,
         sync::{
-            atomic::{self, AtomicBool, AtomicI32, AtomicU16, AtomicU32, Ordering},
+            atomic::{self, AtomicBool, AtomicI32, AtomicIsize, AtomicU16, AtomicU32, Ordering},
             Arc,
         },
-        time::Duration,
+        time::{Duration, Instant},
     };
 
     use futures::prelude::*;
@@ -26,16 +26,20 @@ mod cluster_async {
 
     use redis::{
         aio::{ConnectionLike, MultiplexedConnection},
+        circuit_breaker::CircuitBreakerConfig,
         cluster::ClusterClient,
-        cluster_async::{testing::MANAGEMENT_CONN_NAME, ClusterConnection, Connect},
+        cluster_async::{
+            testing::MANAGEMENT_CONN_NAME, ClusterConnection, Connect, InflightRequestTracker,
+        },
         cluster_routing::{
             MultipleNodeRoutingInfo, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
         },
         cluster_topology::{get_slot, DEFAULT_NUMBER_OF_REFRESH_SLOTS_RETRIES},
-        cmd, fenced_cmd, from_owned_redis_value, parse_redis_value, AsyncCommands, Cmd,
-        ConnectionAddr, ErrorKind, FromRedisValue, GlideConnectionOptions, InfoDict,
-        IntoConnectionInfo, PipelineRetryStrategy, ProtocolVersion, RedisError, RedisFuture,
-        RedisResult, Value,
+        cmd, fenced_cmd, from_owned_redis_value,
+        hedged_reads::{HedgeDelay, HedgingConfig},
+        parse_redis_value, AsyncCommands, Cmd, ConnectionAddr, ErrorKind, FromRedisValue,
+        GlideConnectionOptions, InfoDict, IntoConnectionInfo, PipelineRetryStrategy,
+        ProtocolVersion, RedisError, RedisFuture, RedisResult, Value,
     };
 
     use crate::support::*;
@@ -231,6 +235,73 @@ mod cluster_async {
         .unwrap();
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_cross_slot_emulation() {
+        let cluster = TestClusterContext::new_with_cluster_client_builder(
+            3,
+            0,
+            |builder| builder.cross_slot_emulation(true),
+            false,
+        );
+
+        block_on_all(async move {
+            let mut connection = cluster.async_connection(None).await;
+            cmd("SADD")
+                .arg("{a}set")
+                .arg("x")
+                .arg("y")
+                .query_async::<_, ()>(&mut connection)
+                .await?;
+            cmd("SADD")
+                .arg("{b}set")
+                .arg("y")
+                .arg("z")
+                .query_async::<_, ()>(&mut connection)
+                .await?;
+            cmd("ZADD")
+                .arg("{c}zset")
+                .arg(0.5)
+                .arg("x")
+                .arg(3)
+                .arg("w")
+                .query_async::<_, ()>(&mut connection)
+                .await?;
+
+            let union: HashSet<String> = cmd("SUNION")
+                .arg("{a}set")
+                .arg("{b}set")
+                .query_async(&mut connection)
+                .await?;
+            assert_eq!(
+                union,
+                HashSet::from(["x".to_string(), "y".to_string(), "z".to_string()])
+            );
+
+            // The members of the set `{b}set` have a score of 1.
+            let union: Vec<String> = cmd("ZUNION")
+                .arg(2)
+                .arg("{c}zset")
+                .arg("{b}set")
+                .query_async(&mut connection)
+                .await?;
+            assert_eq!(union, vec!["x", "y", "z", "w"]);
+
+            // Commands that write aren't emulated.
+            let err = cmd("ZUNIONSTORE")
+                .arg("{d}zset")
+                .arg(2)
+                .arg("{c}zset")
+                .arg("{b}set")
+                .query_async::<_, ()>(&mut connection)
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::CrossSlot);
+            Ok::<_, RedisError>(())
+        })
+        .unwrap();
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_async_open_telemetry_moved_command() {
@@ -357,10 +428,7 @@ mod cluster_async {
                     3,
                     1,
                     Some(route),
-                    Some(PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    }),
+                    Some(PipelineRetryStrategy::new(true, false)),
                 )
                 .await;
 
@@ -442,10 +510,7 @@ mod cluster_async {
                     0,
                     3,
                     None,
-                    Some(PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    }),
+                    Some(PipelineRetryStrategy::new(true, false)),
                 )
                 .await
                 .expect("Pipeline execution failed");
@@ -544,10 +609,7 @@ mod cluster_async {
                         3,
                         1,
                         Some(route),
-                        Some(PipelineRetryStrategy {
-                            retry_server_error: retry,
-                            retry_connection_error: false,
-                        }),
+                        Some(PipelineRetryStrategy::new(retry, false)),
                     )
                     .await
                     .expect("Pipeline execution failed");
@@ -639,10 +701,7 @@ mod cluster_async {
                         0,
                         3,
                         None,
-                        Some(PipelineRetryStrategy {
-                            retry_server_error: retry,
-                            retry_connection_error: false,
-                        }),
+                        Some(PipelineRetryStrategy::new(retry, false)),
                     )
                     .await
                     .expect("Pipeline execution failed");
@@ -1906,6 +1965,73 @@ mod cluster_async {
         assert_eq!(requests.load(atomic::Ordering::SeqCst), 3);
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_cross_slot_emulation_retries_key_fetches() {
+        let name = "cross_slot_emulation_retries_key_fetches";
+
+        let tryagain_sent = atomic::AtomicBool::new(false);
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")])
+                .retries(2)
+                .cross_slot_emulation(true),
+            name,
+            move |cmd: &[u8], _| {
+                respond_startup(name, cmd)?;
+                let members = |members: &[&str]| {
+                    Err(Ok(Value::Array(
+                        members
+                            .iter()
+                            .map(|member| Value::BulkString(member.as_bytes().to_vec()))
+                            .collect(),
+                    )))
+                };
+                if contains_slice(cmd, b"{a}set") {
+                    if !tryagain_sent.swap(true, atomic::Ordering::SeqCst) {
+                        return Err(parse_redis_value(b"-TRYAGAIN mock\r\n"));
+                    }
+                    members(&["x", "y"])
+                } else if contains_slice(cmd, b"{b}set") {
+                    members(&["y", "z"])
+                } else {
+                    Err(parse_redis_value(b"-ERR mock\r\n"))
+                }
+            },
+        );
+
+        // The fetch of `{a}set` is answered with TRYAGAIN, and retried like any other request.
+        let union = runtime.block_on(
+            cmd("SUNION")
+                .arg("{a}set")
+                .arg("{b}set")
+                .query_async::<_, HashSet<String>>(&mut connection),
+        );
+        assert_eq!(
+            union,
+            Ok(HashSet::from([
+                "x".to_string(),
+                "y".to_string(),
+                "z".to_string()
+            ]))
+        );
+
+        // A key that can't be fetched fails the whole command.
+        let err = runtime
+            .block_on(
+                cmd("SUNION")
+                    .arg("{a}set")
+                    .arg("{c}set")
+                    .query_async::<_, HashSet<String>>(&mut connection),
+            )
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ResponseError);
+    }
+
     // Obtain the view index associated with the node with [called_port] port
     fn get_node_view_index(num_of_views: usize, ports: &Vec<u16>, called_port: u16) -> usize {
         let port_index = ports
@@ -3402,6 +3528,76 @@ mod cluster_async {
         );
     }
 
+    struct FakeDnsResolver(std::sync::Mutex<IpAddr>);
+
+    #[async_trait::async_trait]
+    impl redis::DnsResolver for FakeDnsResolver {
+        async fn resolve(&self, _host: &str, port: u16) -> RedisResult<Vec<SocketAddr>> {
+            Ok(vec![SocketAddr::new(*self.0.lock().unwrap(), port)])
+        }
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_connection_moves_to_new_address_of_hostname() {
+        let name = "test_async_cluster_connection_moves_to_new_address_of_hostname";
+        let old_ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let new_ip: IpAddr = "10.0.0.2".parse().unwrap();
+        let resolver = Arc::new(FakeDnsResolver(std::sync::Mutex::new(old_ip)));
+
+        let _handler = MockConnectionBehavior::register_new(
+            name,
+            Arc::new(move |cmd, _| {
+                respond_startup(name, cmd)?;
+                Err(Ok(Value::BulkString(b"bar".to_vec())))
+            }),
+        );
+        modify_mock_connection_behavior(name, |behavior| {
+            behavior.returned_ip_type = ConnectionIPReturnType::Specified(old_ip)
+        });
+        let connections_opened = || {
+            let mut count = 0;
+            modify_mock_connection_behavior(name, |behavior| {
+                count = behavior.connection_id_provider.load(Ordering::SeqCst)
+            });
+            count
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .enable_time()
+            .build()
+            .unwrap();
+        let client = ClusterClient::builder(vec![&*format!("redis://{name}")])
+            .dns_refresh_interval(Some(Duration::from_millis(50)))
+            .dns_resolver(Some(resolver.clone()))
+            .build()
+            .unwrap();
+        let mut connection = runtime
+            .block_on(client.get_async_generic_connection::<MockConnection>())
+            .unwrap();
+        let connections_before_move = connections_opened();
+
+        // The hostname moves, and the new connections report the new IP.
+        *resolver.0.lock().unwrap() = new_ip;
+        modify_mock_connection_behavior(name, |behavior| {
+            behavior.returned_ip_type = ConnectionIPReturnType::Specified(new_ip)
+        });
+        runtime.block_on(async {
+            // The user and management connections of the node are replaced.
+            while connections_opened() < connections_before_move + 2 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("foo")
+                .query_async::<_, String>(&mut connection),
+        );
+        assert_eq!(value, Ok("bar".to_string()));
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_async_cluster_refresh_slots_rate_limiter_skips_refresh() {
@@ -3724,6 +3920,222 @@ mod cluster_async {
         assert_eq!(value, Ok(Some(Value::SimpleString("OK".to_owned()))));
     }
 
+    /// Creates a cluster where GET is answered with the port of the node, and the replica that serves the reads of
+    /// the `test` key answers after 10 seconds.
+    fn hedged_reads_env(
+        name: &'static str,
+        builder: redis::cluster::ClusterClientBuilder,
+    ) -> (MockEnv, Arc<std::sync::Mutex<Vec<u16>>>) {
+        let ports = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let ports_clone = ports.clone();
+        let env = MockEnv::with_client_builder(
+            builder
+                .retries(0)
+                .read_from_replicas()
+                .hedged_reads(HedgingConfig {
+                    delay: HedgeDelay::Fixed(Duration::from_millis(20)),
+                }),
+            name,
+            move |cmd: &[u8], port| {
+                respond_startup_with_replica(name, cmd)?;
+                if contains_slice(cmd, b"GET") {
+                    ports_clone.lock().unwrap().push(port);
+                }
+                Err(Ok(Value::BulkString(port.to_string().into_bytes())))
+            },
+        );
+        modify_mock_connection_behavior(name, |behavior| {
+            behavior
+                .response_delays
+                .lock()
+                .unwrap()
+                .insert(6380, Duration::from_secs(10));
+        });
+        (env, ports)
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_hedged_read_is_sent_to_another_node_after_the_delay() {
+        let name = "test_async_cluster_hedged_read_is_sent_to_another_node_after_the_delay";
+        let (
+            MockEnv {
+                runtime,
+                async_connection: mut connection,
+                handler: _handler,
+                ..
+            },
+            ports,
+        ) = hedged_reads_env(
+            name,
+            ClusterClient::builder(vec![&*format!("redis://{name}")]),
+        );
+
+        let inflight_slots = Arc::new(AtomicIsize::new(1));
+        let mut get = cmd("GET");
+        get.arg("test")
+            .set_inflight_tracker(InflightRequestTracker::try_new(inflight_slots.clone()).unwrap());
+        let start = Instant::now();
+        let value = runtime.block_on(get.query_async::<_, String>(&mut connection));
+        let elapsed = start.elapsed();
+        drop(get);
+
+        // The replica didn't answer within the delay, so the read was also sent to the primary, which answered first.
+        assert_eq!(value, Ok("6379".to_string()));
+        assert_eq!(*ports.lock().unwrap(), vec![6380, 6379]);
+        assert!(elapsed >= Duration::from_millis(20), "{elapsed:?}");
+        // The request to the replica was cancelled instead of awaited, and released its inflight slot.
+        assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+        assert_eq!(inflight_slots.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_hedged_read_failure_waits_for_the_other_read_at_most_the_response_timeout(
+    ) {
+        let name = "test_async_cluster_hedged_read_failure_waits_for_the_other_read_at_most_the_response_timeout";
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")])
+                .retries(0)
+                .response_timeout(Duration::from_millis(500))
+                .read_from_replicas()
+                .hedged_reads(HedgingConfig {
+                    delay: HedgeDelay::Fixed(Duration::from_millis(20)),
+                }),
+            name,
+            move |cmd: &[u8], port| {
+                respond_startup_with_replica(name, cmd)?;
+                match port {
+                    6379 => Err(Err((ErrorKind::TypeError, "mock-error").into())),
+                    _ => Err(Ok(Value::BulkString(port.to_string().into_bytes()))),
+                }
+            },
+        );
+        modify_mock_connection_behavior(name, |behavior| {
+            behavior
+                .response_delays
+                .lock()
+                .unwrap()
+                .insert(6380, Duration::from_secs(10));
+        });
+
+        let start = Instant::now();
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, String>(&mut connection),
+        );
+        let elapsed = start.elapsed();
+
+        // The hedged read to the primary failed, and the replica didn't answer within the response timeout.
+        assert_eq!(value.unwrap_err().kind(), ErrorKind::TypeError);
+        assert!(elapsed >= Duration::from_millis(500), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_hedged_read_loser_is_not_counted_by_circuit_breaker() {
+        let name = "test_async_cluster_hedged_read_loser_is_not_counted_by_circuit_breaker";
+        // A single failure would open the circuit of the replica.
+        let builder = ClusterClient::builder(vec![&*format!("redis://{name}")]).circuit_breaker(
+            CircuitBreakerConfig {
+                failure_rate_percent: 1,
+                minimum_requests: 1,
+                window: Duration::from_secs(60),
+                open_duration: Duration::from_secs(60),
+                half_open_probes: 1,
+            },
+        );
+        let (
+            MockEnv {
+                runtime,
+                async_connection: mut connection,
+                handler: _handler,
+                ..
+            },
+            ports,
+        ) = hedged_reads_env(name, builder);
+
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, String>(&mut connection),
+        );
+        assert_eq!(value, Ok("6379".to_string()));
+
+        // The cancelled request to the replica didn't open its circuit, so reads are still sent to it.
+        modify_mock_connection_behavior(name, |behavior| {
+            behavior.response_delays.lock().unwrap().clear();
+        });
+        ports.lock().unwrap().clear();
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, String>(&mut connection),
+        );
+        assert_eq!(value, Ok("6380".to_string()));
+        assert_eq!(*ports.lock().unwrap(), vec![6380]);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_circuit_breaker_fails_requests_to_unhealthy_node() {
+        let name = "test_async_cluster_circuit_breaker_fails_requests_to_unhealthy_node";
+        let requests = Arc::new(AtomicI32::new(0));
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")])
+                .retries(0)
+                .circuit_breaker(CircuitBreakerConfig {
+                    failure_rate_percent: 1,
+                    minimum_requests: 1,
+                    window: Duration::from_secs(60),
+                    open_duration: Duration::from_secs(60),
+                    half_open_probes: 1,
+                }),
+            name,
+            {
+                let requests = requests.clone();
+                move |cmd: &[u8], port| {
+                    respond_startup_two_nodes(name, cmd)?;
+                    match port {
+                        6380 => panic!("Node should not be called"),
+                        _ => {
+                            requests.fetch_add(1, Ordering::SeqCst);
+                            Err(Err((ErrorKind::BusyLoadingError, "mock-loading").into()))
+                        }
+                    }
+                }
+            },
+        );
+
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, Option<i32>>(&mut connection),
+        );
+        assert_eq!(value.unwrap_err().kind(), ErrorKind::BusyLoadingError);
+
+        // The failure opened the circuit of the node, so the next request fails without being sent.
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, Option<i32>>(&mut connection),
+        );
+        assert_eq!(value.unwrap_err().kind(), ErrorKind::CircuitOpen);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
     fn test_async_cluster_fan_out(
         command: &'static str,
         expected_ports: Vec<u16>,
@@ -223,10 +223,7 @@ mod test_cluster_pipeline {
                 0,
                 pipeline.len(),
                 None,
-                Some(PipelineRetryStrategy {
-                    retry_server_error: true,
-                    retry_connection_error: false,
-                }),
+                Some(PipelineRetryStrategy::new(true, false)),
             )
             .await
             .expect("Failed to execute pipeline");
@@ -304,10 +301,7 @@ mod test_cluster_pipeline {
                     0,
                     3,
                     None,
-                    Some(PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    }),
+                    Some(PipelineRetryStrategy::new(true, false)),
                 )
                 .await
                 .expect("Pipeline execution failed");
@@ -385,10 +379,7 @@ mod test_cluster_pipeline {
                     3,
                     1,
                     Some(route),
-                    Some(PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    }),
+                    Some(PipelineRetryStrategy::new(true, false)),
                 )
                 .await;
 
@@ -609,10 +600,7 @@ mod test_cluster_pipeline {
                     0,
                     pipeline.len(),
                     None,
-                    Some(PipelineRetryStrategy {
-                        retry_server_error: retries > 0,
-                        retry_connection_error: false,
-                    }),
+                    Some(PipelineRetryStrategy::new(retries > 0, false)),
                 )
                 .await
                 .expect("Pipeline execution failed");
@@ -726,10 +714,7 @@ mod test_cluster_pipeline {
                         0,
                         3,
                         None,
-                        Some(PipelineRetryStrategy {
-                            retry_server_error: retry,
-                            retry_connection_error: false,
-                        }),
+                        Some(PipelineRetryStrategy::new(retry, false)),
                     )
                     .await
                     .expect("Pipeline execution failed")
@@ -848,10 +833,7 @@ mod test_cluster_pipeline {
                         0,
                         3,
                         None,
-                        Some(PipelineRetryStrategy {
-                            retry_server_error: false,
-                            retry_connection_error: retry,
-                        }),
+                        Some(PipelineRetryStrategy::new(false, retry)),
                     )
                     .await
                     .expect("Pipeline execution failed")
@@ -919,10 +901,7 @@ mod test_cluster_pipeline {
                 0,
                 pipeline.len(),
                 None,
-                Some(PipelineRetryStrategy {
-                    retry_server_error: true,
-                    retry_connection_error: false,
-                }),
+                Some(PipelineRetryStrategy::new(true, false)),
             )
             .await
             .expect("Pipeline execution failed");
@@ -1018,10 +997,7 @@ mod test_cluster_pipeline {
                 0,
                 pipeline.len(),
                 None,
-                Some(PipelineRetryStrategy {
-                    retry_server_error: true,
-                    retry_connection_error: false,
-                }),
+                Some(PipelineRetryStrategy::new(true, false)),
             )
             .await
             .expect("Pipeline execution failed");
@@ -1155,10 +1131,7 @@ mod test_cluster_pipeline {
                 0,
                 pipeline.len(),
                 None,
-                Some(PipelineRetryStrategy {
-                    retry_server_error: true,
-                    retry_connection_error: false,
-                }),
+                Some(PipelineRetryStrategy::new(true, false)),
             )
             .await
             .expect("Pipeline execution failed");
@@ -114,6 +114,56 @@ mod test_cluster_scan_async {
         }
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_async_cluster_scan_resume_from_serialized_cursor() {
+        let cluster = TestClusterContext::new_with_cluster_client_builder(
+            3,
+            0,
+            |builder| builder.retries(1),
+            false,
+        );
+        let mut connection = cluster.async_connection(None).await;
+        let mut expected_keys: Vec<String> = (0..1000).map(|i| format!("key{i}")).collect();
+        for key in expected_keys.iter() {
+            let _: () = redis::cmd("SET")
+                .arg(key)
+                .arg("value")
+                .query_async(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        // Every iteration resumes from the serialized cursor on a fresh connection, as a restarted process would
+        let mut serialized_cursor = ScanStateRC::new().to_serialized_cursor();
+        let mut keys: Vec<String> = vec![];
+        loop {
+            let mut connection = cluster.async_connection(None).await;
+            let scan_state_rc = ScanStateRC::from_serialized_cursor(&serialized_cursor).unwrap();
+            let (next_cursor, scan_keys): (ScanStateRC, Vec<Value>) = connection
+                .cluster_scan(
+                    scan_state_rc,
+                    ClusterScanArgs::builder().with_count(100).build(),
+                )
+                .await
+                .unwrap();
+            keys.extend(
+                scan_keys
+                    .into_iter()
+                    .map(|v| from_redis_value::<String>(&v).unwrap()),
+            );
+            if next_cursor.is_finished() {
+                break;
+            }
+            serialized_cursor = next_cursor.to_serialized_cursor();
+        }
+
+        keys.sort();
+        keys.dedup();
+        expected_keys.sort();
+        assert_eq!(keys, expected_keys);
+    }
+
     #[tokio::test]
     #[serial_test::serial]
     async fn test_async_cluster_scan_with_allow_non_covered_slots() {
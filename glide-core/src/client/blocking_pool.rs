@@ -0,0 +1,295 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use super::BlockingCommandsPool;
+use logger_core::log_debug;
+use redis::aio::{ConnectionLike, MultiplexedConnection};
+use redis::cluster_routing::Routable;
+use redis::{
+    Cmd, ConnectionInfo, ErrorKind, GlideConnectionOptions, RedisConnectionInfo, RedisError,
+    RedisResult, TopologyListener, Value,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use telemetrylib::Telemetry;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Returns true if the command may block its connection until the server has an answer.
+///
+/// `WAIT` and `WAITAOF` aren't included: they wait for the writes sent over the same connection,
+/// so they must run on the shared connection.
+pub(crate) fn is_blocking_command(cmd: &Cmd) -> bool {
+    match cmd.command().unwrap_or_default().as_slice() {
+        b"BLPOP" | b"BRPOP" | b"BLMOVE" | b"BZPOPMAX" | b"BZPOPMIN" | b"BRPOPLPUSH" | b"BLMPOP"
+        | b"BZMPOP" => true,
+        // The options come before `STREAMS`, which is followed by the keys, so a key named `BLOCK` isn't an option.
+        b"XREAD" | b"XREADGROUP" => match (cmd.position(b"BLOCK"), cmd.position(b"STREAMS")) {
+            (Some(block), Some(streams)) => block < streams,
+            (block, None) => block.is_some(),
+            (None, Some(_)) => false,
+        },
+        _ => false,
+    }
+}
+
+/// A dedicated connection, counted in the telemetry for as long as it is open.
+struct PooledConnection(MultiplexedConnection);
+
+impl PooledConnection {
+    fn new(connection: MultiplexedConnection) -> Self {
+        Telemetry::incr_total_blocking_connections(1);
+        Self(connection)
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        Telemetry::decr_total_blocking_connections(1);
+    }
+}
+
+/// The dedicated connections to a single node.
+struct NodePool {
+    /// Bounds the number of blocking commands running on the node at once, and so the number of connections.
+    permits: Arc<Semaphore>,
+    /// Connections that aren't running a command, along with the session settings they were opened with.
+    idle_connections: Mutex<(RedisConnectionInfo, Vec<PooledConnection>)>,
+}
+
+impl NodePool {
+    /// Takes an idle connection opened with the same session settings as `redis_info`.
+    /// Idle connections opened with other settings, e.g. before a `SELECT`, are closed.
+    fn take_idle(&self, redis_info: &RedisConnectionInfo) -> Option<PooledConnection> {
+        let mut guard = self.idle_connections.lock().unwrap();
+        let (idle_redis_info, idle_connections) = &mut *guard;
+        if !same_session(idle_redis_info, redis_info) {
+            idle_connections.clear();
+            *idle_redis_info = redis_info.clone();
+            return None;
+        }
+        idle_connections.pop()
+    }
+
+    fn put_idle(&self, redis_info: &RedisConnectionInfo, connection: PooledConnection) {
+        let mut guard = self.idle_connections.lock().unwrap();
+        let (idle_redis_info, idle_connections) = &mut *guard;
+        if same_session(idle_redis_info, redis_info) {
+            idle_connections.push(connection);
+        }
+    }
+}
+
+fn same_session(first: &RedisConnectionInfo, second: &RedisConnectionInfo) -> bool {
+    first.db == second.db
+        && first.username == second.username
+        && first.password == second.password
+        && first.protocol == second.protocol
+        && first.client_name == second.client_name
+}
+
+/// A bounded pool of dedicated per-node connections for blocking commands.
+///
+/// A blocking command occupies its connection until the server answers, so sending it over the shared
+/// connection of a node would delay every request pipelined behind it.
+pub(crate) struct BlockingConnectionPool {
+    max_connections_per_node: usize,
+    acquire_timeout: Option<Duration>,
+    connection_options: GlideConnectionOptions,
+    nodes: Mutex<HashMap<String, Arc<NodePool>>>,
+}
+
+impl BlockingConnectionPool {
+    pub(crate) fn new(
+        config: &BlockingCommandsPool,
+        connection_timeout: Duration,
+        tcp_nodelay: bool,
+    ) -> Self {
+        Self {
+            max_connections_per_node: config.max_connections_per_node as usize,
+            acquire_timeout: config.acquire_timeout,
+            connection_options: GlideConnectionOptions {
+                connection_timeout: Some(connection_timeout),
+                tcp_nodelay,
+                ..Default::default()
+            },
+            nodes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sends `cmd` over a dedicated connection to the node described by `connection_info`.
+    pub(crate) async fn send_command(
+        &self,
+        connection_info: &ConnectionInfo,
+        cmd: &Cmd,
+    ) -> RedisResult<Value> {
+        let node_pool = self.node_pool(&connection_info.addr.to_string());
+        let _permit = self.acquire_permit(&node_pool).await?;
+
+        let mut connection = match node_pool.take_idle(&connection_info.redis) {
+            Some(connection) => connection,
+            None => self.connect(connection_info).await?,
+        };
+        let result = connection.0.send_packed_command(cmd).await;
+        match &result {
+            Err(err) if err.is_unrecoverable_error() || err.is_io_error() => {
+                log_debug(
+                    "blocking pool",
+                    format!(
+                        "Dropping connection to {} after error: {err}",
+                        connection_info.addr
+                    ),
+                );
+            }
+            _ => node_pool.put_idle(&connection_info.redis, connection),
+        }
+        result
+    }
+
+    fn node_pool(&self, address: &str) -> Arc<NodePool> {
+        self.nodes
+            .lock()
+            .unwrap()
+            .entry(address.to_string())
+            .or_insert_with(|| {
+                Arc::new(NodePool {
+                    permits: Arc::new(Semaphore::new(self.max_connections_per_node)),
+                    idle_connections: Mutex::new((RedisConnectionInfo::default(), Vec::new())),
+                })
+            })
+            .clone()
+    }
+
+    async fn acquire_permit(&self, node_pool: &NodePool) -> RedisResult<OwnedSemaphorePermit> {
+        let acquire = node_pool.permits.clone().acquire_owned();
+        let permit = match self.acquire_timeout {
+            Some(acquire_timeout) => tokio::time::timeout(acquire_timeout, acquire)
+                .await
+                .map_err(|_| {
+                    Telemetry::incr_blocking_pool_acquire_timeout();
+                    RedisError::from((
+                        ErrorKind::ClientError,
+                        "Timed out waiting for a connection from the blocking commands pool",
+                    ))
+                })?,
+            None => acquire.await,
+        };
+        permit.map_err(|_| {
+            RedisError::from((
+                ErrorKind::ClientError,
+                "The blocking commands pool was closed",
+            ))
+        })
+    }
+
+    async fn connect(&self, connection_info: &ConnectionInfo) -> RedisResult<PooledConnection> {
+        let connection =
+            open_dedicated_connection(connection_info, self.connection_options.clone()).await?;
+        Ok(PooledConnection::new(connection))
+    }
+}
+
+impl TopologyListener for BlockingConnectionPool {
+    /// Closes the idle connections of the nodes that left the cluster. Commands that are still running on them keep
+    /// their connection until they finish.
+    fn on_topology_refresh(&self, addresses: &HashSet<Arc<String>>) {
+        self.nodes.lock().unwrap().retain(|address, _| {
+            let present = addresses.contains(address);
+            if !present {
+                log_debug(
+                    "blocking pool",
+                    format!("Dropping the connections to {address}, which left the cluster"),
+                );
+            }
+            present
+        });
+    }
+}
+
+/// Opens a connection that isn't shared with other requests, with the session settings of `connection_info`.
+pub(super) async fn open_dedicated_connection(
+    connection_info: &ConnectionInfo,
+    connection_options: GlideConnectionOptions,
+) -> RedisResult<MultiplexedConnection> {
+    let mut connection_info = connection_info.clone();
+    // Dedicated connections don't serve cached reads, so they shouldn't receive invalidations.
+    connection_info.redis.cache = None;
+    let client = redis::Client::open(connection_info)?;
+    client
+        .get_multiplexed_async_connection(connection_options)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> BlockingConnectionPool {
+        BlockingConnectionPool::new(
+            &BlockingCommandsPool {
+                max_connections_per_node: 1,
+                acquire_timeout: None,
+            },
+            Duration::from_secs(1),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_pools_of_departed_nodes_are_dropped() {
+        let pool = pool();
+        let kept = pool.node_pool("node1:6379");
+        pool.node_pool("node2:6379");
+
+        pool.on_topology_refresh(&HashSet::from([
+            Arc::new("node1:6379".to_string()),
+            Arc::new("node3:6379".to_string()),
+        ]));
+
+        let nodes = pool.nodes.lock().unwrap();
+        assert_eq!(nodes.len(), 1);
+        assert!(Arc::ptr_eq(&nodes["node1:6379"], &kept));
+    }
+
+    #[test]
+    fn test_is_blocking_command() {
+        assert!(is_blocking_command(redis::cmd("BLPOP").arg("key").arg(0)));
+        assert!(is_blocking_command(
+            redis::cmd("XREAD")
+                .arg("BLOCK")
+                .arg(100)
+                .arg("STREAMS")
+                .arg("key")
+                .arg("$")
+        ));
+        assert!(!is_blocking_command(
+            redis::cmd("XREAD").arg("STREAMS").arg("key").arg("$")
+        ));
+        assert!(!is_blocking_command(
+            redis::cmd("XREAD").arg("STREAMS").arg("BLOCK").arg("$")
+        ));
+        assert!(is_blocking_command(
+            redis::cmd("XREADGROUP")
+                .arg("GROUP")
+                .arg("group")
+                .arg("consumer")
+                .arg("block")
+                .arg(0)
+                .arg("STREAMS")
+                .arg("key")
+                .arg(">")
+        ));
+        assert!(!is_blocking_command(
+            redis::cmd("XREADGROUP")
+                .arg("GROUP")
+                .arg("group")
+                .arg("consumer")
+                .arg("COUNT")
+                .arg(10)
+                .arg("STREAMS")
+                .arg("key")
+                .arg(">")
+        ));
+        assert!(!is_blocking_command(redis::cmd("WAIT").arg(1).arg(0)));
+        assert!(!is_blocking_command(redis::cmd("GET").arg("key")));
+    }
+}
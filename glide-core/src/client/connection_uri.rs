@@ -0,0 +1,477 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Parsing of connection URIs into connection requests, shared by all the bindings.
+//!
+//! The accepted format is
+//! `valkey[s]://[[username]:password@]host[:port][,host[:port]...][/database][?option=value&...]`.
+//! `redis://` and `rediss://` are accepted for compatibility. Several hosts seed the connection with
+//! several addresses, e.g. the nodes of a cluster. A standalone server listening on a Unix domain
+//! socket is reached with `unix://[[username]:password@]/path/to/socket[?db=N&option=value&...]`,
+//! where `valkey+unix://` and `redis+unix://` are accepted as well. The options mirror the fields of
+//! the connection request:
+//!
+//! - `cluster_mode_enabled`, `lazy_connect`, `read_only`, `tcp_nodelay`: `true` or `false`.
+//! - `request_timeout`, `connection_timeout`: in milliseconds.
+//! - `inflight_requests_limit`, `connections_per_node`: positive integers.
+//! - `connection_selection`: `RoundRobin` or `LeastPending`.
+//! - `read_from`: `Primary`, `PreferReplica`, `LowestLatency`, `AZAffinity`,
+//!   `AZAffinityReplicasAndPrimary` or `AllNodes`. The AZ affinity strategies require `client_az`.
+//! - `client_az`, `client_name`: strings.
+//! - `protocol`: `RESP2` or `RESP3`.
+//! - `tls_mode`: `InsecureTls`, to skip the verification of the server certificates of a `valkeys://` URI.
+//! - `root_cert_path` (may be repeated), `client_cert_path`, `client_key_path`: paths of PEM files,
+//!   only with `valkeys://` URIs.
+//! - `compression`: `zstd` or `lz4`, along with the optional `compression_level` and `min_compression_size`.
+//! - `db`: the database, only with Unix socket URIs, whose path is the socket's.
+
+use super::ConnectionError;
+use crate::connection_request as protobuf;
+use ::protobuf::{EnumOrUnknown, MessageField};
+use std::collections::HashSet;
+
+const DEFAULT_PORT: u16 = 6379;
+
+fn invalid_uri(reason: impl std::fmt::Display) -> ConnectionError {
+    ConnectionError::Configuration(format!("Invalid connection URI: {reason}"))
+}
+
+/// Parses a connection URI into a connection request. See the module documentation for the format.
+pub fn parse_connection_uri(uri: &str) -> Result<protobuf::ConnectionRequest, ConnectionError> {
+    let (scheme, rest) = uri
+        .split_once("://")
+        .ok_or_else(|| invalid_uri(format!("missing scheme in `{uri}`")))?;
+    let tls_mode = match scheme.to_ascii_lowercase().as_str() {
+        "valkey" | "redis" => protobuf::TlsMode::NoTls,
+        "valkeys" | "rediss" => protobuf::TlsMode::SecureTls,
+        "unix" | "valkey+unix" | "redis+unix" => return parse_unix_socket_uri(rest),
+        _ => return Err(invalid_uri(format!("unsupported scheme `{scheme}`"))),
+    };
+
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let (authority, path) = match rest.split_once('/') {
+        Some((authority, path)) => (authority, path),
+        None => (rest, ""),
+    };
+    let (user_info, hosts) = match authority.rsplit_once('@') {
+        Some((user_info, hosts)) => (Some(user_info), hosts),
+        None => (None, authority),
+    };
+
+    let mut request = protobuf::ConnectionRequest::new();
+    request.tls_mode = EnumOrUnknown::new(tls_mode);
+    for host in hosts.split(',') {
+        request.addresses.push(parse_node_address(host)?);
+    }
+
+    if let Some(user_info) = user_info {
+        apply_user_info(&mut request, user_info)?;
+    }
+
+    if !path.is_empty() {
+        request.database_id = path
+            .parse()
+            .map_err(|_| invalid_uri(format!("invalid database `{path}`")))?;
+    }
+
+    if let Some(query) = query {
+        apply_query_options(&mut request, query)?;
+    }
+
+    Ok(request)
+}
+
+fn parse_unix_socket_uri(rest: &str) -> Result<protobuf::ConnectionRequest, ConnectionError> {
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    // The path is absolute, so user info is anything before its first slash.
+    let (user_info, path) = match rest.split_once('/') {
+        Some(("", _)) => (None, rest),
+        Some((user_info, _)) => (
+            Some(
+                user_info
+                    .strip_suffix('@')
+                    .ok_or_else(|| invalid_uri(format!("invalid Unix socket address `{rest}`")))?,
+            ),
+            &rest[user_info.len()..],
+        ),
+        None => return Err(invalid_uri("missing Unix socket path")),
+    };
+    if path == "/" {
+        return Err(invalid_uri("missing Unix socket path"));
+    }
+
+    let mut request = protobuf::ConnectionRequest::new();
+    request.tls_mode = EnumOrUnknown::new(protobuf::TlsMode::NoTls);
+    request.unix_socket_path = percent_decode(path, "path")?.into();
+    if let Some(user_info) = user_info {
+        apply_user_info(&mut request, user_info)?;
+    }
+    if let Some(query) = query {
+        apply_query_options(&mut request, query)?;
+    }
+    Ok(request)
+}
+
+fn apply_user_info(
+    request: &mut protobuf::ConnectionRequest,
+    user_info: &str,
+) -> Result<(), ConnectionError> {
+    let (username, password) = user_info.split_once(':').unwrap_or((user_info, ""));
+    let username = percent_decode(username, "username")?;
+    let password = percent_decode(password, "password")?;
+    if !username.is_empty() || !password.is_empty() {
+        let mut authentication_info = protobuf::AuthenticationInfo::new();
+        authentication_info.username = username.into();
+        authentication_info.password = password.into();
+        request.authentication_info = MessageField::some(authentication_info);
+    }
+    Ok(())
+}
+
+fn parse_node_address(host: &str) -> Result<protobuf::NodeAddress, ConnectionError> {
+    // IPv6 addresses are enclosed in brackets, since they contain colons.
+    let (host_name, port) = if let Some(bracketed) = host.strip_prefix('[') {
+        let (host_name, rest) = bracketed
+            .split_once(']')
+            .ok_or_else(|| invalid_uri(format!("unterminated IPv6 address `{host}`")))?;
+        match rest {
+            "" => (host_name, None),
+            _ => (
+                host_name,
+                Some(
+                    rest.strip_prefix(':')
+                        .ok_or_else(|| invalid_uri(format!("invalid host `{host}`")))?,
+                ),
+            ),
+        }
+    } else {
+        match host.rsplit_once(':') {
+            Some((host_name, port)) => (host_name, Some(port)),
+            None => (host, None),
+        }
+    };
+    if host_name.is_empty() {
+        return Err(invalid_uri("missing host"));
+    }
+    let port = match port {
+        Some(port) => port
+            .parse::<u16>()
+            .map_err(|_| invalid_uri(format!("invalid port `{port}` for host `{host_name}`")))?,
+        None => DEFAULT_PORT,
+    };
+
+    let mut address = protobuf::NodeAddress::new();
+    address.host = host_name.to_string().into();
+    address.port = port as u32;
+    Ok(address)
+}
+
+fn apply_query_options(
+    request: &mut protobuf::ConnectionRequest,
+    query: &str,
+) -> Result<(), ConnectionError> {
+    let mut seen_options = HashSet::new();
+    let mut compression_config = None;
+    let mut compression_level = None;
+    let mut min_compression_size = None;
+
+    for option in query.split('&').filter(|option| !option.is_empty()) {
+        let (name, value) = option
+            .split_once('=')
+            .ok_or_else(|| invalid_uri(format!("option `{option}` has no value")))?;
+        let value = percent_decode(value, name)?;
+        if name != "root_cert_path" && !seen_options.insert(name) {
+            return Err(invalid_uri(format!(
+                "option `{name}` is set more than once"
+            )));
+        }
+
+        match name {
+            "cluster_mode_enabled" => request.cluster_mode_enabled = parse_bool(name, &value)?,
+            "lazy_connect" => request.lazy_connect = parse_bool(name, &value)?,
+            "read_only" => request.read_only = Some(parse_bool(name, &value)?),
+            "tcp_nodelay" => request.tcp_nodelay = Some(parse_bool(name, &value)?),
+            "request_timeout" => request.request_timeout = parse_number(name, &value)?,
+            "connection_timeout" => request.connection_timeout = parse_number(name, &value)?,
+            "inflight_requests_limit" => {
+                request.inflight_requests_limit = parse_number(name, &value)?
+            }
+            "connections_per_node" => {
+                request.connections_per_node = Some(parse_number(name, &value)?)
+            }
+            "connection_selection" => {
+                let strategy = match value.as_str() {
+                    "RoundRobin" => protobuf::ConnectionSelectionStrategy::RoundRobin,
+                    "LeastPending" => protobuf::ConnectionSelectionStrategy::LeastPending,
+                    _ => return Err(invalid_value(name, &value)),
+                };
+                request.connection_selection_strategy = EnumOrUnknown::new(strategy);
+            }
+            "client_az" => request.client_az = value.into(),
+            "client_name" => request.client_name = value.into(),
+            "read_from" => {
+                let read_from = match value.as_str() {
+                    "Primary" => protobuf::ReadFrom::Primary,
+                    "PreferReplica" => protobuf::ReadFrom::PreferReplica,
+                    "LowestLatency" => protobuf::ReadFrom::LowestLatency,
+                    "AZAffinity" => protobuf::ReadFrom::AZAffinity,
+                    "AZAffinityReplicasAndPrimary" => {
+                        protobuf::ReadFrom::AZAffinityReplicasAndPrimary
+                    }
+                    "AllNodes" => protobuf::ReadFrom::AllNodes,
+                    _ => return Err(invalid_value(name, &value)),
+                };
+                request.read_from = EnumOrUnknown::new(read_from);
+            }
+            "protocol" => {
+                let protocol = match value.to_ascii_uppercase().as_str() {
+                    "RESP2" => protobuf::ProtocolVersion::RESP2,
+                    "RESP3" => protobuf::ProtocolVersion::RESP3,
+                    _ => return Err(invalid_value(name, &value)),
+                };
+                request.protocol = EnumOrUnknown::new(protocol);
+            }
+            "tls_mode" => {
+                if value != "InsecureTls" {
+                    return Err(invalid_value(name, &value));
+                }
+                require_tls(request, name)?;
+                request.tls_mode = EnumOrUnknown::new(protobuf::TlsMode::InsecureTls);
+            }
+            "root_cert_path" => {
+                require_tls(request, name)?;
+                request.root_certs.push(read_file(name, &value)?.into());
+            }
+            "client_cert_path" => {
+                require_tls(request, name)?;
+                request.client_cert = read_file(name, &value)?.into();
+            }
+            "client_key_path" => {
+                require_tls(request, name)?;
+                request.client_key = read_file(name, &value)?.into();
+            }
+            "compression" => {
+                let backend = match value.to_ascii_lowercase().as_str() {
+                    "zstd" => protobuf::CompressionBackend::ZSTD,
+                    "lz4" => protobuf::CompressionBackend::LZ4,
+                    _ => return Err(invalid_value(name, &value)),
+                };
+                let mut config = protobuf::CompressionConfig::new();
+                config.enabled = true;
+                config.backend = EnumOrUnknown::new(backend);
+                config.min_compression_size = 64;
+                compression_config = Some(config);
+            }
+            "compression_level" => {
+                compression_level = Some(
+                    value
+                        .parse::<i32>()
+                        .map_err(|_| invalid_value(name, &value))?,
+                )
+            }
+            "min_compression_size" => min_compression_size = Some(parse_number(name, &value)?),
+            "db" => {
+                if request.unix_socket_path.is_empty() {
+                    return Err(invalid_uri(
+                        "option `db` requires a Unix socket URI, the database of other URIs is their path",
+                    ));
+                }
+                request.database_id = parse_number(name, &value)?;
+            }
+            _ => return Err(invalid_uri(format!("unknown option `{name}`"))),
+        }
+    }
+
+    if request.client_cert.is_empty() != request.client_key.is_empty() {
+        return Err(invalid_uri(
+            "`client_cert_path` and `client_key_path` must be set together",
+        ));
+    }
+    let az_affinity = matches!(
+        request.read_from.enum_value(),
+        Ok(protobuf::ReadFrom::AZAffinity | protobuf::ReadFrom::AZAffinityReplicasAndPrimary)
+    );
+    if az_affinity && request.client_az.is_empty() {
+        return Err(invalid_uri(
+            "`client_az` is required by the AZ affinity `read_from` strategies",
+        ));
+    }
+
+    match compression_config {
+        Some(mut config) => {
+            config.compression_level = compression_level;
+            if let Some(min_compression_size) = min_compression_size {
+                config.min_compression_size = min_compression_size;
+            }
+            request.compression_config = MessageField::some(config);
+        }
+        None if compression_level.is_some() || min_compression_size.is_some() => {
+            return Err(invalid_uri(
+                "`compression_level` and `min_compression_size` require `compression`",
+            ));
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+fn invalid_value(name: &str, value: &str) -> ConnectionError {
+    invalid_uri(format!("invalid value `{value}` for option `{name}`"))
+}
+
+fn parse_bool(name: &str, value: &str) -> Result<bool, ConnectionError> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(invalid_value(name, value)),
+    }
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u32, ConnectionError> {
+    value.parse().map_err(|_| invalid_value(name, value))
+}
+
+fn require_tls(request: &protobuf::ConnectionRequest, name: &str) -> Result<(), ConnectionError> {
+    if request.tls_mode.enum_value() == Ok(protobuf::TlsMode::NoTls) {
+        return Err(invalid_uri(format!(
+            "option `{name}` requires a `valkeys://` URI"
+        )));
+    }
+    Ok(())
+}
+
+fn read_file(name: &str, path: &str) -> Result<Vec<u8>, ConnectionError> {
+    std::fs::read(path).map_err(|err| {
+        invalid_uri(format!(
+            "failed to read `{path}` for option `{name}`: {err}"
+        ))
+    })
+}
+
+fn percent_decode(value: &str, name: &str) -> Result<String, ConnectionError> {
+    urlencoding::decode(value)
+        .map(|decoded| decoded.into_owned())
+        .map_err(|_| invalid_uri(format!("`{name}` is not valid UTF-8 once decoded")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(uri: &str) -> String {
+        parse_connection_uri(uri).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_parse_multiple_hosts() {
+        let request =
+            parse_connection_uri("valkey://user:p%40ss@h1:6379,h2:6380,[::1]:7000,h3/2").unwrap();
+        let addresses: Vec<(String, u32)> = request
+            .addresses
+            .iter()
+            .map(|address| (address.host.to_string(), address.port))
+            .collect();
+        assert_eq!(
+            addresses,
+            vec![
+                ("h1".to_string(), 6379),
+                ("h2".to_string(), 6380),
+                ("::1".to_string(), 7000),
+                ("h3".to_string(), 6379),
+            ]
+        );
+        let authentication_info = request.authentication_info.unwrap();
+        assert_eq!(&*authentication_info.username, "user");
+        assert_eq!(&*authentication_info.password, "p@ss");
+        assert_eq!(request.database_id, 2);
+        assert_eq!(request.tls_mode.enum_value(), Ok(protobuf::TlsMode::NoTls));
+    }
+
+    #[test]
+    fn test_parse_unix_socket_uri() {
+        let request = parse_connection_uri("unix:///var/run/valkey.sock?db=3").unwrap();
+        assert_eq!(&*request.unix_socket_path, "/var/run/valkey.sock");
+        assert!(request.addresses.is_empty());
+        assert_eq!(request.database_id, 3);
+        assert_eq!(request.tls_mode.enum_value(), Ok(protobuf::TlsMode::NoTls));
+        assert!(request.authentication_info.is_none());
+
+        let request =
+            parse_connection_uri("valkey+unix://user:p%40ss@/tmp/valkey.sock?request_timeout=500")
+                .unwrap();
+        assert_eq!(&*request.unix_socket_path, "/tmp/valkey.sock");
+        let authentication_info = request.authentication_info.unwrap();
+        assert_eq!(&*authentication_info.username, "user");
+        assert_eq!(&*authentication_info.password, "p@ss");
+        assert_eq!(request.request_timeout, 500);
+    }
+
+    #[test]
+    fn test_parse_query_options() {
+        let request = parse_connection_uri(
+            "valkeys://h1:6379?cluster_mode_enabled=true&read_from=AZAffinity&client_az=us-east-1a\
+             &request_timeout=500&connection_timeout=1000&protocol=resp2&compression=lz4\
+             &compression_level=1&tls_mode=InsecureTls&connections_per_node=4\
+             &connection_selection=LeastPending",
+        )
+        .unwrap();
+        assert!(request.cluster_mode_enabled);
+        assert_eq!(
+            request.read_from.enum_value(),
+            Ok(protobuf::ReadFrom::AZAffinity)
+        );
+        assert_eq!(&*request.client_az, "us-east-1a");
+        assert_eq!(request.request_timeout, 500);
+        assert_eq!(request.connection_timeout, 1000);
+        assert_eq!(
+            request.protocol.enum_value(),
+            Ok(protobuf::ProtocolVersion::RESP2)
+        );
+        assert_eq!(
+            request.tls_mode.enum_value(),
+            Ok(protobuf::TlsMode::InsecureTls)
+        );
+        let compression_config = request.compression_config.unwrap();
+        assert!(compression_config.enabled);
+        assert_eq!(
+            compression_config.backend.enum_value(),
+            Ok(protobuf::CompressionBackend::LZ4)
+        );
+        assert_eq!(compression_config.compression_level, Some(1));
+        assert_eq!(request.connections_per_node, Some(4));
+        assert_eq!(
+            request.connection_selection_strategy.enum_value(),
+            Ok(protobuf::ConnectionSelectionStrategy::LeastPending)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_error("localhost:6379").contains("missing scheme"));
+        assert!(parse_error("http://localhost").contains("unsupported scheme"));
+        assert!(parse_error("unix://").contains("missing Unix socket path"));
+        assert!(parse_error("unix://user/tmp/valkey.sock").contains("invalid Unix socket address"));
+        assert!(parse_error("valkey://h1?db=2").contains("requires a Unix socket URI"));
+        assert!(parse_error("valkey://h1:6379,:6380").contains("missing host"));
+        assert!(parse_error("valkey://h1:port").contains("invalid port"));
+        assert!(parse_error("valkey://h1/db").contains("invalid database"));
+        assert!(parse_error("valkey://h1?foo=bar").contains("unknown option `foo`"));
+        assert!(parse_error("valkey://h1?protocol=resp4").contains("option `protocol`"));
+        assert!(
+            parse_error("valkey://h1?request_timeout=1&request_timeout=2")
+                .contains("more than once")
+        );
+        assert!(parse_error("valkey://h1?read_from=AZAffinity").contains("client_az"));
+        assert!(parse_error("valkey://h1?root_cert_path=ca.pem").contains("valkeys://"));
+        assert!(parse_error("valkey://h1?compression_level=3").contains("require `compression`"));
+        assert!(
+            parse_error("valkeys://h1?root_cert_path=/does/not/exist").contains("failed to read")
+        );
+    }
+}
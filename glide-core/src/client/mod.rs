@@ -6,7 +6,8 @@ use crate::cluster_scan_container::insert_cluster_scan_cursor;
 use crate::compression::CompressionBackendType;
 use crate::compression::lz4_backend::Lz4Backend;
 use crate::compression::zstd_backend::ZstdBackend;
-use crate::compression::{CompressionConfig, CompressionManager};
+use crate::compression::{CompressionConfig, CompressionManager, compression_request_type};
+use crate::credentials::{CredentialsProvider, CredentialsProviderHandle, is_authentication_error};
 use crate::scripts_container::get_script;
 use futures::FutureExt;
 use logger_core::{log_debug, log_error, log_info, log_warn};
@@ -15,7 +16,8 @@ use redis::aio::ConnectionLike;
 use redis::cache::{get_or_create_cache, glide_cache::GlideCache};
 use redis::cluster_async::ClusterConnection;
 use redis::cluster_routing::{
-    MultipleNodeRoutingInfo, ResponsePolicy, Routable, RoutingInfo, SingleNodeRoutingInfo,
+    MultipleNodeRoutingInfo, ResponsePolicy, Routable, Route, RoutingInfo, SingleNodeRoutingInfo,
+    SlotAddr,
 };
 use redis::cluster_slotmap::ReadFromReplicaStrategy;
 use redis::{
@@ -23,6 +25,7 @@ use redis::{
     RedisResult, RetryStrategy, ScanStateRC, Value,
 };
 pub use standalone_client::StandaloneClient;
+use std::collections::HashSet;
 use std::io;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicIsize, Ordering};
@@ -33,12 +36,22 @@ use tokio::runtime::{Builder, Handle};
 pub use types::*;
 
 use self::value_conversion::{convert_to_expected_type, expected_type_for_cmd, get_value_type};
+mod blocking_pool;
+#[cfg(feature = "proto")]
+mod connection_uri;
+use blocking_pool::{BlockingConnectionPool, is_blocking_command};
+#[cfg(feature = "proto")]
+pub use connection_uri::parse_connection_uri;
 mod reconnecting_connection;
 pub use reconnecting_connection::IAMTokenHandle;
+mod sentinel;
 mod standalone_client;
+mod tls_watcher;
+use tls_watcher::TlsCertificateWatcher;
+mod transaction_session;
+use transaction_session::TransactionSessions;
 mod value_conversion;
-use crate::pubsub::{PubSubSynchronizer, create_pubsub_synchronizer};
-use crate::request_type::RequestType;
+use crate::pubsub::{KeyspaceEvents, PubSubSynchronizer, PushSender, create_pubsub_synchronizer};
 use redis::InfoDict;
 use std::future::Future;
 use std::pin::Pin;
@@ -70,21 +83,30 @@ pub const DEFAULT_MAX_INFLIGHT_REQUESTS: u32 = 1000;
 /// and performance overhead.
 pub const CONNECTION_CHECKS_INTERVAL: Duration = Duration::from_secs(3);
 
-/// Extract RequestType from a Redis command for decompression processing
-fn extract_request_type_from_cmd(cmd: &Cmd) -> Option<RequestType> {
-    // Get the command name (first argument)
-    let command_name = cmd.command()?;
-    let command_str = String::from_utf8_lossy(&command_name).to_uppercase();
-
-    // Map command names to RequestType for decompression
-    // Only read commands that return values needing decompression are included
-    match command_str.as_str() {
-        "GET" => Some(RequestType::Get),
-        "MGET" => Some(RequestType::MGet),
-        "GETEX" => Some(RequestType::GetEx),
-        "GETDEL" => Some(RequestType::GetDel),
-        "GETSET" => Some(RequestType::GetSet),
-        _ => None, // Unknown command or write command, no decompression needed
+/// Decompress the response of a read command, returning the raw response if it can't be decompressed
+fn decompress_response(
+    cmd: &Cmd,
+    raw_value: Value,
+    compression_manager: Option<&CompressionManager>,
+) -> Value {
+    let (Some(compression_manager), Some(request_type)) =
+        (compression_manager, compression_request_type(cmd))
+    else {
+        return raw_value;
+    };
+    match crate::compression::process_response_for_decompression(
+        raw_value.clone(),
+        request_type,
+        Some(compression_manager),
+    ) {
+        Ok(decompressed_value) => decompressed_value,
+        Err(e) => {
+            log_warn(
+                "send_command_decompression",
+                format!("Failed to decompress response: {}", e),
+            );
+            raw_value
+        }
     }
 }
 
@@ -161,6 +183,7 @@ pub(super) fn get_port(address: &NodeAddress) -> u16 {
 /// Get Valkey connection info with IAM token integration
 ///
 /// If IAM config + token manager exist, use the IAM token as the password; otherwise use the provided password.
+/// A credentials provider, when set, supplies the password instead.
 ///
 /// `iam_token_manager: Option<&Arc<IAMTokenManager>>`
 /// — `Option` because IAM is optional; `&Arc` gives shared, non-owning, cheap access to a shared manager (we only read a token).
@@ -182,10 +205,11 @@ pub async fn get_valkey_connection_info(
                 client_side_cache.entry_ttl_ms,
                 client_side_cache.eviction_policy,
                 client_side_cache.enable_metrics,
+                client_side_cache.tracking_mode,
             )
         });
 
-    match &connection_request.authentication_info {
+    let mut connection_info = match &connection_request.authentication_info {
         Some(info) => {
             // If we have IAM configuration and a token manager, use the IAM token as password
             if info.iam_config.is_some() && iam_token_manager.is_some() {
@@ -226,11 +250,44 @@ pub async fn get_valkey_connection_info(
             cache,
             ..Default::default()
         },
+    };
+
+    if let Some(provider) = &connection_request.credentials_provider
+        && let Some(password) = provider.get_password().await
+    {
+        connection_info.password = Some(password);
     }
+    connection_info
 }
 
 use redis::{TlsCertificates, retrieve_tls_certificates};
 
+/// Adds the TLS options of the request to the TLS parameters of the connections.
+pub(super) fn with_tls_options(
+    tls_params: Option<redis::TlsConnParams>,
+    tls_options: Option<&TlsOptions>,
+) -> Option<redis::TlsConnParams> {
+    match tls_options {
+        Some(tls_options) => Some(
+            tls_params
+                .unwrap_or_default()
+                .with_options(tls_options.clone()),
+        ),
+        None => tls_params,
+    }
+}
+
+/// Returns the interval of the periodic checks, which refresh the slots of a cluster and the roles of standalone
+/// nodes, or `None` if they're disabled.
+pub(super) fn periodic_checks_interval(periodic_checks: Option<PeriodicCheck>) -> Option<Duration> {
+    match periodic_checks {
+        Some(PeriodicCheck::Disabled) => None,
+        Some(PeriodicCheck::Enabled) => Some(DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL),
+        Some(PeriodicCheck::ManualInterval(interval)) => Some(interval),
+        None => Some(DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL),
+    }
+}
+
 // tls_params should be only set if tls_mode is SecureTls
 // this should be validated before calling this function
 pub(super) fn get_connection_info(
@@ -278,12 +335,20 @@ pub struct Client {
     inflight_log_interval: isize,
     // IAM token manager for automatic credential refresh
     iam_token_manager: Option<Arc<crate::iam::IAMTokenManager>>,
+    // Optional source of the password, asked again after authentication errors
+    credentials_provider: Option<Arc<dyn CredentialsProvider>>,
+    // Reloads the TLS certificate files while the client is alive
+    tls_certificate_watcher: Option<Arc<TlsCertificateWatcher>>,
     // Optional compression manager for automatic compression/decompression
     compression_manager: Option<Arc<CompressionManager>>,
     pubsub_synchronizer: Arc<dyn PubSubSynchronizer>,
     otel_metadata: types::OTelMetadata,
     // Optional client-side cache
     client_side_cache: Option<Arc<dyn GlideCache>>,
+    // Optional pool of dedicated connections for blocking commands
+    blocking_pool: Option<Arc<BlockingConnectionPool>>,
+    // Connections leased by WATCH/MULTI transaction sessions
+    transaction_sessions: Arc<TransactionSessions>,
 }
 
 async fn run_with_timeout<T>(
@@ -788,6 +853,7 @@ impl Client {
                     push_sender,
                     iam_manager_ref,
                     self.pubsub_synchronizer.clone(),
+                    self.blocking_pool.clone(),
                 )
                 .await?;
                 ClientWrapper::Cluster { client }
@@ -843,8 +909,15 @@ impl Client {
         client: ClientWrapper,
         compression_manager: Option<Arc<CompressionManager>>,
     ) -> RedisResult<Value> {
+        let blocking_pool = self_clone
+            .blocking_pool
+            .clone()
+            .filter(|_| is_blocking_command(&cmd));
         let raw_value = match client {
-            ClientWrapper::Standalone(mut client) => client.send_command(&cmd).await,
+            ClientWrapper::Standalone(mut client) => match blocking_pool {
+                Some(blocking_pool) => client.send_blocking_command(&cmd, &blocking_pool).await,
+                None => client.send_command(&cmd).await,
+            },
             ClientWrapper::Cluster { mut client } => {
                 let final_routing = if let Some(RoutingInfo::SingleNode(
                     SingleNodeRoutingInfo::Random,
@@ -868,35 +941,30 @@ impl Client {
                         .or_else(|| RoutingInfo::for_routable(cmd.as_ref()))
                         .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
                 };
-                client.route_command(&cmd, final_routing).await
+                match (blocking_pool, &final_routing) {
+                    (
+                        Some(blocking_pool),
+                        RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route)),
+                    ) => {
+                        let connection_info =
+                            client.get_connection_info_for_route(route.clone()).await?;
+                        match blocking_pool.send_command(&connection_info, &cmd).await {
+                            // The dedicated connections don't follow redirections, the shared connections handle them and refresh the topology.
+                            Err(err) if matches!(err.kind(), ErrorKind::Moved | ErrorKind::Ask) => {
+                                client.route_command(&cmd, final_routing).await
+                            }
+                            result => result,
+                        }
+                    }
+                    _ => client.route_command(&cmd, final_routing).await,
+                }
             }
             ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
         }?;
 
         // Post-process: decompress and convert to expected type.
         // Done after the mutable borrow on cmd is released.
-        let processed_value = if let Some(ref compression_manager) = compression_manager {
-            if let Some(request_type) = extract_request_type_from_cmd(&cmd) {
-                match crate::compression::process_response_for_decompression(
-                    raw_value.clone(),
-                    request_type,
-                    Some(compression_manager.as_ref()),
-                ) {
-                    Ok(decompressed_value) => decompressed_value,
-                    Err(e) => {
-                        log_warn(
-                            "send_command_decompression",
-                            format!("Failed to decompress response: {}", e),
-                        );
-                        raw_value
-                    }
-                }
-            } else {
-                raw_value
-            }
-        } else {
-            raw_value
-        };
+        let processed_value = decompress_response(&cmd, raw_value, compression_manager.as_deref());
 
         let expected_type = expected_type_for_cmd(&cmd);
         let value = convert_to_expected_type(processed_value, expected_type)?;
@@ -920,6 +988,61 @@ impl Client {
         &'a mut self,
         cmd: &'a mut Cmd,
         routing: Option<RoutingInfo>,
+    ) -> redis::RedisFuture<'a, Value> {
+        self.send_command_with_timeout(cmd, routing, None)
+    }
+
+    /// Sends a command with its own request timeout in milliseconds, which replaces the client's request timeout.
+    /// Blocking commands still wait according to their own timeout argument.
+    pub fn send_command_with_timeout<'a>(
+        &'a mut self,
+        cmd: &'a mut Cmd,
+        routing: Option<RoutingInfo>,
+        request_timeout: Option<u32>,
+    ) -> redis::RedisFuture<'a, Value> {
+        Box::pin(async move {
+            let err = match self
+                .send_command_once(cmd, routing.clone(), request_timeout)
+                .await
+            {
+                Err(err) if !self.is_auth_command(cmd) => err,
+                result => return result,
+            };
+            // The server rejected the command, so it's sent again once re-authenticated.
+            if self.reauthenticate_after(&err).await? {
+                self.send_command_once(cmd, routing, request_timeout).await
+            } else {
+                Err(err)
+            }
+        })
+    }
+
+    /// Re-authenticates the connections with the password of the credentials provider if `err` means that the
+    /// password was rotated. Returns whether the request that failed with `err` should be sent again.
+    async fn reauthenticate_after(&mut self, err: &RedisError) -> RedisResult<bool> {
+        let Some(provider) = self.credentials_provider.clone() else {
+            return Ok(false);
+        };
+        if !is_authentication_error(err) {
+            return Ok(false);
+        }
+        let Some(password) = provider.get_password().await else {
+            return Ok(false);
+        };
+        log_info(
+            "credentials provider",
+            "Re-authenticating with the provider's password after an authentication error",
+        );
+        self.update_connection_password(Some(password), true)
+            .await?;
+        Ok(true)
+    }
+
+    fn send_command_once<'a>(
+        &'a mut self,
+        cmd: &'a mut Cmd,
+        routing: Option<RoutingInfo>,
+        request_timeout: Option<u32>,
     ) -> redis::RedisFuture<'a, Value> {
         Box::pin(async move {
             // Check for IAM token changes and update the password without authentication if needed (pull model)
@@ -948,7 +1071,8 @@ impl Client {
                 return result;
             }
 
-            let request_timeout = get_request_timeout(cmd, self.request_timeout)?;
+            let request_timeout =
+                get_request_timeout(cmd, to_duration(request_timeout, self.request_timeout))?;
 
             // Reserve an inflight slot. The tracker holds the slot until the
             // last clone of the Cmd is dropped (i.e. all sub-commands in the
@@ -1112,6 +1236,20 @@ impl Client {
         Ok(Value::Int(metrics.expirations() as i64))
     }
 
+    /// Returns the total number of entries removed from the cache by server invalidation messages.
+    /// Returns an error if caching is not enabled or metrics are disabled.
+    pub fn cache_invalidations(&self) -> RedisResult<Value> {
+        let cache = self.client_side_cache.as_ref().ok_or_else(|| {
+            RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Client-side caching is not enabled",
+            ))
+        })?;
+
+        let metrics = cache.metrics()?;
+        Ok(Value::Int(metrics.invalidations() as i64))
+    }
+
     // Cluster scan is not passed to redis-rs as a regular command, so we need to handle it separately.
     // We send the command to a specific function in the redis-rs cluster client, which internally handles the
     // the complication of a command scan, and generate the command base on the logic in the redis-rs library.
@@ -1129,6 +1267,27 @@ impl Client {
         &'a mut self,
         scan_state_cursor: &'a ScanStateRC,
         cluster_scan_args: ClusterScanArgs,
+    ) -> RedisResult<Value> {
+        let err = match self
+            .cluster_scan_once(scan_state_cursor, cluster_scan_args.clone())
+            .await
+        {
+            Err(err) => err,
+            result => return result,
+        };
+        // Scanning again from the same cursor returns the same keys.
+        if self.reauthenticate_after(&err).await? {
+            self.cluster_scan_once(scan_state_cursor, cluster_scan_args)
+                .await
+        } else {
+            Err(err)
+        }
+    }
+
+    async fn cluster_scan_once(
+        &mut self,
+        scan_state_cursor: &ScanStateRC,
+        cluster_scan_args: ClusterScanArgs,
     ) -> RedisResult<Value> {
         // Clone arguments before the async block (ScanStateRC is Arc, clone is cheap)
         let scan_state_cursor_clone = scan_state_cursor.clone();
@@ -1239,6 +1398,36 @@ impl Client {
         routing: Option<RoutingInfo>,
         transaction_timeout: Option<u32>,
         raise_on_error: bool,
+    ) -> redis::RedisFuture<'a, Value> {
+        Box::pin(async move {
+            let err = match self
+                .send_transaction_once(
+                    pipeline,
+                    routing.clone(),
+                    transaction_timeout,
+                    raise_on_error,
+                )
+                .await
+            {
+                Err(err) => err,
+                result => return result,
+            };
+            // The server rejected the whole transaction, so it's sent again once re-authenticated.
+            if self.reauthenticate_after(&err).await? {
+                self.send_transaction_once(pipeline, routing, transaction_timeout, raise_on_error)
+                    .await
+            } else {
+                Err(err)
+            }
+        })
+    }
+
+    fn send_transaction_once<'a>(
+        &'a mut self,
+        pipeline: &'a redis::Pipeline,
+        routing: Option<RoutingInfo>,
+        transaction_timeout: Option<u32>,
+        raise_on_error: bool,
     ) -> redis::RedisFuture<'a, Value> {
         Box::pin(async move {
             let client = self.get_or_initialize_client().await?;
@@ -1296,6 +1485,93 @@ impl Client {
         })
     }
 
+    /// Leases a dedicated connection for a `WATCH` ... `EXEC` sequence, and returns the id of the new session.
+    ///
+    /// In cluster mode the session is pinned to the primary serving the slot of `routing_key`. Commands sent in the
+    /// session aren't redirected, so keys served by other nodes fail with a `MOVED` error.
+    pub async fn start_transaction_session(
+        &mut self,
+        routing_key: Option<&[u8]>,
+    ) -> RedisResult<String> {
+        let client = self.get_or_initialize_client().await?;
+        let connection_info = match client {
+            ClientWrapper::Standalone(client) => client.primary_connection_info(),
+            ClientWrapper::Cluster { mut client } => {
+                let Some(routing_key) = routing_key else {
+                    return Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "A routing key is required to start a transaction session in cluster mode",
+                    )));
+                };
+                let route = Route::new(
+                    redis::cluster_topology::get_slot(routing_key),
+                    SlotAddr::Master,
+                );
+                client.get_connection_info_for_route(route).await?
+            }
+            ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+        };
+        self.transaction_sessions.start(&connection_info).await
+    }
+
+    /// Sends a command over the connection of a transaction session.
+    /// `request_timeout`, in milliseconds, replaces the client's request timeout when set.
+    pub async fn send_session_command(
+        &mut self,
+        session_id: &str,
+        cmd: &Cmd,
+        request_timeout: Option<u32>,
+    ) -> RedisResult<Value> {
+        let mut connection = self.transaction_sessions.get(session_id)?;
+        let request_timeout =
+            get_request_timeout(cmd, to_duration(request_timeout, self.request_timeout))?;
+        let result = run_with_timeout(request_timeout, connection.send_packed_command(cmd)).await;
+        self.transaction_sessions
+            .end_on_connection_error(session_id, &result);
+        let compression_manager = if self.is_compression_enabled() {
+            self.compression_manager.clone()
+        } else {
+            None
+        };
+        let value = decompress_response(cmd, result?, compression_manager.as_deref());
+        convert_to_expected_type(value, expected_type_for_cmd(cmd))
+    }
+
+    /// Sends an atomic batch over the connection of a transaction session.
+    /// Returns `Nil` if the transaction was aborted because a watched key was modified.
+    pub async fn send_session_transaction(
+        &mut self,
+        session_id: &str,
+        pipeline: &redis::Pipeline,
+        transaction_timeout: Option<u32>,
+        raise_on_error: bool,
+    ) -> RedisResult<Value> {
+        if !pipeline.is_atomic() {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Only atomic batches can be sent in a transaction session",
+            )));
+        }
+        let mut connection = self.transaction_sessions.get(session_id)?;
+        let command_count = pipeline.cmd_iter().count();
+        // MULTI and the QUEUED responses are skipped, see `send_transaction`.
+        let offset = command_count + 1;
+        let result = run_with_timeout(
+            Some(to_duration(transaction_timeout, self.request_timeout)),
+            connection.send_packed_commands(pipeline, offset, 1),
+        )
+        .await;
+        self.transaction_sessions
+            .end_on_connection_error(session_id, &result);
+        Client::get_transaction_values(pipeline, result?, command_count, offset, raise_on_error)
+    }
+
+    /// Ends a transaction session and closes its connection. Keys watched in the session are unwatched.
+    /// Returns false if the session didn't exist.
+    pub fn end_transaction_session(&self, session_id: &str) -> bool {
+        self.transaction_sessions.end(session_id)
+    }
+
     /// Send a pipeline to the server.
     /// Pipeline is a batch of commands that are sent in a single request.
     /// Unlike a transaction, the commands are not executed atomically, and in cluster mode, the commands can be sent to different nodes.
@@ -1309,6 +1585,8 @@ impl Client {
     ///   - If `retry_connection_error` is `true`, sub-pipeline requests will be retried on connection errors.
     ///     ⚠️ **Caution**: Retrying after a connection error may result in duplicate executions, since the server might have already received and processed the request before the error occurred.
     ///     TODO: add wiki link.
+    ///   - If `retry_idempotent_connection_error` is `true`, only the idempotent commands are retried on connection errors.
+    ///     When `raise_on_error` is set, the commands that weren't retried are reported as a connection error with their indices.
     pub fn send_pipeline<'a>(
         &'a mut self,
         pipeline: &'a redis::Pipeline,
@@ -1317,6 +1595,50 @@ impl Client {
         pipeline_timeout: Option<u32>,
         pipeline_retry_strategy: PipelineRetryStrategy,
     ) -> redis::RedisFuture<'a, Value> {
+        Box::pin(async move {
+            let command_count = pipeline.cmd_iter().count();
+            let mut values = self
+                .send_pipeline_once(
+                    pipeline,
+                    routing.clone(),
+                    pipeline_timeout,
+                    pipeline_retry_strategy,
+                )
+                .await?;
+            // None of the commands ran if they were all rejected, so the batch is sent again once re-authenticated.
+            if let Some(err) = batch_authentication_error(&values)
+                && self.reauthenticate_after(&err).await?
+            {
+                values = self
+                    .send_pipeline_once(
+                        pipeline,
+                        routing,
+                        pipeline_timeout,
+                        pipeline_retry_strategy,
+                    )
+                    .await?;
+            }
+
+            if raise_on_error {
+                check_for_commands_not_retried(&values)?;
+            }
+            Client::convert_pipeline_values_to_expected_types(
+                pipeline,
+                values,
+                command_count,
+                raise_on_error,
+            )
+        })
+    }
+
+    /// Sends the pipeline and returns the responses of its commands.
+    fn send_pipeline_once<'a>(
+        &'a mut self,
+        pipeline: &'a redis::Pipeline,
+        routing: Option<RoutingInfo>,
+        pipeline_timeout: Option<u32>,
+        pipeline_retry_strategy: PipelineRetryStrategy,
+    ) -> redis::RedisFuture<'a, Vec<Value>> {
         Box::pin(async move {
             let client = self.get_or_initialize_client().await?;
 
@@ -1331,7 +1653,7 @@ impl Client {
             run_with_timeout(
                 Some(to_duration(pipeline_timeout, self.request_timeout)),
                 async move {
-                    let values = match client {
+                    match client {
                         ClientWrapper::Standalone(mut client) => {
                             client.send_pipeline(pipeline, 0, command_count).await
                         }
@@ -1363,14 +1685,7 @@ impl Client {
                         ClientWrapper::Lazy(_) => {
                             unreachable!("Lazy client should have been initialized")
                         }
-                    }?;
-
-                    Client::convert_pipeline_values_to_expected_types(
-                        pipeline,
-                        values,
-                        command_count,
-                        raise_on_error,
-                    )
+                    }
                 },
             )
             .await
@@ -1627,6 +1942,41 @@ impl PubSubCommandApplier for ClientWrapper {
     }
 }
 
+/// Returns the authentication error that all the commands of a batch failed with, if any, in which case none of them
+/// ran.
+fn batch_authentication_error(values: &[Value]) -> Option<RedisError> {
+    let mut errors = values.iter().map(|value| match value {
+        Value::ServerError(error) => Some(RedisError::from(error.clone())),
+        _ => None,
+    });
+    let first = errors.next()??;
+    (is_authentication_error(&first)
+        && errors.all(|err| err.is_some_and(|err| is_authentication_error(&err))))
+    .then_some(first)
+}
+
+/// Fails with a connection error listing the indices of the batch commands that weren't retried after a connection
+/// error because they aren't idempotent.
+fn check_for_commands_not_retried(values: &[Value]) -> RedisResult<()> {
+    let indices: Vec<usize> = values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| {
+            matches!(value, Value::ServerError(error)
+                if error.err_code() == redis::NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE)
+        })
+        .map(|(index, _)| index)
+        .collect();
+    if indices.is_empty() {
+        return Ok(());
+    }
+    Err(RedisError::from((
+        ErrorKind::FatalReceiveError,
+        "Batch commands that aren't idempotent were not retried after a connection error",
+        format!("command indices: {indices:?}"),
+    )))
+}
+
 fn load_cmd(code: &[u8]) -> Cmd {
     let mut cmd = redis::cmd("SCRIPT");
     cmd.arg("LOAD").arg(code);
@@ -1656,6 +2006,7 @@ async fn create_cluster_client(
     push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
     iam_token_manager: Option<&Arc<crate::iam::IAMTokenManager>>,
     pubsub_synchronizer: Arc<dyn crate::pubsub::PubSubSynchronizer>,
+    blocking_pool: Option<Arc<BlockingConnectionPool>>,
 ) -> RedisResult<redis::cluster_async::ClusterConnection> {
     let tls_mode = request.tls_mode.unwrap_or_default();
 
@@ -1671,7 +2022,7 @@ async fn create_cluster_client(
         )));
     }
 
-    let (tls_params, tls_certificates) = if has_root_certs || has_client_cert || has_client_key {
+    let tls_params = if has_root_certs || has_client_cert || has_client_key {
         if tls_mode == TlsMode::NoTls {
             return Err(RedisError::from((
                 ErrorKind::InvalidClientConfig,
@@ -1708,17 +2059,12 @@ async fn create_cluster_client(
             client_tls,
             root_cert,
         };
-        let params = retrieve_tls_certificates(tls_certs.clone())?;
-        (Some(params), Some(tls_certs))
+        Some(retrieve_tls_certificates(tls_certs)?)
     } else {
-        (None, None)
-    };
-    let periodic_topology_checks = match request.periodic_checks {
-        Some(PeriodicCheck::Disabled) => None,
-        Some(PeriodicCheck::Enabled) => Some(DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL),
-        Some(PeriodicCheck::ManualInterval(interval)) => Some(interval),
-        None => Some(DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL),
+        request.watched_tls_params.clone()
     };
+    let tls_params = with_tls_options(tls_params, request.tls_options.as_ref());
+    let periodic_topology_checks = periodic_checks_interval(request.periodic_checks);
     let connection_timeout = request.get_connection_timeout();
     let initial_nodes: Vec<_> = request
         .addresses
@@ -1744,6 +2090,7 @@ async fn create_cluster_client(
         }
         ReadFrom::PreferReplica => ReadFromReplicaStrategy::RoundRobin,
         ReadFrom::AllNodes => ReadFromReplicaStrategy::AllNodes,
+        ReadFrom::LowestLatency(hysteresis) => ReadFromReplicaStrategy::LowestLatency(hysteresis),
         ReadFrom::Primary => ReadFromReplicaStrategy::AlwaysFromPrimary,
     });
     if let Some(interval_duration) = periodic_topology_checks {
@@ -1759,14 +2106,15 @@ async fn create_cluster_client(
         builder = builder.lib_name(lib_name);
     }
     if tls_mode != TlsMode::NoTls {
-        let tls = if tls_mode == TlsMode::SecureTls {
+        // Certificates in the request always turn on verification
+        let tls = if tls_mode == TlsMode::SecureTls || has_root_certs || has_client_cert {
             redis::cluster::TlsMode::Secure
         } else {
             redis::cluster::TlsMode::Insecure
         };
         builder = builder.tls(tls);
-        if let Some(certs) = tls_certificates {
-            builder = builder.certs(certs);
+        if let Some(tls_params) = tls_params {
+            builder = builder.tls_params(tls_params);
         }
     }
 
@@ -1786,12 +2134,35 @@ async fn create_cluster_client(
 
     builder = builder.tcp_nodelay(request.tcp_nodelay);
 
+    if let Some(connections_per_node) = request.connections_per_node {
+        builder = builder.connections_per_node(connections_per_node as usize);
+    }
+    builder = builder.connection_selection_strategy(request.connection_selection_strategy);
+    if let Some(circuit_breaker) = request.circuit_breaker {
+        builder = builder.circuit_breaker(circuit_breaker);
+    }
+    if let Some(hedged_reads) = request.hedged_reads {
+        builder = builder.hedged_reads(hedged_reads);
+    }
+    builder = builder.cross_slot_emulation(request.cross_slot_emulation);
+    builder = builder.dns_refresh_interval(request.dns_refresh_interval);
+    builder = builder.dns_resolver(request.dns_resolver);
+    // Lets the blocking commands pool drop the connections to nodes that left the cluster.
+    builder = builder.topology_listener(
+        blocking_pool.map(|blocking_pool| blocking_pool as Arc<dyn redis::TopologyListener>),
+    );
+
     // Always use with Glide
     builder = builder.periodic_connections_checks(Some(CONNECTION_CHECKS_INTERVAL));
 
     let client = builder.build()?;
     let iam_token_provider: Option<Arc<dyn redis::IAMTokenProvider>> = iam_token_manager
-        .map(|manager| Arc::new(manager.get_token_handle()) as Arc<dyn redis::IAMTokenProvider>);
+        .map(|manager| Arc::new(manager.get_token_handle()) as Arc<dyn redis::IAMTokenProvider>)
+        .or_else(|| {
+            request.credentials_provider.map(|provider| {
+                Arc::new(CredentialsProviderHandle(provider)) as Arc<dyn redis::IAMTokenProvider>
+            })
+        });
 
     let mut con = client
         .get_async_connection(push_sender, Some(pubsub_synchronizer), iam_token_provider)
@@ -1932,12 +2303,15 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
             format!(
                 "\nRead from Replica mode: {}",
                 match rfr {
-                    ReadFrom::Primary => "Only primary",
-                    ReadFrom::PreferReplica => "Prefer replica",
-                    ReadFrom::AZAffinity(_) => "Prefer replica in user's availability zone",
+                    ReadFrom::Primary => "Only primary".to_string(),
+                    ReadFrom::PreferReplica => "Prefer replica".to_string(),
+                    ReadFrom::AZAffinity(_) =>
+                        "Prefer replica in user's availability zone".to_string(),
                     ReadFrom::AZAffinityReplicasAndPrimary(_) =>
-                        "Prefer replica and primary in user's availability zone",
-                    ReadFrom::AllNodes => "All nodes (primary and replicas)",
+                        "Prefer replica and primary in user's availability zone".to_string(),
+                    ReadFrom::AllNodes => "All nodes (primary and replicas)".to_string(),
+                    ReadFrom::LowestLatency(hysteresis) =>
+                        format!("Lowest latency replica, hysteresis: {hysteresis:?}"),
                 }
             )
         })
@@ -1954,20 +2328,16 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
         .as_ref()
         .map(|client_name| format!("\nClient name: {client_name}"))
         .unwrap_or_default();
-    let periodic_checks = if request.cluster_mode_enabled {
-        match request.periodic_checks {
-            Some(PeriodicCheck::Disabled) => "\nPeriodic Checks: Disabled".to_string(),
-            Some(PeriodicCheck::Enabled) => format!(
-                "\nPeriodic Checks: Enabled with default interval of {DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL:?}"
-            ),
-            Some(PeriodicCheck::ManualInterval(interval)) => format!(
-                "\nPeriodic Checks: Enabled with manual interval of {:?}s",
-                interval.as_secs()
-            ),
-            None => String::new(),
-        }
-    } else {
-        String::new()
+    let periodic_checks = match request.periodic_checks {
+        Some(PeriodicCheck::Disabled) => "\nPeriodic Checks: Disabled".to_string(),
+        Some(PeriodicCheck::Enabled) => format!(
+            "\nPeriodic Checks: Enabled with default interval of {DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL:?}"
+        ),
+        Some(PeriodicCheck::ManualInterval(interval)) => format!(
+            "\nPeriodic Checks: Enabled with manual interval of {:?}s",
+            interval.as_secs()
+        ),
+        None => String::new(),
     };
 
     let pubsub_subscriptions = request
@@ -1981,8 +2351,127 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
         request.inflight_requests_limit,
     );
 
+    let sentinel = request
+        .sentinel_config
+        .as_ref()
+        .map(|sentinel_config| {
+            let sentinel_addresses = sentinel_config
+                .sentinel_addresses
+                .iter()
+                .map(|address| format!("{}:{}", address.host, address.port))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let username = sentinel_config
+                .username
+                .as_ref()
+                .map(|username| format!(", username: {username}"))
+                .unwrap_or_default();
+            format!(
+                "\nSentinels: {sentinel_addresses}, master name: {}{username}",
+                sentinel_config.master_name
+            )
+        })
+        .unwrap_or_default();
+
+    let blocking_commands_pool = request
+        .blocking_commands_pool
+        .as_ref()
+        .map(|pool| {
+            format!(
+                "\nBlocking commands pool: {} connections per node",
+                pool.max_connections_per_node
+            )
+        })
+        .unwrap_or_default();
+
+    let connections_per_node = request
+        .connections_per_node
+        .map(|connections_per_node| {
+            format!(
+                "\nConnections per node: {connections_per_node}, selection strategy: {:?}",
+                request.connection_selection_strategy
+            )
+        })
+        .unwrap_or_default();
+
+    let circuit_breaker = request
+        .circuit_breaker
+        .map(|config| format!("\nCircuit breaker: {config:?}"))
+        .unwrap_or_default();
+    let hedged_reads = request
+        .hedged_reads
+        .map(|config| format!("\nHedged reads: {:?}", config.delay))
+        .unwrap_or_default();
+    let cross_slot_emulation = if request.cross_slot_emulation {
+        "\nCross-slot emulation"
+    } else {
+        ""
+    };
+    let unix_socket_path = request
+        .unix_socket_path
+        .as_ref()
+        .map(|path| format!("\nUnix socket: {}", path.display()))
+        .unwrap_or_default();
+    let credentials_provider = if request.credentials_provider.is_some() {
+        "\nCredentials provider"
+    } else {
+        ""
+    };
+    let discover_replicas = if request.discover_replicas {
+        "\nReplica discovery"
+    } else {
+        ""
+    };
+    let dns_refresh_interval = request
+        .dns_refresh_interval
+        .map(|interval| format!("\nDNS refresh interval: {interval:?}"))
+        .unwrap_or_default();
+    let pubsub_queue = request
+        .pubsub_queue
+        .map(|config| {
+            format!(
+                "\nPubSub queue: {} messages, overflow policy: {:?}",
+                config.capacity, config.overflow_policy
+            )
+        })
+        .unwrap_or_default();
+    let keyspace_events = request
+        .keyspace_events
+        .as_ref()
+        .map(|config| {
+            format!(
+                "\nKeyspace events: {}, key patterns: {}",
+                config.event_mask,
+                config.key_patterns.len()
+            )
+        })
+        .unwrap_or_default();
+    let tls_options = request
+        .tls_options
+        .as_ref()
+        .map(|options| {
+            format!(
+                "\nTLS options: server name: {:?}, pinned keys: {}, minimum version: {:?}, cipher suites: {:?}",
+                options.server_name,
+                options.pinned_spki_sha256.len(),
+                options.min_protocol_version,
+                options.cipher_suites
+            )
+        })
+        .unwrap_or_default();
+    let tls_certificate_files = request
+        .tls_certificate_files
+        .as_ref()
+        .map(|files| {
+            format!(
+                "\nTLS certificate files: checked every {:?}",
+                files.reload_interval
+            )
+        })
+        .unwrap_or_default();
+
     format!(
-        "\nAddresses: {addresses}{tls_mode}{cluster_mode}{request_timeout}{connection_timeout}{rfr_strategy}{connection_retry_strategy}{database_id}{protocol}{client_name}{periodic_checks}{pubsub_subscriptions}{inflight_requests_limit}",
+        "\nAddresses: {addresses}{tls_mode}{cluster_mode}{request_timeout}{connection_timeout}{rfr_strategy}{connection_retry_strategy}{database_id}{protocol}{client_name}{periodic_checks}{pubsub_subscriptions}{inflight_requests_limit}{sentinel}{blocking_commands_pool}{connections_per_node}{circuit_breaker}{hedged_reads}{cross_slot_emulation}{credentials_provider}{tls_certificate_files}{tls_options}{unix_socket_path}{discover_replicas}{dns_refresh_interval}{pubsub_queue}{keyspace_events}",
     )
 }
 
@@ -2000,7 +2489,13 @@ fn create_compression_manager(
     }
 
     let backend: Box<dyn crate::compression::CompressionBackend> = match config.backend {
-        CompressionBackendType::Zstd => Box::new(ZstdBackend::new()),
+        CompressionBackendType::Zstd => match &config.dictionary {
+            Some(dictionary) => Box::new(ZstdBackend::with_dictionary(
+                dictionary,
+                config.compression_level,
+            )),
+            None => Box::new(ZstdBackend::new()),
+        },
         CompressionBackendType::Lz4 => Box::new(Lz4Backend::new()),
     };
 
@@ -2013,8 +2508,8 @@ fn create_compression_manager(
 
 impl Client {
     pub async fn new(
-        request: ConnectionRequest,
-        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+        mut request: ConnectionRequest,
+        push_queue: Option<PushSender>,
     ) -> Result<Self, ConnectionError> {
         // Add buffer to connection_timeout to allow inner connection logic to fully execute before the outer timeout triggers
         let client_creation_timeout = request.get_connection_timeout() + Duration::from_millis(500);
@@ -2031,6 +2526,110 @@ impl Client {
             inflight_requests_limit.try_into().unwrap(),
         ));
 
+        // Started before the validations, so that the receiver is closed if the client isn't created.
+        let push_sender = push_queue
+            .as_ref()
+            .map(|push_queue| push_queue.start(request.pubsub_queue));
+
+        if request.cluster_mode_enabled && request.sentinel_config.is_some() {
+            return Err(ConnectionError::Configuration(
+                "Sentinel can only be used with standalone clients".to_string(),
+            ));
+        }
+
+        if request.credentials_provider.is_some()
+            && request
+                .authentication_info
+                .as_ref()
+                .is_some_and(|info| info.iam_config.is_some())
+        {
+            return Err(ConnectionError::Configuration(
+                "A credentials provider can't be combined with IAM authentication".to_string(),
+            ));
+        }
+
+        if request
+            .pubsub_queue
+            .is_some_and(|config| config.capacity == 0)
+        {
+            return Err(ConnectionError::Configuration(
+                "The pubsub queue capacity must be positive".to_string(),
+            ));
+        }
+
+        let keyspace_events = request
+            .keyspace_events
+            .as_ref()
+            .map(KeyspaceEvents::new)
+            .transpose()
+            .map_err(|err| ConnectionError::Configuration(err.to_string()))?;
+        // Subscribed once the synchronizer is created, on every primary in cluster mode.
+        let keyspace_event_patterns = match (keyspace_events, &push_queue) {
+            (Some(keyspace_events), Some(push_queue)) => {
+                let patterns = keyspace_events.patterns();
+                push_queue.set_keyspace_events(keyspace_events);
+                patterns
+            }
+            (Some(_), None) => {
+                return Err(ConnectionError::Configuration(
+                    "Keyspace events can only be delivered with a push queue".to_string(),
+                ));
+            }
+            (None, _) => HashSet::new(),
+        };
+
+        if request.unix_socket_path.is_some() {
+            let conflict = if request.cluster_mode_enabled {
+                Some("cluster mode")
+            } else if request.sentinel_config.is_some() {
+                Some("Sentinel")
+            } else if !request.addresses.is_empty() {
+                Some("addresses")
+            } else if request.tls_mode.unwrap_or_default() != TlsMode::NoTls {
+                Some("TLS")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(ConnectionError::Configuration(format!(
+                    "A Unix socket path can't be combined with {conflict}"
+                )));
+            }
+        }
+
+        if request.tls_options.is_some() && request.tls_mode.unwrap_or_default() == TlsMode::NoTls {
+            return Err(ConnectionError::Configuration(
+                "TLS options provided but TLS is disabled".to_string(),
+            ));
+        }
+
+        let tls_certificate_watcher = match request.tls_certificate_files.clone() {
+            Some(files) => {
+                if request.tls_mode.unwrap_or_default() == TlsMode::NoTls {
+                    return Err(ConnectionError::Configuration(
+                        "TLS certificate files provided but TLS is disabled".to_string(),
+                    ));
+                }
+                if !request.root_certs.is_empty()
+                    || !request.client_cert.is_empty()
+                    || !request.client_key.is_empty()
+                {
+                    return Err(ConnectionError::Configuration(
+                        "TLS certificate files can't be combined with root_certs, client_cert and client_key"
+                            .to_string(),
+                    ));
+                }
+                let watcher = TlsCertificateWatcher::start(files).map_err(|err| {
+                    ConnectionError::Configuration(format!(
+                        "Failed to load the TLS certificate files: {err}"
+                    ))
+                })?;
+                request.watched_tls_params = Some(watcher.conn_params());
+                Some(Arc::new(watcher))
+            }
+            None => None,
+        };
+
         // Create compression manager from configuration
         let compression_manager = create_compression_manager(request.compression_config.clone())?;
 
@@ -2046,9 +2645,22 @@ impl Client {
                 config.entry_ttl_ms,
                 config.eviction_policy,
                 config.enable_metrics,
+                config.tracking_mode,
             )
         });
 
+        let transaction_sessions = Arc::new(TransactionSessions::new(
+            request.get_connection_timeout(),
+            request.tcp_nodelay,
+        ));
+        let blocking_pool = request.blocking_commands_pool.as_ref().map(|config| {
+            Arc::new(BlockingConnectionPool::new(
+                config,
+                request.get_connection_timeout(),
+                request.tcp_nodelay,
+            ))
+        });
+
         tokio::time::timeout(client_creation_timeout, async move {
             // Create shared, thread-safe wrapper for the internal client that starts as lazy
             // Arc<RwLock<T>> enables multiple async tasks to safely share and modify the client state
@@ -2069,6 +2681,12 @@ impl Client {
                 request_timeout,
             )
             .await;
+            if let Some(push_queue) = &push_queue {
+                push_queue.set_synchronizer(Arc::downgrade(&pubsub_synchronizer));
+            }
+            if !keyspace_event_patterns.is_empty() {
+                pubsub_synchronizer.add_node_local_patterns(keyspace_event_patterns);
+            }
 
             // Extract connection metadata for OTel span attributes.
             // Port 0 is normalized to the default (6379) for OTel reporting.
@@ -2098,9 +2716,13 @@ impl Client {
                 inflight_log_interval,
                 compression_manager: compression_manager.clone(),
                 iam_token_manager: None,
+                credentials_provider: request.credentials_provider.clone(),
+                tls_certificate_watcher: tls_certificate_watcher.clone(),
                 pubsub_synchronizer: pubsub_synchronizer.clone(),
                 otel_metadata,
                 client_side_cache,
+                blocking_pool: blocking_pool.clone(),
+                transaction_sessions,
             };
 
             let client_arc = Arc::new(RwLock::new(client));
@@ -2130,6 +2752,7 @@ impl Client {
                     push_sender,
                     iam_token_manager.as_ref(),
                     pubsub_synchronizer.clone(),
+                    blocking_pool,
                 )
                 .await
                 .map_err(ConnectionError::Cluster)?;
@@ -2269,9 +2892,36 @@ mod tests {
         BLOCKING_CMD_TIMEOUT_EXTENSION, RequestTimeoutOption, TimeUnit, get_request_timeout,
     };
 
-    use super::{Client, ClientWrapper, LazyClient, get_timeout_from_cmd_arg};
+    use super::{
+        Client, ClientWrapper, LazyClient, check_for_commands_not_retried, get_timeout_from_cmd_arg,
+    };
+    use crate::errors::{RequestErrorType, error_type};
+    use redis::Value;
     use std::sync::Weak;
 
+    #[test]
+    fn test_commands_not_retried_are_reported_as_disconnect() {
+        let not_retried = redis::parse_redis_value(
+            format!(
+                "-{} INCR wasn't retried\r\n",
+                redis::NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        assert!(check_for_commands_not_retried(&[Value::Okay, Value::Int(1)]).is_ok());
+
+        let err = check_for_commands_not_retried(&[
+            Value::Okay,
+            not_retried.clone(),
+            Value::Int(1),
+            not_retried,
+        ])
+        .unwrap_err();
+        assert_eq!(error_type(&err), RequestErrorType::Disconnect);
+        assert!(err.to_string().contains("[1, 3]"));
+    }
+
     #[test]
     fn test_get_timeout_from_cmd_returns_correct_duration_int() {
         let mut cmd = Cmd::new();
@@ -2541,6 +3191,8 @@ mod tests {
             inflight_requests_limit: 1000,
             inflight_log_interval: 100,
             iam_token_manager: None,
+            credentials_provider: None,
+            tls_certificate_watcher: None,
             compression_manager: None,
             pubsub_synchronizer,
             otel_metadata: OTelMetadata {
@@ -2551,6 +3203,11 @@ mod tests {
                 db_namespace: "0".to_string(),
             },
             client_side_cache: None,
+            blocking_pool: None,
+            transaction_sessions: Arc::new(TransactionSessions::new(
+                DEFAULT_CONNECTION_TIMEOUT,
+                true,
+            )),
         }
     }
 
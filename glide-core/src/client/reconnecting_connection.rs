@@ -4,14 +4,17 @@ use super::{NodeAddress, TlsMode};
 use async_trait::async_trait;
 use futures_intrusive::sync::ManualResetEvent;
 use logger_core::{log_debug, log_error, log_trace, log_warn};
-use redis::aio::{DisconnectNotifier, MultiplexedConnection};
+use redis::aio::{ConnectionLike, DisconnectNotifier, MultiplexedConnection};
+use redis::cluster::ConnectionSelectionStrategy;
 use redis::{
     GlideConnectionOptions, PushInfo, RedisConnectionInfo, RedisError, RedisResult, RetryStrategy,
 };
 use std::fmt;
+use std::net::IpAddr;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{RwLock, RwLockReadGuard};
 use std::time::Duration;
 use telemetrylib::Telemetry;
@@ -117,8 +120,9 @@ struct ConnectionBackend {
     connection_info: RwLock<redis::Client>,
     /// Once this flag is set, the internal connection needs no longer try to reconnect to the server, because all the outer clients were dropped.
     client_dropped_flagged: AtomicBool,
-    /// Optional handle to the IAM token cache for refreshing the password before reconnection.
-    iam_token_handle: Option<IAMTokenHandle>,
+    /// Optional source of the password, i.e. the IAM token cache or a credentials provider, for refreshing the
+    /// password before reconnection.
+    password_provider: Option<Arc<dyn redis::IAMTokenProvider>>,
 }
 
 /// State of the current connection. Allows the user to use a connection only when a reconnect isn't in progress or has failed.
@@ -133,13 +137,25 @@ enum ConnectionState {
 
 struct InnerReconnectingConnection {
     state: Mutex<ConnectionState>,
+    /// The IP of the node that the current connection is connected to, if it's a TCP connection.
+    connected_ip: Mutex<Option<IpAddr>>,
     backend: ConnectionBackend,
 }
 
+/// Other connections to the same node. Requests are spread over them and the connection that owns them, while
+/// subscriptions always stay on the owning connection.
+#[derive(Default)]
+struct AdditionalConnections {
+    connections: Vec<ReconnectingConnection>,
+    selection_strategy: ConnectionSelectionStrategy,
+    last_used_connection: AtomicUsize,
+}
+
 #[derive(Clone)]
 pub(super) struct ReconnectingConnection {
     inner: Arc<InnerReconnectingConnection>,
     connection_options: GlideConnectionOptions,
+    additional_connections: Arc<AdditionalConnections>,
 }
 
 impl fmt::Debug for ReconnectingConnection {
@@ -151,14 +167,14 @@ impl fmt::Debug for ReconnectingConnection {
 async fn get_multiplexed_connection(
     client: &redis::Client,
     connection_options: &GlideConnectionOptions,
-) -> RedisResult<MultiplexedConnection> {
+) -> RedisResult<(MultiplexedConnection, Option<IpAddr>)> {
     run_with_timeout(
         Some(
             connection_options
                 .connection_timeout
                 .unwrap_or(DEFAULT_CONNECTION_TIMEOUT),
         ),
-        client.get_multiplexed_async_connection(connection_options.clone()),
+        client.get_multiplexed_async_connection_ip(connection_options.clone()),
     )
     .await
 }
@@ -202,6 +218,7 @@ async fn create_connection(
     connection_timeout: Duration,
     tcp_nodelay: bool,
     pubsub_synchronizer: Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
+    dns_resolver: Option<Arc<dyn redis::DnsResolver>>,
 ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
     let client = {
         let guard = connection_backend
@@ -222,12 +239,13 @@ async fn create_connection(
         tcp_nodelay,
         pubsub_synchronizer,
         iam_token_provider: None,
+        dns_resolver,
     };
 
     // Wrap retry loop in timeout so total time respects connection_timeout
     let action = || async {
         client
-            .get_multiplexed_async_connection(connection_options.clone())
+            .get_multiplexed_async_connection_ip(connection_options.clone())
             .await
             .map_err(|e| {
                 // Don't retry errors that won't resolve with retries
@@ -249,7 +267,7 @@ async fn create_connection(
     let result = timeout(connection_timeout, retry_future).await;
 
     match result {
-        Ok(Ok(connection)) => {
+        Ok(Ok((connection, connected_ip))) => {
             log_debug(
                 "connection creation",
                 format!(
@@ -264,9 +282,11 @@ async fn create_connection(
             Ok(ReconnectingConnection {
                 inner: Arc::new(InnerReconnectingConnection {
                     state: Mutex::new(ConnectionState::Connected(connection)),
+                    connected_ip: Mutex::new(connected_ip),
                     backend: connection_backend,
                 }),
                 connection_options,
+                additional_connections: Default::default(),
             })
         }
         err => {
@@ -287,9 +307,11 @@ async fn create_connection(
             let connection = ReconnectingConnection {
                 inner: Arc::new(InnerReconnectingConnection {
                     state: Mutex::new(ConnectionState::InitializedDisconnected),
+                    connected_ip: Mutex::new(None),
                     backend: connection_backend,
                 }),
                 connection_options,
+                additional_connections: Default::default(),
             };
             connection.reconnect(ReconnectReason::CreateError);
             Err((connection, err))
@@ -301,12 +323,18 @@ async fn create_connection(
 // this should be validated before calling this function
 fn get_client(
     address: &NodeAddress,
+    unix_socket_path: Option<&Path>,
     tls_mode: TlsMode,
     redis_connection_info: redis::RedisConnectionInfo,
     tls_params: Option<redis::TlsConnParams>,
 ) -> redis::Client {
-    let connection_info =
-        super::get_connection_info(address, tls_mode, redis_connection_info, tls_params);
+    let connection_info = match unix_socket_path {
+        Some(path) => redis::ConnectionInfo {
+            addr: redis::ConnectionAddr::Unix(path.to_path_buf()),
+            redis: redis_connection_info,
+        },
+        None => super::get_connection_info(address, tls_mode, redis_connection_info, tls_params),
+    };
     redis::Client::open(connection_info).unwrap() // can unwrap, because [open] fails only on trying to convert input to ConnectionInfo, and we pass ConnectionInfo.
 }
 
@@ -321,6 +349,7 @@ impl ReconnectingConnection {
     #[allow(clippy::too_many_arguments)]
     pub(super) async fn new(
         address: &NodeAddress,
+        unix_socket_path: Option<&Path>,
         connection_retry_strategy: RetryStrategy,
         redis_connection_info: RedisConnectionInfo,
         tls_mode: TlsMode,
@@ -330,19 +359,26 @@ impl ReconnectingConnection {
         tls_params: Option<redis::TlsConnParams>,
         tcp_nodelay: bool,
         pubsub_synchronizer: Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
-        iam_token_handle: Option<IAMTokenHandle>,
+        password_provider: Option<Arc<dyn redis::IAMTokenProvider>>,
+        dns_resolver: Option<Arc<dyn redis::DnsResolver>>,
     ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
         log_debug(
             "connection creation",
             format!("Attempting connection to {address}"),
         );
 
-        let connection_info = get_client(address, tls_mode, redis_connection_info, tls_params);
+        let connection_info = get_client(
+            address,
+            unix_socket_path,
+            tls_mode,
+            redis_connection_info,
+            tls_params,
+        );
         let backend = ConnectionBackend {
             connection_info: RwLock::new(connection_info),
             connection_available_signal: ManualResetEvent::new(true),
             client_dropped_flagged: AtomicBool::new(false),
-            iam_token_handle,
+            password_provider,
         };
         create_connection(
             backend,
@@ -352,10 +388,64 @@ impl ReconnectingConnection {
             connection_timeout,
             tcp_nodelay,
             pubsub_synchronizer,
+            dns_resolver,
         )
         .await
     }
 
+    /// Adds other connections to the same node, which [`Self::select_connection`] spreads the requests over.
+    pub(super) fn with_additional_connections(
+        mut self,
+        connections: Vec<ReconnectingConnection>,
+        selection_strategy: ConnectionSelectionStrategy,
+    ) -> Self {
+        self.additional_connections = Arc::new(AdditionalConnections {
+            connections,
+            selection_strategy,
+            last_used_connection: AtomicUsize::new(0),
+        });
+        self
+    }
+
+    /// Returns this connection, followed by the additional connections to the same node.
+    pub(super) fn all_connections(&self) -> impl Iterator<Item = &ReconnectingConnection> {
+        std::iter::once(self).chain(self.additional_connections.connections.iter())
+    }
+
+    /// Picks the connection to the node that the next request is sent on.
+    /// Falls back to this connection when the picked one is reconnecting.
+    pub(super) fn select_connection(&self) -> &ReconnectingConnection {
+        let additional = &self.additional_connections;
+        if additional.connections.is_empty() {
+            return self;
+        }
+        let selected = match additional.selection_strategy {
+            ConnectionSelectionStrategy::RoundRobin => {
+                let index = additional
+                    .last_used_connection
+                    .fetch_add(1, Ordering::Relaxed)
+                    .wrapping_add(1)
+                    % (additional.connections.len() + 1);
+                self.all_connections().nth(index)
+            }
+            ConnectionSelectionStrategy::LeastPending => self
+                .all_connections()
+                .filter(|connection| connection.is_connected())
+                .min_by_key(|connection| connection.pending_requests()),
+        };
+        selected
+            .filter(|connection| connection.is_connected())
+            .unwrap_or(self)
+    }
+
+    /// Number of requests sent on the connection that are still waiting for their response.
+    fn pending_requests(&self) -> usize {
+        match &*self.inner.state.lock().unwrap() {
+            ConnectionState::Connected(connection) => connection.pending_requests(),
+            _ => usize::MAX,
+        }
+    }
+
     pub(crate) fn node_address(&self) -> String {
         self.inner
             .backend
@@ -365,6 +455,15 @@ impl ReconnectingConnection {
             .to_string()
     }
 
+    /// Returns the information used to connect to the node, including the current session settings.
+    pub(super) fn connection_info(&self) -> redis::ConnectionInfo {
+        self.inner
+            .backend
+            .get_backend_client()
+            .get_connection_info()
+            .clone()
+    }
+
     pub(super) fn is_dropped(&self) -> bool {
         self.inner
             .backend
@@ -379,7 +478,10 @@ impl ReconnectingConnection {
         self.inner
             .backend
             .client_dropped_flagged
-            .store(true, Ordering::Relaxed)
+            .store(true, Ordering::Relaxed);
+        for connection in self.additional_connections.connections.iter() {
+            connection.mark_as_dropped();
+        }
     }
 
     pub(super) async fn try_get_connection(&self) -> Option<MultiplexedConnection> {
@@ -427,13 +529,13 @@ impl ReconnectingConnection {
         // The reconnect task is spawned instead of awaited here, so that the reconnect attempt will continue in the
         // background, regardless of whether the calling task is dropped or not.
         task::spawn(async move {
-            let has_iam = connection_clone.inner.backend.iam_token_handle.is_some();
+            let has_password_provider = connection_clone.inner.backend.password_provider.is_some();
 
-            // For non-IAM connections, clone the client once before the loop to preserve
+            // For connections without a password provider, clone the client once before the loop to preserve
             // the original reconnection behavior (password is fixed at reconnect start).
-            // For IAM connections, the client is cloned inside the loop so each retry
-            // picks up the freshest token written by the IAM handle.
-            let static_client = if !has_iam {
+            // For IAM or credentials provider connections, the client is cloned inside the loop so each retry
+            // picks up the freshest password written by the provider.
+            let static_client = if !has_password_provider {
                 Some({
                     let guard = connection_clone.inner.backend.get_backend_client();
                     guard.clone()
@@ -457,11 +559,11 @@ impl ReconnectingConnection {
                     return;
                 }
 
-                // If IAM authentication is configured, ensure the connection uses a
-                // valid token before attempting to reconnect.  If the cached token has
+                // If IAM authentication or a credentials provider is configured, ensure the connection
+                // uses a valid password before attempting to reconnect.  If the cached IAM token has
                 // expired, a fresh one is generated on demand via SigV4 signing.
-                if let Some(handle) = &connection_clone.inner.backend.iam_token_handle
-                    && let Some(valid_token) = handle.get_valid_token_inner().await
+                if let Some(provider) = &connection_clone.inner.backend.password_provider
+                    && let Some(valid_token) = provider.get_valid_token().await
                 {
                     let mut client = connection_clone
                         .inner
@@ -472,22 +574,34 @@ impl ReconnectingConnection {
                     client.update_password(Some(valid_token));
                     log_debug(
                         "reconnect",
-                        "Updated connection password with valid IAM token before reconnection attempt",
+                        "Updated connection password from the password provider before reconnection attempt",
                     );
                 }
 
-                let client = if let Some(ref c) = static_client {
-                    c.clone()
-                } else {
-                    // IAM path: re-read from backend to pick up the token update above
-                    let guard = connection_clone.inner.backend.get_backend_client();
-                    guard.clone()
+                let client = match static_client {
+                    // A Sentinel failover may have moved the connection to another address while reconnecting.
+                    Some(ref c)
+                        if c.get_connection_info().addr
+                            == connection_clone
+                                .inner
+                                .backend
+                                .get_backend_client()
+                                .get_connection_info()
+                                .addr =>
+                    {
+                        c.clone()
+                    }
+                    _ => {
+                        // Password provider path: re-read from backend to pick up the password update above
+                        let guard = connection_clone.inner.backend.get_backend_client();
+                        guard.clone()
+                    }
                 };
 
                 match get_multiplexed_connection(&client, &connection_clone.connection_options)
                     .await
                 {
-                    Ok(mut connection) => {
+                    Ok((mut connection, connected_ip)) => {
                         if connection
                             .send_packed_command(&redis::cmd("PING"))
                             .await
@@ -505,6 +619,7 @@ impl ReconnectingConnection {
                                 .connection_available_signal
                                 .set();
                             *guard = ConnectionState::Connected(connection);
+                            *connection_clone.inner.connected_ip.lock().unwrap() = connected_ip;
                         }
 
                         Telemetry::incr_total_connections(1);
@@ -516,6 +631,35 @@ impl ReconnectingConnection {
         });
     }
 
+    /// Returns the IP of the node that the connection is connected to, or `None` while it's reconnecting or if it
+    /// isn't a TCP connection.
+    pub(super) fn connected_ip(&self) -> Option<IpAddr> {
+        let guard = self.inner.state.lock().unwrap();
+        match *guard {
+            ConnectionState::Connected(_) => *self.inner.connected_ip.lock().unwrap(),
+            _ => None,
+        }
+    }
+
+    /// Opens a new connection to the node and sends the following requests on it, e.g. after the node's hostname moved
+    /// to another IP. The replaced connection is closed once the requests that were sent on it got their responses.
+    pub(super) async fn migrate(&self) -> RedisResult<()> {
+        let client = self.inner.backend.get_backend_client().clone();
+        let (mut connection, connected_ip) =
+            get_multiplexed_connection(&client, &self.connection_options).await?;
+        connection.send_packed_command(&redis::cmd("PING")).await?;
+        let mut guard = self.inner.state.lock().unwrap();
+        // A reconnection that started meanwhile resolves the hostname again by itself.
+        if matches!(*guard, ConnectionState::Connected(_)) {
+            // As on reconnect, the replaced connection is counted as closed and the new one as opened.
+            Telemetry::decr_total_connections(1);
+            *guard = ConnectionState::Connected(connection);
+            *self.inner.connected_ip.lock().unwrap() = connected_ip;
+            Telemetry::incr_total_connections(1);
+        }
+        Ok(())
+    }
+
     pub fn is_connected(&self) -> bool {
         !matches!(
             *self.inner.state.lock().unwrap(),
@@ -542,7 +686,10 @@ impl ReconnectingConnection {
             .connection_info
             .write()
             .expect(WRITE_LOCK_ERR);
-        client.update_password(new_password);
+        client.update_password(new_password.clone());
+        for connection in self.additional_connections.connections.iter() {
+            connection.update_connection_password(new_password.clone());
+        }
     }
 
     /// Updates the database ID that's saved inside connection_info, that will be used in case of disconnection from the server.
@@ -562,6 +709,9 @@ impl ReconnectingConnection {
             .write()
             .expect(WRITE_LOCK_ERR);
         client.update_database(new_database_id);
+        for connection in self.additional_connections.connections.iter() {
+            connection.update_connection_database(new_database_id);
+        }
     }
 
     /// Updates the client name that's saved inside connection_info, that will be used in case of disconnection from the server.
@@ -572,7 +722,10 @@ impl ReconnectingConnection {
             .connection_info
             .write()
             .expect(WRITE_LOCK_ERR);
-        client.update_client_name(new_client_name);
+        client.update_client_name(new_client_name.clone());
+        for connection in self.additional_connections.connections.iter() {
+            connection.update_connection_client_name(new_client_name.clone());
+        }
     }
 
     /// Updates the username that's saved inside connection_info, that will be used in case of disconnection from the server.
@@ -590,7 +743,10 @@ impl ReconnectingConnection {
             .connection_info
             .write()
             .expect(WRITE_LOCK_ERR);
-        client.update_username(new_username);
+        client.update_username(new_username.clone());
+        for connection in self.additional_connections.connections.iter() {
+            connection.update_connection_username(new_username.clone());
+        }
     }
 
     /// Updates the protocol version that's saved inside connection_info, that will be used in case of disconnection from the server.
@@ -609,6 +765,42 @@ impl ReconnectingConnection {
             .write()
             .expect(WRITE_LOCK_ERR);
         client.update_protocol(new_protocol);
+        for connection in self.additional_connections.connections.iter() {
+            connection.update_connection_protocol(new_protocol);
+        }
+    }
+
+    /// Points the connection at another node, e.g. after a Sentinel failover, and reconnects to it.
+    /// Returns the previous address, or `None` if the connection doesn't use a TCP address.
+    pub(super) fn update_address(&self, address: &NodeAddress) -> Option<NodeAddress> {
+        let previous_address = {
+            let mut client = self
+                .inner
+                .backend
+                .connection_info
+                .write()
+                .expect(WRITE_LOCK_ERR);
+            let mut connection_info = client.get_connection_info().clone();
+            let previous_address = match &mut connection_info.addr {
+                redis::ConnectionAddr::Tcp(host, port)
+                | redis::ConnectionAddr::TcpTls { host, port, .. } => NodeAddress {
+                    host: std::mem::replace(host, address.host.clone()),
+                    port: std::mem::replace(port, address.port),
+                },
+                redis::ConnectionAddr::Unix(_) => return None,
+            };
+            *client = redis::Client::open(connection_info).unwrap(); // can unwrap, because [open] fails only on trying to convert input to ConnectionInfo, and we pass ConnectionInfo.
+            previous_address
+        };
+        log_debug(
+            "reconnect",
+            format!("Connection moved from {previous_address} to {address}"),
+        );
+        self.reconnect(ReconnectReason::ConnectionDropped);
+        for connection in self.additional_connections.connections.iter() {
+            connection.update_address(address);
+        }
+        Some(previous_address)
     }
 
     /// Returns the username if one was configured during client creation. Otherwise, returns None.
@@ -0,0 +1,155 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use super::standalone_client::{Nodes, Topology};
+use super::{NodeAddress, SentinelConfig, TlsMode};
+use futures::StreamExt;
+use logger_core::{log_debug, log_info, log_warn};
+use redis::sentinel::Sentinel;
+use redis::{RedisConnectionInfo, RedisResult};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task;
+
+/// Delay before subscribing again after the connection to the sentinels was lost.
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+/// How often the failover listener checks whether the client was dropped while waiting for events.
+const DROPPED_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Creates a [Sentinel] querying the configured sentinels. The sentinels are reached with the same TLS settings as the
+/// nodes, and authenticated with the sentinel credentials of `config`.
+pub(super) fn build_sentinel(
+    config: &SentinelConfig,
+    tls_mode: TlsMode,
+    tls_params: Option<redis::TlsConnParams>,
+) -> RedisResult<Sentinel> {
+    let redis_connection_info = RedisConnectionInfo {
+        username: config.username.clone(),
+        password: config.password.clone(),
+        ..Default::default()
+    };
+    let sentinels_connection_info = config
+        .sentinel_addresses
+        .iter()
+        .map(|address| {
+            super::get_connection_info(
+                address,
+                tls_mode,
+                redis_connection_info.clone(),
+                tls_params.clone(),
+            )
+        })
+        .collect();
+    Sentinel::build(sentinels_connection_info)
+}
+
+/// Returns the addresses of the primary and of its replicas, as reported by the sentinels. The primary is always first.
+pub(super) async fn discover_nodes(
+    sentinel: &mut Sentinel,
+    master_name: &str,
+) -> RedisResult<Vec<NodeAddress>> {
+    let (host, port) = sentinel.async_master_address_for(master_name).await?;
+    let mut addresses = vec![NodeAddress { host, port }];
+    match sentinel.async_replica_addresses_for(master_name).await {
+        Ok(replicas) => {
+            for (host, port) in replicas {
+                let replica = NodeAddress { host, port };
+                if !addresses.contains(&replica) {
+                    addresses.push(replica);
+                }
+            }
+        }
+        Err(err) => log_warn(
+            "sentinel",
+            format!(
+                "Failed to discover the replicas of `{master_name}`, continuing with the primary only: {err}"
+            ),
+        ),
+    }
+    Ok(addresses)
+}
+
+/// Subscribes to the `+switch-master` events of the sentinels, and moves the primary connection to the new primary after
+/// every failover of `master_name`. Stops once the client is dropped.
+pub(super) fn start_failover_listener(
+    mut sentinel: Sentinel,
+    master_name: String,
+    topology: Arc<Topology>,
+) {
+    task::spawn(async move {
+        loop {
+            if topology.nodes().primary().is_dropped() {
+                log_debug(
+                    "sentinel",
+                    "failover listener stopped after client was dropped",
+                );
+                return;
+            }
+
+            let mut events = match sentinel.async_switch_master_events().await {
+                Ok(events) => events,
+                Err(err) => {
+                    log_warn(
+                        "sentinel",
+                        format!("Failed to subscribe to failover events: {err}"),
+                    );
+                    tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                    continue;
+                }
+            };
+
+            // Failovers that happened while we weren't subscribed are caught up on by asking for the current primary.
+            match sentinel.async_master_address_for(&master_name).await {
+                Ok((host, port)) => follow_new_primary(&topology.nodes(), host, port),
+                Err(err) => log_warn(
+                    "sentinel",
+                    format!("Failed to get the address of `{master_name}`: {err}"),
+                ),
+            }
+
+            loop {
+                match tokio::time::timeout(DROPPED_CHECK_INTERVAL, events.next()).await {
+                    Ok(Some(event)) => {
+                        if event.master_name == master_name {
+                            let (host, port) = event.new_address;
+                            follow_new_primary(&topology.nodes(), host, port);
+                        }
+                    }
+                    // The connection to the sentinel was lost.
+                    Ok(None) => break,
+                    Err(_) => {
+                        if topology.nodes().primary().is_dropped() {
+                            break;
+                        }
+                    }
+                }
+            }
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    });
+}
+
+/// Points the primary connection at the new primary. The promoted replica's connection is pointed at the previous
+/// primary, which rejoins as a replica once it recovers.
+fn follow_new_primary(nodes: &Nodes, host: String, port: u16) {
+    let primary = nodes.primary();
+    let new_primary_address = format!("{host}:{port}");
+    let previous_primary_address = primary.node_address();
+    if previous_primary_address == new_primary_address {
+        return;
+    }
+    log_info(
+        "sentinel",
+        format!("Primary moved from {previous_primary_address} to {new_primary_address}"),
+    );
+
+    let promoted_replica = nodes
+        .connections
+        .iter()
+        .find(|node| node.node_address() == new_primary_address);
+    let Some(previous_primary) = primary.update_address(&NodeAddress { host, port }) else {
+        return;
+    };
+    if let Some(promoted_replica) = promoted_replica {
+        promoted_replica.update_address(&previous_primary);
+    }
+}
@@ -1,23 +1,89 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
+use super::blocking_pool::BlockingConnectionPool;
 use super::get_valkey_connection_info;
 use super::reconnecting_connection::{ReconnectReason, ReconnectingConnection};
-use super::{ConnectionRequest, NodeAddress, TlsMode};
+use super::sentinel;
+use super::{ConnectionRequest, NodeAddress, SentinelConfig, TlsMode};
 use crate::client::types::ReadFrom as ClientReadFrom;
+use crate::credentials::CredentialsProviderHandle;
+use arc_swap::ArcSwap;
 use futures::{StreamExt, future, stream};
 use logger_core::log_debug;
+use logger_core::log_error;
+use logger_core::log_info;
 use logger_core::log_warn;
 use redis::aio::ConnectionLike;
+use redis::circuit_breaker::CircuitBreaker;
+use redis::cluster::ConnectionSelectionStrategy;
 use redis::cluster_routing::{self, ResponsePolicy, Routable, RoutingInfo, is_readonly_cmd};
-use redis::{PushInfo, RedisError, RedisResult, RetryStrategy, Value};
-use std::sync::Arc;
+use redis::latency_tracker::{LATENCY_PROBE_INTERVAL, LatencyTracker};
+use redis::sentinel::Sentinel;
+use redis::{DnsResolver, PushInfo, RedisError, RedisResult, RetryStrategy, Value};
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::path::PathBuf;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
-use std::time::Duration;
-use telemetrylib::Telemetry;
-use tokio::sync::mpsc;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use telemetrylib::{GlideOpenTelemetry, Telemetry};
+use tokio::sync::{Notify, mpsc};
 use tokio::task;
 
+/// Records the latency of a request of `request_type` sent to the node, if OpenTelemetry is initialized.
+fn record_command_latency(
+    request_type: &str,
+    reconnecting_connection: &ReconnectingConnection,
+    latency: Duration,
+) {
+    if !GlideOpenTelemetry::is_initialized() {
+        return;
+    }
+    if let Err(e) = GlideOpenTelemetry::record_command_latency(
+        request_type,
+        &reconnecting_connection.node_address(),
+        latency,
+    ) {
+        log_error(
+            "OpenTelemetry:command_latency",
+            format!("Failed to record command latency: {e}"),
+        );
+    }
+}
+
+/// Returns true for the commands whose subscriptions are tracked on the first connection to the node.
+fn is_subscription_command(cmd: &redis::Cmd) -> bool {
+    matches!(
+        Routable::command(cmd).as_deref(),
+        Some(
+            b"SUBSCRIBE"
+                | b"PSUBSCRIBE"
+                | b"SSUBSCRIBE"
+                | b"UNSUBSCRIBE"
+                | b"PUNSUBSCRIBE"
+                | b"SUNSUBSCRIBE"
+        )
+    )
+}
+
+/// Returns true for the commands of optimistic transactions. `WATCH` only applies to the connection it was sent on, so
+/// these are sent on the first connection to the node, along with the transactions themselves.
+fn is_transaction_command(cmd: &redis::Cmd) -> bool {
+    matches!(
+        Routable::command(cmd).as_deref(),
+        Some(b"WATCH" | b"UNWATCH" | b"MULTI" | b"EXEC" | b"DISCARD")
+    )
+}
+
+/// Returns true for the commands that change the state of the connection they are sent on, rather than of the node.
+fn is_connection_state_command(cmd: &redis::Cmd) -> bool {
+    matches!(
+        Routable::command(cmd).as_deref(),
+        Some(b"AUTH" | b"SELECT" | b"HELLO" | b"CLIENT SETNAME")
+    )
+}
+
 #[derive(Debug)]
 enum ReadFrom {
     Primary,
@@ -35,21 +101,333 @@ enum ReadFrom {
         client_az: String,
         last_read_replica_index: Arc<AtomicUsize>,
     },
+    LowestLatency {
+        hysteresis: Duration,
+        latency_tracker: Arc<LatencyTracker>,
+        last_read_replica_index: Arc<AtomicUsize>,
+    },
 }
 
+/// The connections to the nodes, and which of them is the primary.
 #[derive(Debug)]
-struct DropWrapper {
-    /// Connection to the primary node in the client.
+pub(super) struct Nodes {
+    pub(super) connections: Vec<ReconnectingConnection>,
     primary_index: usize,
-    nodes: Vec<ReconnectingConnection>,
+}
+
+impl Nodes {
+    /// Returns the connection to the primary node.
+    pub(super) fn primary(&self) -> &ReconnectingConnection {
+        &self.connections[self.primary_index]
+    }
+}
+
+/// Tracks the nodes of the client and which of them is the primary. The primary is re-discovered through `ROLE` after
+/// a failover, which is noticed when the primary rejects a write with `READONLY`, when it disconnects, and by the
+/// periodic checks. Replica discovery adds and removes replicas. Both replace the nodes as a whole, so that the index
+/// of the primary always matches the connections.
+#[derive(Debug)]
+pub(super) struct Topology {
+    nodes: ArcSwap<Nodes>,
+    /// Serializes the updates of the nodes, so that requests failing together send a single round of `ROLE`.
+    update_lock: tokio::sync::Mutex<()>,
+    /// Wakes the topology monitor to re-discover the primary and the replicas in the background.
+    recheck: Notify,
+}
+
+impl Topology {
+    fn new(nodes: Nodes) -> Self {
+        Self {
+            nodes: ArcSwap::from_pointee(nodes),
+            update_lock: Default::default(),
+            recheck: Notify::new(),
+        }
+    }
+
+    /// Returns the current nodes.
+    pub(super) fn nodes(&self) -> Arc<Nodes> {
+        self.nodes.load_full()
+    }
+
+    /// Asks the topology monitor to re-discover the primary and the replicas.
+    fn request_recheck(&self) {
+        self.recheck.notify_one();
+    }
+
+    /// Sends `ROLE` to the connected nodes and moves the primary to the node that reports itself as primary, unless
+    /// the primary already moved away from the primary of `observed`. Returns whether the primary isn't the primary of
+    /// `observed` anymore.
+    async fn rediscover_primary(&self, observed: &Nodes) -> bool {
+        let _guard = self.update_lock.lock().await;
+        let nodes = self.nodes();
+        if nodes.primary().node_address() != observed.primary().node_address() {
+            return true;
+        }
+
+        let roles = future::join_all(nodes.connections.iter().map(|node| async move {
+            let mut connection = node.try_get_connection().await?;
+            connection
+                .send_packed_command(&redis::cmd("ROLE"))
+                .await
+                .ok()
+                .map(|role| is_primary_role(&role))
+        }))
+        .await;
+        let primaries: Vec<usize> = roles
+            .iter()
+            .enumerate()
+            .filter(|(_, is_primary)| **is_primary == Some(true))
+            .map(|(index, _)| index)
+            .collect();
+        match primaries.as_slice() {
+            [new_index] if *new_index != nodes.primary_index => {
+                log_info(
+                    "StandaloneClient",
+                    format!(
+                        "Primary moved from {} to {}",
+                        nodes.primary().node_address(),
+                        nodes.connections[*new_index].node_address()
+                    ),
+                );
+                self.nodes.store(Arc::new(Nodes {
+                    connections: nodes.connections.clone(),
+                    primary_index: *new_index,
+                }));
+                true
+            }
+            [] | [_] => false,
+            _ => {
+                // Both nodes report themselves as primary while the failover is in progress.
+                log_warn(
+                    "StandaloneClient",
+                    format!(
+                        "More than one node reported the primary role, keeping {} as primary",
+                        nodes.primary().node_address()
+                    ),
+                );
+                false
+            }
+        }
+    }
+}
+
+/// Returns whether a `ROLE` reply is the reply of a primary.
+fn is_primary_role(role: &Value) -> bool {
+    let Value::Array(fields) = role else {
+        return false;
+    };
+    match fields.first() {
+        Some(Value::BulkString(role)) => role == b"master",
+        Some(Value::SimpleString(role)) => role == "master",
+        _ => false,
+    }
+}
+
+/// Returns the addresses of the online replicas in the `INFO REPLICATION` output of a primary, which lists them as
+/// `slave0:ip=10.0.0.2,port=6379,state=online,offset=1234,lag=0`.
+fn parse_replica_addresses(replication_info: &str) -> Vec<NodeAddress> {
+    replication_info
+        .lines()
+        .filter_map(|line| {
+            let (name, fields) = line.trim().split_once(':')?;
+            let is_replica_line = name.strip_prefix("slave").is_some_and(|index| {
+                !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit())
+            });
+            if !is_replica_line {
+                return None;
+            }
+            let (mut host, mut port, mut online) = (None, None, false);
+            for field in fields.split(',') {
+                match field.split_once('=') {
+                    Some(("ip", value)) => host = Some(value.to_string()),
+                    Some(("port", value)) => port = value.parse().ok(),
+                    Some(("state", value)) => online = value == "online",
+                    _ => {}
+                }
+            }
+            if !online {
+                return None;
+            }
+            Some(NodeAddress {
+                host: host?,
+                port: port?,
+            })
+        })
+        .collect()
+}
+
+/// The settings the connections to the nodes are created with.
+#[derive(Clone)]
+struct NodeConnectionSettings {
+    unix_socket_path: Option<PathBuf>,
+    retry_strategy: RetryStrategy,
+    tls_mode: TlsMode,
+    push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+    discover_az: bool,
+    connection_timeout: Duration,
+    tls_params: Option<redis::TlsConnParams>,
+    tcp_nodelay: bool,
+    pubsub_synchronizer: Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
+    password_provider: Option<Arc<dyn redis::IAMTokenProvider>>,
+    connections_per_node: usize,
+    connection_selection_strategy: ConnectionSelectionStrategy,
+    dns_resolver: Option<Arc<dyn DnsResolver>>,
+}
+
+impl std::fmt::Debug for NodeConnectionSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodeConnectionSettings")
+            .field("tls_mode", &self.tls_mode)
+            .field("connections_per_node", &self.connections_per_node)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Connects to the replicas that the primary reports.
+#[derive(Debug)]
+struct ReplicaDiscovery {
+    settings: NodeConnectionSettings,
+    /// The addresses the client was created with. Only the replicas that were discovered are removed again.
+    configured_addresses: Vec<String>,
+}
+
+#[derive(Debug)]
+struct DropWrapper {
+    /// The connections to the nodes, and which of them is the primary.
+    topology: Arc<Topology>,
     read_from: ReadFrom,
     /// When true, write commands are blocked and INFO REPLICATION is skipped during connection.
     read_only: bool,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    replica_discovery: Option<ReplicaDiscovery>,
+}
+
+impl DropWrapper {
+    /// Connects to the replicas listed by the primary that the client isn't connected to yet, and drops the discovered
+    /// replicas that the primary doesn't list anymore.
+    async fn discover_replicas(&self, discovery: &ReplicaDiscovery) {
+        let nodes = self.topology.nodes();
+        // A read-only client doesn't know which node is the primary, so the replicas are listed by the first node
+        // that reports the primary role.
+        let candidates: Vec<&ReconnectingConnection> = if self.read_only {
+            nodes.connections.iter().collect()
+        } else {
+            vec![nodes.primary()]
+        };
+        let mut source = None;
+        for node in candidates {
+            if let Some(replication_info) =
+                replication_info(node, discovery.settings.connection_timeout).await
+            {
+                if !self.read_only || replication_info.contains("role:master") {
+                    source = Some((node, replication_info));
+                    break;
+                }
+            }
+        }
+        let Some((source, replication_info)) = source else {
+            return;
+        };
+        let listed_addresses: Vec<NodeAddress> = parse_replica_addresses(&replication_info);
+        let listed: HashSet<String> = listed_addresses
+            .iter()
+            .map(|address| format!("{}:{}", address.host, address.port))
+            .collect();
+        // The primary reports the IPs of the replicas, which also matches the nodes the client is connected to by
+        // hostname.
+        let dns_resolver = discovery
+            .settings
+            .dns_resolver
+            .as_deref()
+            .unwrap_or(&redis::aio::SystemDnsResolver);
+        let mut known = HashSet::new();
+        for node in nodes.connections.iter() {
+            known.extend(resolved_node_addresses(node, dns_resolver).await);
+        }
+
+        // New replicas are connected with the current session settings of the node that listed them.
+        let connection_info = source.connection_info().redis;
+        let new_connections = future::join_all(
+            listed_addresses
+                .iter()
+                .filter(|address| !known.contains(&format!("{}:{}", address.host, address.port)))
+                .map(|address| async {
+                    log_info(
+                        "replica discovery",
+                        format!("Connecting to discovered replica {address}"),
+                    );
+                    match get_connection_and_replication_info(
+                        address,
+                        &discovery.settings,
+                        &connection_info,
+                        true,
+                    )
+                    .await
+                    {
+                        Ok((connection, _)) => connection,
+                        // The connection keeps reconnecting in the background.
+                        Err((connection, err)) => {
+                            log_warn(
+                                "replica discovery",
+                                format!("Failed to connect to discovered replica {address}: {err}"),
+                            );
+                            connection
+                        }
+                    }
+                }),
+        )
+        .await;
+        for node in new_connections.iter() {
+            StandaloneClient::start_node_tasks(node, &self.read_from);
+        }
+
+        let _guard = self.topology.update_lock.lock().await;
+        // The primary may have moved while connecting to the new replicas.
+        let nodes = self.topology.nodes();
+        let primary_address = nodes.primary().node_address();
+        let (mut connections, removed): (Vec<_>, Vec<_>) =
+            nodes.connections.iter().cloned().partition(|node| {
+                let address = node.node_address();
+                address == primary_address
+                    || listed.contains(&address)
+                    || discovery.configured_addresses.contains(&address)
+            });
+        if removed.is_empty() && new_connections.is_empty() {
+            return;
+        }
+        for node in removed {
+            let address = node.node_address();
+            log_info(
+                "replica discovery",
+                format!("Dropping replica {address}, which the primary doesn't list anymore"),
+            );
+            node.mark_as_dropped();
+            if let ReadFrom::LowestLatency {
+                latency_tracker, ..
+            } = &self.read_from
+            {
+                latency_tracker.remove(&address);
+            }
+            if let Some(circuit_breaker) = &self.circuit_breaker {
+                circuit_breaker.remove(&address);
+            }
+        }
+        connections.extend(new_connections);
+        // The primary is always kept.
+        let primary_index = connections
+            .iter()
+            .position(|node| node.node_address() == primary_address)
+            .unwrap();
+        self.topology.nodes.store(Arc::new(Nodes {
+            connections,
+            primary_index,
+        }));
+    }
 }
 
 impl Drop for DropWrapper {
     fn drop(&mut self) {
-        for node in self.nodes.iter() {
+        for node in self.topology.nodes().connections.iter() {
             node.mark_as_dropped();
         }
     }
@@ -122,7 +500,10 @@ impl StandaloneClient {
         iam_token_manager: Option<&Arc<crate::iam::IAMTokenManager>>,
         pubsub_synchronizer: Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
     ) -> Result<Self, StandaloneClientConnectionError> {
-        if connection_request.addresses.is_empty() {
+        if connection_request.addresses.is_empty()
+            && connection_request.sentinel_config.is_none()
+            && connection_request.unix_socket_path.is_none()
+        {
             return Err(StandaloneClientConnectionError::NoAddressesProvided);
         }
 
@@ -143,6 +524,20 @@ impl StandaloneClient {
             )]));
         }
 
+        // Replicas are discovered through the primary, and Sentinel already reports them.
+        if connection_request.discover_replicas
+            && (connection_request.sentinel_config.is_some()
+                || connection_request.unix_socket_path.is_some())
+        {
+            return Err(StandaloneClientConnectionError::FailedConnection(vec![(
+                None,
+                RedisError::from((
+                    redis::ErrorKind::InvalidClientConfig,
+                    "replica discovery is not compatible with Sentinel or Unix domain sockets",
+                )),
+            )]));
+        }
+
         let valkey_connection_info =
             get_valkey_connection_info(&connection_request, iam_token_manager).await;
         let retry_strategy = match connection_request.connection_retry_strategy {
@@ -156,7 +551,6 @@ impl StandaloneClient {
         };
 
         let tls_mode = connection_request.tls_mode;
-        let node_count = connection_request.addresses.len();
         let discover_az = matches!(
             connection_request.read_from,
             Some(ClientReadFrom::AZAffinity(_))
@@ -166,6 +560,10 @@ impl StandaloneClient {
         let connection_timeout = connection_request.get_connection_timeout();
 
         let tcp_nodelay = connection_request.tcp_nodelay;
+        let connections_per_node = connection_request
+            .connections_per_node
+            .map_or(1, |count| count.max(1) as usize);
+        let connection_selection_strategy = connection_request.connection_selection_strategy;
 
         let has_root_certs = !connection_request.root_certs.is_empty();
         let has_client_cert = !connection_request.client_cert.is_empty();
@@ -220,45 +618,81 @@ impl StandaloneClient {
                 })?,
             )
         } else {
-            None
+            connection_request.watched_tls_params.clone()
         };
+        let tls_params =
+            super::with_tls_options(tls_params, connection_request.tls_options.as_ref());
 
         let read_only = connection_request.read_only;
-        let addresses = connection_request.addresses.clone();
+        let (addresses, sentinel) = match &connection_request.sentinel_config {
+            Some(sentinel_config) => {
+                let (addresses, sentinel) = discover_nodes_through_sentinel(
+                    sentinel_config,
+                    tls_mode.unwrap_or(TlsMode::NoTls),
+                    tls_params.clone(),
+                )
+                .await
+                .map_err(|err| {
+                    StandaloneClientConnectionError::FailedConnection(vec![(None, err)])
+                })?;
+                (addresses, Some(sentinel))
+            }
+            // The socket path stands for the address in logs and errors.
+            None => match &connection_request.unix_socket_path {
+                Some(path) => (
+                    vec![NodeAddress {
+                        host: path.display().to_string(),
+                        port: 0,
+                    }],
+                    None,
+                ),
+                None => (connection_request.addresses.clone(), None),
+            },
+        };
+        let node_count = addresses.len();
         let read_from_option = connection_request.read_from.clone();
 
-        let iam_token_handle = iam_token_manager.map(|m| m.get_token_handle());
+        let password_provider: Option<Arc<dyn redis::IAMTokenProvider>> = iam_token_manager
+            .map(|m| Arc::new(m.get_token_handle()) as Arc<dyn redis::IAMTokenProvider>)
+            .or_else(|| {
+                connection_request
+                    .credentials_provider
+                    .clone()
+                    .map(|provider| {
+                        Arc::new(CredentialsProviderHandle(provider))
+                            as Arc<dyn redis::IAMTokenProvider>
+                    })
+            });
+
+        let settings = NodeConnectionSettings {
+            unix_socket_path: connection_request.unix_socket_path.clone(),
+            retry_strategy,
+            tls_mode: tls_mode.unwrap_or(TlsMode::NoTls),
+            push_sender,
+            discover_az,
+            connection_timeout,
+            tls_params,
+            tcp_nodelay,
+            pubsub_synchronizer,
+            password_provider,
+            connections_per_node,
+            connection_selection_strategy,
+            dns_resolver: connection_request.dns_resolver.clone(),
+        };
+        let configured_addresses: Vec<String> = addresses
+            .iter()
+            .map(|address| format!("{}:{}", address.host, address.port))
+            .collect();
 
+        let stream_settings = settings.clone();
         let mut stream = stream::iter(addresses)
             .map(move |address| {
+                let settings = stream_settings.clone();
                 let info = valkey_connection_info.clone();
-                let retry = retry_strategy;
-                let sender = push_sender.clone();
-                let tls = tls_mode.unwrap_or(TlsMode::NoTls);
-                let discover = discover_az;
-                let timeout = connection_timeout;
-                let params = tls_params.clone();
-                let nodelay = tcp_nodelay;
-                let sync = pubsub_synchronizer.clone();
-                let skip_replication = read_only;
-                let iam_handle = iam_token_handle.clone();
                 async move {
-                    get_connection_and_replication_info(
-                        &address,
-                        &retry,
-                        &info,
-                        tls,
-                        &sender,
-                        discover,
-                        timeout,
-                        params,
-                        nodelay,
-                        &sync,
-                        skip_replication,
-                        iam_handle,
-                    )
-                    .await
-                    .map_err(|err| (format!("{}:{}", address.host, address.port), err))
+                    get_connection_and_replication_info(&address, &settings, &info, read_only)
+                        .await
+                        .map_err(|err| (format!("{}:{}", address.host, address.port), err))
                 }
             })
             .buffer_unordered(node_count);
@@ -347,50 +781,109 @@ impl StandaloneClient {
             get_read_from(read_from_option)
         };
 
-        #[cfg(feature = "standalone_heartbeat")]
         for node in nodes.iter() {
-            Self::start_heartbeat(node.clone());
+            Self::start_node_tasks(node, &read_from);
         }
 
-        for node in nodes.iter() {
-            Self::start_periodic_connection_check(node.clone());
+        // Without writes there's no primary to follow, and without other nodes there's no other node to promote. The
+        // discovered replicas are still updated.
+        let monitor_topology =
+            connection_request.discover_replicas || (!read_only && nodes.len() > 1);
+        let topology = Arc::new(Topology::new(Nodes {
+            connections: nodes,
+            primary_index,
+        }));
+
+        if let (Some(sentinel), Some(sentinel_config)) =
+            (sentinel, &connection_request.sentinel_config)
+        {
+            sentinel::start_failover_listener(
+                sentinel,
+                sentinel_config.master_name.clone(),
+                topology.clone(),
+            );
+        }
+
+        let inner = Arc::new(DropWrapper {
+            topology,
+            read_from,
+            read_only,
+            circuit_breaker: connection_request
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreaker::new(config))),
+            replica_discovery: connection_request
+                .discover_replicas
+                .then(|| ReplicaDiscovery {
+                    settings,
+                    configured_addresses,
+                }),
+        });
+        if let Some(discovery) = &inner.replica_discovery {
+            inner.discover_replicas(discovery).await;
+        }
+        if monitor_topology {
+            Self::start_topology_monitor(
+                Arc::downgrade(&inner),
+                super::periodic_checks_interval(connection_request.periodic_checks),
+            );
+        }
+        if let Some(interval) = connection_request.dns_refresh_interval {
+            Self::start_dns_refresh(
+                Arc::downgrade(&inner),
+                interval,
+                connection_request.dns_resolver,
+            );
         }
 
         // Successfully created new client. Update the telemetry
         Telemetry::incr_total_clients(1);
 
-        Ok(Self {
-            inner: Arc::new(DropWrapper {
-                primary_index,
-                nodes,
-                read_from,
-                read_only,
-            }),
-        })
+        Ok(Self { inner })
+    }
+
+    /// Starts the background tasks that keep the connections to the node alive and measured.
+    fn start_node_tasks(node: &ReconnectingConnection, read_from: &ReadFrom) {
+        #[cfg(feature = "standalone_heartbeat")]
+        for connection in node.all_connections() {
+            Self::start_heartbeat(connection.clone());
+        }
+
+        for connection in node.all_connections() {
+            Self::start_periodic_connection_check(connection.clone());
+        }
+
+        if let ReadFrom::LowestLatency {
+            latency_tracker, ..
+        } = read_from
+        {
+            Self::start_latency_probe(node.clone(), latency_tracker.clone());
+        }
     }
 
-    fn get_primary_connection(&self) -> &ReconnectingConnection {
-        self.inner.nodes.get(self.inner.primary_index).unwrap()
+    /// Returns the information used to connect to the primary, including the current session settings.
+    pub(crate) fn primary_connection_info(&self) -> redis::ConnectionInfo {
+        self.inner.topology.nodes().primary().connection_info()
     }
 
-    fn round_robin_read_from_replica(
+    fn round_robin_read_from_replica<'a>(
         &self,
+        nodes: &'a Nodes,
         latest_read_replica_index: &Arc<AtomicUsize>,
-    ) -> &ReconnectingConnection {
+    ) -> &'a ReconnectingConnection {
         let initial_index = latest_read_replica_index.load(Ordering::Relaxed);
         let mut check_count = 0;
         loop {
             check_count += 1;
 
             // Looped through all replicas, no connected replica was found.
-            if check_count > self.inner.nodes.len() {
-                return self.get_primary_connection();
+            if check_count > nodes.connections.len() {
+                return nodes.primary();
             }
-            let index = (initial_index + check_count) % self.inner.nodes.len();
-            if index == self.inner.primary_index {
+            let index = (initial_index + check_count) % nodes.connections.len();
+            if index == nodes.primary_index {
                 continue;
             }
-            let Some(connection) = self.inner.nodes.get(index) else {
+            let Some(connection) = nodes.connections.get(index) else {
                 continue;
             };
             if connection.is_connected() {
@@ -405,21 +898,22 @@ impl StandaloneClient {
         }
     }
 
-    fn round_robin_read_from_all_nodes(
+    fn round_robin_read_from_all_nodes<'a>(
         &self,
+        nodes: &'a Nodes,
         latest_read_node_index: &Arc<AtomicUsize>,
-    ) -> &ReconnectingConnection {
+    ) -> &'a ReconnectingConnection {
         let initial_index = latest_read_node_index.load(Ordering::Relaxed);
         let mut check_count = 0;
         loop {
             check_count += 1;
 
             // Looped through all nodes, no connected node was found.
-            if check_count > self.inner.nodes.len() {
-                return self.get_primary_connection();
+            if check_count > nodes.connections.len() {
+                return nodes.primary();
             }
-            let index = (initial_index + check_count) % self.inner.nodes.len();
-            let Some(connection) = self.inner.nodes.get(index) else {
+            let index = (initial_index + check_count) % nodes.connections.len();
+            let Some(connection) = nodes.connections.get(index) else {
                 continue;
             };
             if connection.is_connected() {
@@ -434,25 +928,26 @@ impl StandaloneClient {
         }
     }
 
-    async fn round_robin_read_from_replica_az_awareness(
+    async fn round_robin_read_from_replica_az_awareness<'a>(
         &self,
+        nodes: &'a Nodes,
         latest_read_replica_index: &Arc<AtomicUsize>,
         client_az: String,
-    ) -> &ReconnectingConnection {
+    ) -> &'a ReconnectingConnection {
         let initial_index = latest_read_replica_index.load(Ordering::Relaxed);
         let mut retries = 0usize;
 
         loop {
             retries = retries.saturating_add(1);
             // Looped through all replicas; no connected replica found in the same AZ.
-            if retries > self.inner.nodes.len() {
+            if retries > nodes.connections.len() {
                 // Attempt a fallback to any available replica in other AZs or primary.
-                return self.round_robin_read_from_replica(latest_read_replica_index);
+                return self.round_robin_read_from_replica(nodes, latest_read_replica_index);
             }
 
             // Calculate index based on initial index and check count.
-            let index = (initial_index + retries) % self.inner.nodes.len();
-            let replica = &self.inner.nodes[index];
+            let index = (initial_index + retries) % nodes.connections.len();
+            let replica = &nodes.connections[index];
 
             // Attempt to get a connection and retrieve the replica's AZ.
             if let Ok(connection) = replica.get_connection().await
@@ -471,11 +966,12 @@ impl StandaloneClient {
         }
     }
 
-    async fn round_robin_read_from_replica_az_awareness_replicas_and_primary(
+    async fn round_robin_read_from_replica_az_awareness_replicas_and_primary<'a>(
         &self,
+        nodes: &'a Nodes,
         latest_read_replica_index: &Arc<AtomicUsize>,
         client_az: String,
-    ) -> &ReconnectingConnection {
+    ) -> &'a ReconnectingConnection {
         let initial_index = latest_read_replica_index.load(Ordering::Relaxed);
         let mut retries = 0usize;
 
@@ -483,13 +979,13 @@ impl StandaloneClient {
         loop {
             retries = retries.saturating_add(1);
             // Looped through all replicas; no connected replica found in the same AZ.
-            if retries >= self.inner.nodes.len() {
+            if retries >= nodes.connections.len() {
                 break;
             }
 
             // Calculate index based on initial index and check count.
-            let index = (initial_index + retries) % self.inner.nodes.len();
-            let replica = &self.inner.nodes[index];
+            let index = (initial_index + retries) % nodes.connections.len();
+            let replica = &nodes.connections[index];
 
             // Attempt to get a connection and retrieve the replica's AZ.
             if let Ok(connection) = replica.get_connection().await
@@ -508,7 +1004,7 @@ impl StandaloneClient {
         }
 
         // Step 2: Check if primary is in the same AZ
-        let primary = self.get_primary_connection();
+        let primary = nodes.primary();
         if let Ok(connection) = primary.get_connection().await
             && let Some(primary_az) = connection.get_az().as_deref()
             && primary_az == client_az
@@ -517,27 +1013,92 @@ impl StandaloneClient {
         }
 
         // Step 3: Fall back to any available replica using round-robin
-        self.round_robin_read_from_replica(latest_read_replica_index)
+        self.round_robin_read_from_replica(nodes, latest_read_replica_index)
+    }
+
+    /// Returns the connected replica with the lowest measured latency, keeping the previously used
+    /// replica unless another one is faster by more than `hysteresis`.
+    /// Falls back to round robin if no connected replica was measured yet.
+    fn read_from_lowest_latency_replica<'a>(
+        &self,
+        nodes: &'a Nodes,
+        latency_tracker: &LatencyTracker,
+        last_read_replica_index: &Arc<AtomicUsize>,
+        hysteresis: Duration,
+    ) -> &'a ReconnectingConnection {
+        let replicas: Vec<(usize, String)> = nodes
+            .connections
+            .iter()
+            .enumerate()
+            .filter(|(index, node)| *index != nodes.primary_index && node.is_connected())
+            .map(|(index, node)| (index, node.node_address()))
+            .collect();
+        let last_read_index = last_read_replica_index.load(Ordering::Relaxed);
+        let current = replicas
+            .iter()
+            .position(|(index, _)| *index == last_read_index);
+
+        match latency_tracker.select_lowest(
+            replicas.iter().map(|(_, address)| address.as_str()),
+            current,
+            hysteresis,
+        ) {
+            Some(position) => {
+                let index = replicas[position].0;
+                last_read_replica_index.store(index, Ordering::Relaxed);
+                &nodes.connections[index]
+            }
+            None => self.round_robin_read_from_replica(nodes, last_read_replica_index),
+        }
+    }
+
+    /// Returns `node`, or another node whose circuit is closed if the circuit of `node` is open.
+    /// Replicas are preferred over the primary.
+    fn node_with_closed_circuit<'a>(
+        &self,
+        nodes: &'a Nodes,
+        node: &'a ReconnectingConnection,
+    ) -> &'a ReconnectingConnection {
+        let Some(circuit_breaker) = &self.inner.circuit_breaker else {
+            return node;
+        };
+        if !circuit_breaker.is_open(&node.node_address()) {
+            return node;
+        }
+        nodes
+            .connections
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != nodes.primary_index)
+            .map(|(_, replica)| replica)
+            .chain(std::iter::once(nodes.primary()))
+            .find(|candidate| !circuit_breaker.is_open(&candidate.node_address()))
+            .unwrap_or(node)
     }
 
-    async fn get_connection(&self, readonly: bool) -> &ReconnectingConnection {
-        if self.inner.nodes.len() == 1 || !readonly {
-            return self.get_primary_connection();
+    async fn get_connection<'a>(
+        &self,
+        nodes: &'a Nodes,
+        readonly: bool,
+    ) -> &'a ReconnectingConnection {
+        if nodes.connections.len() == 1 || !readonly {
+            return nodes.primary();
         }
 
-        match &self.inner.read_from {
-            ReadFrom::Primary => self.get_primary_connection(),
+        let node = match &self.inner.read_from {
+            ReadFrom::Primary => nodes.primary(),
             ReadFrom::PreferReplica {
                 latest_read_replica_index,
-            } => self.round_robin_read_from_replica(latest_read_replica_index),
+            } => self.round_robin_read_from_replica(nodes, latest_read_replica_index),
             ReadFrom::AllNodes {
                 latest_read_node_index,
-            } => self.round_robin_read_from_all_nodes(latest_read_node_index),
+            } => self.round_robin_read_from_all_nodes(nodes, latest_read_node_index),
             ReadFrom::AZAffinity {
                 client_az,
                 last_read_replica_index,
             } => {
                 self.round_robin_read_from_replica_az_awareness(
+                    nodes,
                     last_read_replica_index,
                     client_az.to_string(),
                 )
@@ -548,20 +1109,75 @@ impl StandaloneClient {
                 last_read_replica_index,
             } => {
                 self.round_robin_read_from_replica_az_awareness_replicas_and_primary(
+                    nodes,
                     last_read_replica_index,
                     client_az.to_string(),
                 )
                 .await
             }
+            ReadFrom::LowestLatency {
+                hysteresis,
+                latency_tracker,
+                last_read_replica_index,
+            } => self.read_from_lowest_latency_replica(
+                nodes,
+                latency_tracker,
+                last_read_replica_index,
+                *hysteresis,
+            ),
+        };
+        if matches!(self.inner.read_from, ReadFrom::Primary) {
+            return node;
         }
+        self.node_with_closed_circuit(nodes, node)
     }
 
+    /// Sends the request to the node, failing fast if the circuit of the node is open.
     async fn send_request(
+        cmd: &redis::Cmd,
+        node: &ReconnectingConnection,
+        circuit_breaker: Option<&Arc<CircuitBreaker>>,
+    ) -> RedisResult<Value> {
+        let permit = circuit_breaker
+            .map(|circuit_breaker| circuit_breaker.try_acquire(&node.node_address()))
+            .transpose()?;
+        let result = Self::send_request_to_node(cmd, node).await;
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        result
+    }
+
+    /// Sends the request to the node. Subscriptions and the commands of transactions are sent on the node's first
+    /// connection, and commands that change the connection state on all of its connections.
+    async fn send_request_to_node(
+        cmd: &redis::Cmd,
+        node: &ReconnectingConnection,
+    ) -> RedisResult<Value> {
+        if is_subscription_command(cmd) || is_transaction_command(cmd) {
+            return Self::send_request_on_connection(cmd, node).await;
+        }
+        if is_connection_state_command(cmd) {
+            return future::try_join_all(
+                node.all_connections()
+                    .map(|connection| Self::send_request_on_connection(cmd, connection)),
+            )
+            .await
+            .map(|mut results| results.swap_remove(0));
+        }
+        Self::send_request_on_connection(cmd, node.select_connection()).await
+    }
+
+    async fn send_request_on_connection(
         cmd: &redis::Cmd,
         reconnecting_connection: &ReconnectingConnection,
     ) -> RedisResult<Value> {
         let mut connection = reconnecting_connection.get_connection().await?;
+        let start = Instant::now();
         let result = connection.send_packed_command(cmd).await;
+        if let Some(request_type) = cmd.request_type() {
+            record_command_latency(&request_type, reconnecting_connection, start.elapsed());
+        }
         match result {
             Err(err) if err.is_unrecoverable_error() => {
                 log_warn("send request", format!("received disconnect error `{err}`"));
@@ -577,11 +1193,11 @@ impl StandaloneClient {
         cmd: &redis::Cmd,
         response_policy: Option<ResponsePolicy>,
     ) -> RedisResult<Value> {
-        let requests = self
-            .inner
-            .nodes
+        let nodes = self.inner.topology.nodes();
+        let requests = nodes
+            .connections
             .iter()
-            .map(|node| Self::send_request(cmd, node));
+            .map(|node| Self::send_request(cmd, node, self.inner.circuit_breaker.as_ref()));
 
         // TODO - once Value::Error will be merged, these will need to be updated to handle this new value.
         match response_policy {
@@ -627,9 +1243,8 @@ impl StandaloneClient {
                 // Await all futures and collect results
                 let results = future::try_join_all(requests).await?;
                 // Create key-value pairs where the key is the node address and the value is the corresponding result
-                let node_result_pairs = self
-                    .inner
-                    .nodes
+                let node_result_pairs = nodes
+                    .connections
                     .iter()
                     .zip(results)
                     .map(|(node, result)| (Value::BulkString(node.node_address().into()), result))
@@ -651,8 +1266,54 @@ impl StandaloneClient {
         cmd: &redis::Cmd,
         readonly: bool,
     ) -> RedisResult<Value> {
-        let reconnecting_connection = self.get_connection(readonly).await;
-        Self::send_request(cmd, reconnecting_connection).await
+        let nodes = self.inner.topology.nodes();
+        let reconnecting_connection = self.get_connection(&nodes, readonly).await;
+        let result = Self::send_request(
+            cmd,
+            reconnecting_connection,
+            self.inner.circuit_breaker.as_ref(),
+        )
+        .await;
+        if self.inner.read_only || !std::ptr::eq(reconnecting_connection, nodes.primary()) {
+            return result;
+        }
+
+        match result {
+            Err(err) if err.kind() == redis::ErrorKind::ReadOnly => {
+                if !self.inner.topology.rediscover_primary(&nodes).await {
+                    return Err(err);
+                }
+                // A command rejected with READONLY wasn't executed, so it's safe to send it to the new primary.
+                let nodes = self.inner.topology.nodes();
+                Self::send_request(cmd, nodes.primary(), self.inner.circuit_breaker.as_ref()).await
+            }
+            Err(err) if err.is_unrecoverable_error() => {
+                self.inner.topology.request_recheck();
+                Err(err)
+            }
+            _ => result,
+        }
+    }
+
+    /// Sends a blocking command over a dedicated connection from `blocking_pool`, instead of the node's shared connection.
+    pub(crate) async fn send_blocking_command(
+        &self,
+        cmd: &redis::Cmd,
+        blocking_pool: &BlockingConnectionPool,
+    ) -> RedisResult<Value> {
+        let readonly = Routable::command(cmd).is_some_and(|cmd_bytes| is_readonly_cmd(&cmd_bytes));
+        if self.inner.read_only && !readonly {
+            return Err(RedisError::from((
+                redis::ErrorKind::ReadOnly,
+                "write commands are not allowed in read-only mode",
+            )));
+        }
+        let nodes = self.inner.topology.nodes();
+        let connection_info = self
+            .get_connection(&nodes, readonly)
+            .await
+            .connection_info();
+        blocking_pool.send_command(&connection_info, cmd).await
     }
 
     pub async fn send_command(&mut self, cmd: &redis::Cmd) -> RedisResult<Value> {
@@ -682,11 +1343,38 @@ impl StandaloneClient {
         offset: usize,
         count: usize,
     ) -> RedisResult<Vec<Value>> {
-        let reconnecting_connection = self.get_primary_connection();
-        let mut connection = reconnecting_connection.get_connection().await?;
-        let result = connection
-            .send_packed_commands(pipeline, offset, count)
-            .await;
+        let nodes = self.inner.topology.nodes();
+        let primary = nodes.primary();
+        let permit = self
+            .inner
+            .circuit_breaker
+            .as_ref()
+            .map(|circuit_breaker| circuit_breaker.try_acquire(&primary.node_address()))
+            .transpose()?;
+        // Transactions run on the first connection, which the keys they depend on are watched on.
+        let reconnecting_connection = if pipeline.is_atomic() {
+            primary
+        } else {
+            primary.select_connection()
+        };
+        let result = match reconnecting_connection.get_connection().await {
+            Ok(mut connection) => {
+                let start = Instant::now();
+                let result = connection
+                    .send_packed_commands(pipeline, offset, count)
+                    .await;
+                record_command_latency(
+                    pipeline.request_type(),
+                    reconnecting_connection,
+                    start.elapsed(),
+                );
+                result
+            }
+            Err(err) => Err(err),
+        };
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
         match result {
             Err(err) if err.is_unrecoverable_error() => {
                 log_warn(
@@ -694,6 +1382,12 @@ impl StandaloneClient {
                     format!("received disconnect error `{err}`"),
                 );
                 reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
+                self.inner.topology.request_recheck();
+                Err(err)
+            }
+            // Pipelines aren't retried, since some of their commands may have been executed.
+            Err(err) if err.kind() == redis::ErrorKind::ReadOnly => {
+                self.inner.topology.request_recheck();
                 Err(err)
             }
             _ => result,
@@ -736,6 +1430,109 @@ impl StandaloneClient {
         });
     }
 
+    // Re-discovers the primary, and the replicas when replica discovery is enabled, when requested by a failed request
+    // and every `interval` when periodic checks are enabled. Holds the client weakly, so it stops once the client is
+    // dropped.
+    fn start_topology_monitor(inner: Weak<DropWrapper>, interval: Option<Duration>) {
+        let Some(topology) = inner.upgrade().map(|inner| inner.topology.clone()) else {
+            return;
+        };
+        let wait_duration = interval.map_or(super::CONNECTION_CHECKS_INTERVAL, |interval| {
+            interval.min(super::CONNECTION_CHECKS_INTERVAL)
+        });
+        task::spawn(async move {
+            let mut last_check = Instant::now();
+            loop {
+                let recheck_requested =
+                    tokio::time::timeout(wait_duration, topology.recheck.notified())
+                        .await
+                        .is_ok();
+                let Some(inner) = inner.upgrade() else {
+                    log_debug(
+                        "StandaloneClient",
+                        "topology monitor stopped after client was dropped",
+                    );
+                    return;
+                };
+
+                let periodic_check_due =
+                    interval.is_some_and(|interval| last_check.elapsed() >= interval);
+                if !recheck_requested && !periodic_check_due {
+                    continue;
+                }
+                last_check = Instant::now();
+                if !inner.read_only {
+                    topology.rediscover_primary(&topology.nodes()).await;
+                }
+                if let Some(discovery) = &inner.replica_discovery {
+                    inner.discover_replicas(discovery).await;
+                }
+            }
+        });
+    }
+
+    // Resolves the hostnames of the nodes every `interval`, and moves the connections whose IP isn't among the resolved
+    // addresses anymore to a new connection. Holds the client weakly, so it stops once the client is dropped.
+    fn start_dns_refresh(
+        inner: Weak<DropWrapper>,
+        interval: Duration,
+        dns_resolver: Option<Arc<dyn DnsResolver>>,
+    ) {
+        task::spawn(async move {
+            let dns_resolver = dns_resolver
+                .as_deref()
+                .unwrap_or(&redis::aio::SystemDnsResolver);
+            loop {
+                tokio::time::sleep(interval).await;
+                let Some(inner) = inner.upgrade() else {
+                    log_debug(
+                        "StandaloneClient",
+                        "DNS refresh stopped after client was dropped",
+                    );
+                    return;
+                };
+                for node in inner.topology.nodes().connections.iter() {
+                    migrate_if_address_changed(node, dns_resolver).await;
+                }
+            }
+        });
+    }
+
+    // Periodically PINGs the node and records the round-trip time for the lowest-latency read strategy.
+    // A node that can't answer is dropped from the tracker, so reads move away from it.
+    fn start_latency_probe(
+        reconnecting_connection: ReconnectingConnection,
+        latency_tracker: Arc<LatencyTracker>,
+    ) {
+        task::spawn(async move {
+            let address = reconnecting_connection.node_address();
+            loop {
+                tokio::time::sleep(LATENCY_PROBE_INTERVAL).await;
+                if reconnecting_connection.is_dropped() {
+                    log_debug(
+                        "StandaloneClient",
+                        "latency probe stopped after connection was dropped",
+                    );
+                    return;
+                }
+
+                let Some(mut connection) = reconnecting_connection.try_get_connection().await
+                else {
+                    latency_tracker.remove(&address);
+                    continue;
+                };
+                let start = Instant::now();
+                match connection.send_packed_command(&redis::cmd("PING")).await {
+                    // The node may have been dropped while the PING was in flight.
+                    Ok(_) if !reconnecting_connection.is_dropped() => {
+                        latency_tracker.record(&address, start.elapsed())
+                    }
+                    _ => latency_tracker.remove(&address),
+                }
+            }
+        });
+    }
+
     // Monitors passive connection status and reconnects if necessary.
     // This function is cheaper alternative to start_heartbeat(),
     // as it avoids sending PING commands to the server, checking only the connection state.
@@ -782,7 +1579,7 @@ impl StandaloneClient {
         &self,
         new_password: Option<String>,
     ) -> RedisResult<Value> {
-        for node in self.inner.nodes.iter() {
+        for node in self.inner.topology.nodes().connections.iter() {
             node.update_connection_password(new_password.clone());
         }
 
@@ -791,7 +1588,7 @@ impl StandaloneClient {
 
     /// Update the database id used to establish connection with the servers.
     pub async fn update_connection_database(&self, database_id: i64) -> RedisResult<Value> {
-        for node in self.inner.nodes.iter() {
+        for node in self.inner.topology.nodes().connections.iter() {
             node.update_connection_database(database_id);
         }
 
@@ -803,7 +1600,7 @@ impl StandaloneClient {
         &self,
         new_client_name: Option<String>,
     ) -> RedisResult<Value> {
-        for node in self.inner.nodes.iter() {
+        for node in self.inner.topology.nodes().connections.iter() {
             node.update_connection_client_name(new_client_name.clone());
         }
 
@@ -823,7 +1620,7 @@ impl StandaloneClient {
         &self,
         new_username: Option<String>,
     ) -> RedisResult<Value> {
-        for node in self.inner.nodes.iter() {
+        for node in self.inner.topology.nodes().connections.iter() {
             node.update_connection_username(new_username.clone());
         }
 
@@ -843,7 +1640,7 @@ impl StandaloneClient {
         &self,
         new_protocol: redis::ProtocolVersion,
     ) -> RedisResult<Value> {
-        for node in self.inner.nodes.iter() {
+        for node in self.inner.topology.nodes().connections.iter() {
             node.update_connection_protocol(new_protocol);
         }
 
@@ -853,39 +1650,71 @@ impl StandaloneClient {
     /// Retrieve the username used to authenticate with the server.
     pub fn get_username(&self) -> Option<String> {
         // All nodes in the client should have the same username configured, thus any connection would work here.
-        self.get_primary_connection().get_username()
+        self.inner.topology.nodes().primary().get_username()
     }
 }
 
-#[allow(clippy::too_many_arguments)]
 async fn get_connection_and_replication_info(
     address: &NodeAddress,
-    retry_strategy: &RetryStrategy,
+    settings: &NodeConnectionSettings,
     connection_info: &redis::RedisConnectionInfo,
-    tls_mode: TlsMode,
-    push_sender: &Option<mpsc::UnboundedSender<PushInfo>>,
-    discover_az: bool,
-    connection_timeout: Duration,
-    tls_params: Option<redis::TlsConnParams>,
-    tcp_nodelay: bool,
-    pubsub_synchronizer: &Option<Arc<dyn crate::pubsub::PubSubSynchronizer>>,
     skip_replication_check: bool,
-    iam_token_handle: Option<super::IAMTokenHandle>,
 ) -> Result<(ReconnectingConnection, Option<Value>), (ReconnectingConnection, RedisError)> {
-    let reconnecting_connection = ReconnectingConnection::new(
+    let first_connection = ReconnectingConnection::new(
         address,
-        *retry_strategy,
+        settings.unix_socket_path.as_deref(),
+        settings.retry_strategy,
         connection_info.clone(),
-        tls_mode,
-        push_sender.clone(),
-        discover_az,
-        connection_timeout,
-        tls_params,
-        tcp_nodelay,
-        pubsub_synchronizer.clone(),
-        iam_token_handle,
-    )
-    .await?;
+        settings.tls_mode,
+        settings.push_sender.clone(),
+        settings.discover_az,
+        settings.connection_timeout,
+        settings.tls_params.clone(),
+        settings.tcp_nodelay,
+        settings.pubsub_synchronizer.clone(),
+        settings.password_provider.clone(),
+        settings.dns_resolver.clone(),
+    );
+    // Subscriptions are only sent on the first connection, so the others don't report them to the synchronizer.
+    let additional_connections = future::join_all((1..settings.connections_per_node).map(|_| {
+        ReconnectingConnection::new(
+            address,
+            settings.unix_socket_path.as_deref(),
+            settings.retry_strategy,
+            connection_info.clone(),
+            settings.tls_mode,
+            settings.push_sender.clone(),
+            settings.discover_az,
+            settings.connection_timeout,
+            settings.tls_params.clone(),
+            settings.tcp_nodelay,
+            None,
+            settings.password_provider.clone(),
+            settings.dns_resolver.clone(),
+        )
+    }));
+    let (first_connection, additional_connections) =
+        future::join(first_connection, additional_connections).await;
+    // A connection that failed to connect keeps reconnecting in the background, so it's kept either way.
+    let additional_connections = additional_connections
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|(connection, _)| connection))
+        .collect();
+    let reconnecting_connection = match first_connection {
+        Ok(connection) => connection.with_additional_connections(
+            additional_connections,
+            settings.connection_selection_strategy,
+        ),
+        Err((connection, err)) => {
+            return Err((
+                connection.with_additional_connections(
+                    additional_connections,
+                    settings.connection_selection_strategy,
+                ),
+                err,
+            ));
+        }
+    };
 
     let mut multiplexed_connection = match reconnecting_connection.get_connection().await {
         Ok(multiplexed_connection) => multiplexed_connection,
@@ -909,6 +1738,127 @@ async fn get_connection_and_replication_info(
     }
 }
 
+/// Resolves the hostname of the node, and moves each of its connections whose IP isn't among the resolved addresses
+/// anymore to a new connection. Connections to an IP address or a Unix socket are left as they are.
+/// Returns the `INFO REPLICATION` output of the node. Waits for the node to reconnect, when discovery was requested
+/// after it disconnected.
+async fn replication_info(node: &ReconnectingConnection, timeout: Duration) -> Option<String> {
+    let Ok(Ok(mut connection)) = tokio::time::timeout(timeout, node.get_connection()).await else {
+        return None;
+    };
+    match connection
+        .send_packed_command(redis::cmd("INFO").arg("REPLICATION"))
+        .await
+        .and_then(redis::from_owned_redis_value::<String>)
+    {
+        Ok(replication_info) => Some(replication_info),
+        Err(err) => {
+            log_warn(
+                "replica discovery",
+                format!(
+                    "Failed to get the replicas of {}: {err}",
+                    node.node_address()
+                ),
+            );
+            None
+        }
+    }
+}
+
+/// Returns the `host:port` address of the node, and the `ip:port` addresses its hostname resolves to.
+async fn resolved_node_addresses(
+    node: &ReconnectingConnection,
+    dns_resolver: &dyn DnsResolver,
+) -> Vec<String> {
+    let mut addresses = vec![node.node_address()];
+    let (redis::ConnectionAddr::Tcp(host, port) | redis::ConnectionAddr::TcpTls { host, port, .. }) =
+        node.connection_info().addr
+    else {
+        return addresses;
+    };
+    if host.parse::<IpAddr>().is_ok() {
+        return addresses;
+    }
+    match dns_resolver.resolve(&host, port).await {
+        Ok(socket_addrs) => addresses.extend(
+            socket_addrs
+                .iter()
+                .map(|socket_addr| format!("{}:{}", socket_addr.ip(), socket_addr.port())),
+        ),
+        Err(err) => log_debug(
+            "replica discovery",
+            format!("Failed to resolve {host}:{port}: {err}"),
+        ),
+    }
+    addresses
+}
+
+async fn migrate_if_address_changed(node: &ReconnectingConnection, dns_resolver: &dyn DnsResolver) {
+    let (redis::ConnectionAddr::Tcp(host, port) | redis::ConnectionAddr::TcpTls { host, port, .. }) =
+        node.connection_info().addr
+    else {
+        return;
+    };
+    if host.parse::<IpAddr>().is_ok() {
+        return;
+    }
+    let resolved_ips: HashSet<IpAddr> = match dns_resolver.resolve(&host, port).await {
+        Ok(socket_addrs) if !socket_addrs.is_empty() => socket_addrs
+            .iter()
+            .map(|socket_addr| socket_addr.ip())
+            .collect(),
+        // The connections are kept until the hostname resolves again.
+        Ok(_) => return,
+        Err(err) => {
+            log_warn(
+                "DNS refresh",
+                format!("Failed to resolve {host}:{port}, keeping the current connections: {err}"),
+            );
+            return;
+        }
+    };
+    for connection in node.all_connections() {
+        // A connection that's reconnecting resolves the hostname by itself.
+        let Some(connected_ip) = connection.connected_ip() else {
+            continue;
+        };
+        if resolved_ips.contains(&connected_ip) {
+            continue;
+        }
+        log_info(
+            "DNS refresh",
+            format!(
+                "{host}:{port} moved from {connected_ip} to {resolved_ips:?}, moving the connection"
+            ),
+        );
+        if let Err(err) = connection.migrate().await {
+            log_warn(
+                "DNS refresh",
+                format!(
+                    "Failed to connect to the new address of {host}:{port}, retrying on the next refresh: {err}"
+                ),
+            );
+        }
+    }
+}
+
+async fn discover_nodes_through_sentinel(
+    sentinel_config: &SentinelConfig,
+    tls_mode: TlsMode,
+    tls_params: Option<redis::TlsConnParams>,
+) -> RedisResult<(Vec<NodeAddress>, Sentinel)> {
+    let mut sentinel = sentinel::build_sentinel(sentinel_config, tls_mode, tls_params)?;
+    let addresses = sentinel::discover_nodes(&mut sentinel, &sentinel_config.master_name).await?;
+    log_debug(
+        "client creation",
+        format!(
+            "Sentinel reported nodes {addresses:?} for `{}`",
+            sentinel_config.master_name
+        ),
+    );
+    Ok((addresses, sentinel))
+}
+
 fn get_read_from(read_from: Option<super::ReadFrom>) -> ReadFrom {
     match read_from {
         Some(super::ReadFrom::Primary) => ReadFrom::Primary,
@@ -928,6 +1878,56 @@ fn get_read_from(read_from: Option<super::ReadFrom>) -> ReadFrom {
                 last_read_replica_index: Default::default(),
             }
         }
+        Some(super::ReadFrom::LowestLatency(hysteresis)) => ReadFrom::LowestLatency {
+            hysteresis,
+            latency_tracker: Default::default(),
+            last_read_replica_index: Default::default(),
+        },
         None => ReadFrom::Primary,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_replica_addresses() {
+        let replication_info = "# Replication\r\nrole:master\r\nconnected_slaves:3\r\n\
+            slave0:ip=10.0.0.2,port=6379,state=online,offset=1234,lag=0\r\n\
+            slave1:ip=10.0.0.3,port=6380,state=wait_bgsave,offset=0,lag=0\r\n\
+            slave2:ip=::1,port=6381,state=online,offset=1234,lag=1\r\n\
+            slave_read_repl_offset:1234\r\nmaster_repl_offset:1234\r\n";
+        assert_eq!(
+            parse_replica_addresses(replication_info),
+            vec![
+                NodeAddress {
+                    host: "10.0.0.2".to_string(),
+                    port: 6379,
+                },
+                NodeAddress {
+                    host: "::1".to_string(),
+                    port: 6381,
+                },
+            ]
+        );
+        assert!(parse_replica_addresses("role:slave\r\nmaster_host:10.0.0.1\r\n").is_empty());
+    }
+
+    #[test]
+    fn test_is_primary_role() {
+        let primary = Value::Array(vec![
+            Value::BulkString(b"master".to_vec()),
+            Value::Int(0),
+            Value::Array(vec![]),
+        ]);
+        assert!(is_primary_role(&primary));
+        let replica = Value::Array(vec![
+            Value::BulkString(b"slave".to_vec()),
+            Value::BulkString(b"127.0.0.1".to_vec()),
+            Value::Int(6379),
+        ]);
+        assert!(!is_primary_role(&replica));
+        assert!(!is_primary_role(&Value::Nil));
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Loads the TLS certificates from files and reloads them when the files change, so that rotated certificates are
+//! used by new connections and reconnections without recreating the client.
+
+use super::types::TlsCertificateFiles;
+use logger_core::{log_error, log_info};
+use redis::{
+    ClientTlsConfig, ErrorKind, RedisError, RedisResult, ReloadableTlsConnParams, TlsCertificates,
+    TlsConnParams,
+};
+use std::path::Path;
+use std::time::SystemTime;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+const PEM_CERTIFICATE_HEADER: &[u8] = b"-----BEGIN CERTIFICATE-----";
+
+/// Modification times of the root certificates, client certificate and client key files.
+type ModificationTimes = [Option<SystemTime>; 3];
+
+/// Watches the TLS certificate files for as long as it's alive.
+pub(crate) struct TlsCertificateWatcher {
+    params: ReloadableTlsConnParams,
+    task: JoinHandle<()>,
+}
+
+impl TlsCertificateWatcher {
+    /// Loads the certificates and starts checking the files for changes.
+    pub(crate) fn start(files: TlsCertificateFiles) -> RedisResult<Self> {
+        let modification_times = modification_times(&files);
+        let params = ReloadableTlsConnParams::new(load_certificates(&files)?);
+        let task = tokio::spawn(watch_files(files, params.clone(), modification_times));
+        Ok(Self { params, task })
+    }
+
+    /// Returns TLS parameters that follow the reloaded certificates.
+    pub(crate) fn conn_params(&self) -> TlsConnParams {
+        self.params.conn_params()
+    }
+}
+
+impl Drop for TlsCertificateWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn watch_files(
+    files: TlsCertificateFiles,
+    params: ReloadableTlsConnParams,
+    mut last_modification_times: ModificationTimes,
+) {
+    let mut interval = tokio::time::interval(files.reload_interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // The first tick completes immediately.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let current_modification_times = modification_times(&files);
+        if current_modification_times == last_modification_times {
+            continue;
+        }
+        // A rejected version isn't loaded again, the next change of the files is.
+        last_modification_times = current_modification_times;
+
+        let files = files.clone();
+        let result = tokio::task::spawn_blocking(move || load_certificates(&files))
+            .await
+            .map_err(|err| {
+                RedisError::from((
+                    ErrorKind::ClientError,
+                    "Failed to load the TLS certificates",
+                    err.to_string(),
+                ))
+            })
+            .and_then(|loaded| loaded)
+            .and_then(|new_params| params.update(new_params));
+        match result {
+            Ok(()) => log_info("TLS certificates", "Reloaded the TLS certificate files"),
+            Err(err) => log_error(
+                "TLS certificates",
+                format!(
+                    "Rejected the replaced TLS certificate files, keeping the current certificates: {err}"
+                ),
+            ),
+        }
+    }
+}
+
+fn modification_times(files: &TlsCertificateFiles) -> ModificationTimes {
+    [&files.root_certs, &files.client_cert, &files.client_key].map(|path| {
+        path.as_ref().and_then(|path| {
+            std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+    })
+}
+
+fn read_file(path: &Path) -> RedisResult<Vec<u8>> {
+    std::fs::read(path).map_err(|err| {
+        RedisError::from((
+            ErrorKind::InvalidClientConfig,
+            "Failed to read TLS certificate file",
+            format!("{}: {err}", path.display()),
+        ))
+    })
+}
+
+fn read_certificate_file(path: &Path) -> RedisResult<Vec<u8>> {
+    let contents = read_file(path)?;
+    if !contents
+        .windows(PEM_CERTIFICATE_HEADER.len())
+        .any(|window| window == PEM_CERTIFICATE_HEADER)
+    {
+        return Err(RedisError::from((
+            ErrorKind::InvalidClientConfig,
+            "TLS certificate file doesn't contain a PEM certificate",
+            path.display().to_string(),
+        )));
+    }
+    Ok(contents)
+}
+
+fn load_certificates(files: &TlsCertificateFiles) -> RedisResult<TlsConnParams> {
+    let root_cert = files
+        .root_certs
+        .as_deref()
+        .map(read_certificate_file)
+        .transpose()?;
+    let client_tls = match (&files.client_cert, &files.client_key) {
+        (Some(client_cert), Some(client_key)) => Some(ClientTlsConfig {
+            client_cert: read_certificate_file(client_cert)?,
+            client_key: read_file(client_key)?,
+        }),
+        (None, None) => None,
+        _ => {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "client_cert and client_key must both be provided or both be empty",
+            )));
+        }
+    };
+    redis::retrieve_tls_certificates(TlsCertificates {
+        client_tls,
+        root_cert,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn files(
+        root_certs: Option<PathBuf>,
+        client_cert: Option<PathBuf>,
+        client_key: Option<PathBuf>,
+    ) -> TlsCertificateFiles {
+        TlsCertificateFiles {
+            root_certs,
+            client_cert,
+            client_key,
+            reload_interval: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn test_load_certificates_rejects_invalid_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let not_pem = dir.path().join("not_pem.crt");
+        std::fs::write(&not_pem, b"not a certificate").unwrap();
+
+        let err = load_certificates(&files(Some(dir.path().join("missing.crt")), None, None))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+
+        let err = load_certificates(&files(Some(not_pem.clone()), None, None)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+
+        let err = load_certificates(&files(None, Some(not_pem), None)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+    }
+
+    #[test]
+    fn test_modification_times_of_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root_certs = dir.path().join("ca.crt");
+        let files = files(Some(root_certs.clone()), None, None);
+        assert_eq!(modification_times(&files), [None, None, None]);
+
+        std::fs::write(&root_certs, b"").unwrap();
+        assert!(modification_times(&files)[0].is_some());
+    }
+}
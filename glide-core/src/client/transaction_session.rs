@@ -0,0 +1,202 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use super::blocking_pool::open_dedicated_connection;
+use logger_core::log_debug;
+use nanoid::nanoid;
+use redis::aio::MultiplexedConnection;
+use redis::{ConnectionInfo, ErrorKind, GlideConnectionOptions, RedisError, RedisResult};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long a session may stay unused before it is ended and its connection is closed.
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Maximum number of sessions a client keeps open at once.
+const MAX_SESSIONS: usize = 1000;
+
+struct Session<C> {
+    connection: C,
+    last_used: Instant,
+}
+
+/// Connections leased for `WATCH` ... `EXEC` sequences, keyed by session id.
+///
+/// `WATCH` only applies to the connection it was sent on, so the commands of a session can't be multiplexed over the
+/// shared connections. Each session owns a dedicated connection until it is ended, until the connection fails, or
+/// until it stays unused for longer than the idle timeout, so that an abandoned session doesn't pin its connection
+/// forever.
+pub(crate) struct TransactionSessions<C = MultiplexedConnection> {
+    connection_options: GlideConnectionOptions,
+    sessions: Mutex<HashMap<String, Session<C>>>,
+    idle_timeout: Duration,
+    max_sessions: usize,
+}
+
+impl TransactionSessions {
+    pub(crate) fn new(connection_timeout: Duration, tcp_nodelay: bool) -> Self {
+        Self::with_limits(
+            GlideConnectionOptions {
+                connection_timeout: Some(connection_timeout),
+                tcp_nodelay,
+                ..Default::default()
+            },
+            SESSION_IDLE_TIMEOUT,
+            MAX_SESSIONS,
+        )
+    }
+
+    /// Opens a dedicated connection to the node described by `connection_info`, and returns the id of the new session.
+    pub(crate) async fn start(&self, connection_info: &ConnectionInfo) -> RedisResult<String> {
+        self.check_capacity(&mut self.sessions.lock().unwrap())?;
+        let connection =
+            open_dedicated_connection(connection_info, self.connection_options.clone()).await?;
+        let id = self.insert(connection)?;
+        log_debug(
+            "transaction session",
+            format!("Started session `{id}` on {}", connection_info.addr),
+        );
+        Ok(id)
+    }
+}
+
+impl<C: Clone> TransactionSessions<C> {
+    fn with_limits(
+        connection_options: GlideConnectionOptions,
+        idle_timeout: Duration,
+        max_sessions: usize,
+    ) -> Self {
+        Self {
+            connection_options,
+            sessions: Mutex::new(HashMap::new()),
+            idle_timeout,
+            max_sessions,
+        }
+    }
+
+    /// Ends the sessions that stayed unused for longer than the idle timeout.
+    fn end_idle_sessions(&self, sessions: &mut HashMap<String, Session<C>>) {
+        sessions.retain(|id, session| {
+            let active = session.last_used.elapsed() < self.idle_timeout;
+            if !active {
+                log_debug(
+                    "transaction session",
+                    format!("Ending session `{id}` after it stayed unused"),
+                );
+            }
+            active
+        });
+    }
+
+    fn check_capacity(&self, sessions: &mut HashMap<String, Session<C>>) -> RedisResult<()> {
+        self.end_idle_sessions(sessions);
+        if sessions.len() >= self.max_sessions {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Too many transaction sessions",
+                format!(
+                    "The client already has {} open transaction sessions, end some before starting new ones",
+                    sessions.len()
+                ),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Adds a session for `connection`, unless the maximum number of sessions is open, and returns its id.
+    fn insert(&self, connection: C) -> RedisResult<String> {
+        let mut sessions = self.sessions.lock().unwrap();
+        // Checked again, since other sessions may have started while the connection was opened.
+        self.check_capacity(&mut sessions)?;
+        let id = nanoid!();
+        sessions.insert(
+            id.clone(),
+            Session {
+                connection,
+                last_used: Instant::now(),
+            },
+        );
+        Ok(id)
+    }
+
+    pub(crate) fn get(&self, id: &str) -> RedisResult<C> {
+        let mut sessions = self.sessions.lock().unwrap();
+        self.end_idle_sessions(&mut sessions);
+        sessions
+            .get_mut(id)
+            .map(|session| {
+                session.last_used = Instant::now();
+                session.connection.clone()
+            })
+            .ok_or_else(|| {
+                RedisError::from((
+                    ErrorKind::ClientError,
+                    "Invalid transaction session id",
+                    format!("The transaction session with id `{id}` does not exist or has ended"),
+                ))
+            })
+    }
+
+    /// Ends the session after its connection failed, since the server dropped its watched keys along with it.
+    pub(crate) fn end_on_connection_error<T>(&self, id: &str, result: &RedisResult<T>) {
+        if let Err(err) = result
+            && (err.is_unrecoverable_error() || err.is_io_error() || err.is_timeout())
+        {
+            log_debug(
+                "transaction session",
+                format!("Ending session `{id}` after error: {err}"),
+            );
+            self.end(id);
+        }
+    }
+
+    /// Ends the session and closes its connection. Returns false if the session didn't exist.
+    pub(crate) fn end(&self, id: &str) -> bool {
+        self.sessions.lock().unwrap().remove(id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sessions(idle_timeout: Duration, max_sessions: usize) -> TransactionSessions<u32> {
+        TransactionSessions::with_limits(Default::default(), idle_timeout, max_sessions)
+    }
+
+    #[test]
+    fn test_idle_sessions_are_ended() {
+        let sessions = sessions(Duration::ZERO, MAX_SESSIONS);
+        let id = sessions.insert(1).unwrap();
+        assert!(sessions.get(&id).is_err());
+        assert!(!sessions.end(&id));
+    }
+
+    #[test]
+    fn test_used_sessions_are_kept() {
+        let sessions = sessions(SESSION_IDLE_TIMEOUT, MAX_SESSIONS);
+        let id = sessions.insert(1).unwrap();
+        assert_eq!(sessions.get(&id).unwrap(), 1);
+        assert!(sessions.end(&id));
+        assert!(sessions.get(&id).is_err());
+    }
+
+    #[test]
+    fn test_number_of_sessions_is_limited() {
+        let sessions = sessions(SESSION_IDLE_TIMEOUT, 2);
+        let first = sessions.insert(1).unwrap();
+        sessions.insert(2).unwrap();
+        let err = sessions.insert(3).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ClientError);
+
+        sessions.end(&first);
+        assert!(sessions.insert(3).is_ok());
+    }
+
+    #[test]
+    fn test_idle_sessions_free_their_slots() {
+        let sessions = sessions(Duration::ZERO, 1);
+        sessions.insert(1).unwrap();
+        assert!(sessions.insert(2).is_ok());
+    }
+}
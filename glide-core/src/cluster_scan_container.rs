@@ -27,7 +27,12 @@ pub fn insert_cluster_scan_cursor(scan_state: ScanStateRC) -> String {
     id
 }
 
+// A cursor serialized with `serialize_cluster_scan_cursor` can be passed in place of an id, which restores the scan
+// state it was created from. This lets a scan be resumed after a restart, or by another process.
 pub fn get_cluster_scan_cursor(id: String) -> RedisResult<ScanStateRC> {
+    if ScanStateRC::is_serialized_cursor(&id) {
+        return ScanStateRC::from_serialized_cursor(&id);
+    }
     let scan_state_rc = CONTAINER.lock().unwrap().get(&id).cloned();
     log_debug(
         "scan_state_cursor get",
@@ -50,3 +55,8 @@ pub fn remove_scan_state_cursor(id: String) {
     );
     CONTAINER.lock().unwrap().remove(&id);
 }
+
+pub fn serialize_cluster_scan_cursor(id: String) -> RedisResult<String> {
+    let scan_state_rc = get_cluster_scan_cursor(id)?;
+    Ok(scan_state_rc.to_serialized_cursor())
+}
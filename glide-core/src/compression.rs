@@ -213,12 +213,29 @@ impl std::str::FromStr for CompressionBackendType {
     }
 }
 
+/// A pre-trained zstd dictionary, and the id written in the header of the values compressed with it.
+#[derive(Clone, PartialEq)]
+pub struct CompressionDictionary {
+    pub id: u32,
+    pub data: Vec<u8>,
+}
+
+impl fmt::Debug for CompressionDictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressionDictionary")
+            .field("id", &self.id)
+            .field("size", &self.data.len())
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CompressionConfig {
     pub enabled: bool,
     pub backend: CompressionBackendType,
     pub compression_level: Option<i32>,
     pub min_compression_size: usize,
+    pub dictionary: Option<CompressionDictionary>,
 }
 
 impl CompressionConfig {
@@ -228,6 +245,7 @@ impl CompressionConfig {
             backend,
             compression_level: backend.default_level(),
             min_compression_size: 64,
+            dictionary: None,
         }
     }
 
@@ -237,6 +255,7 @@ impl CompressionConfig {
             backend: CompressionBackendType::Zstd,
             compression_level: None,
             min_compression_size: 64,
+            dictionary: None,
         }
     }
 
@@ -250,6 +269,12 @@ impl CompressionConfig {
         self
     }
 
+    /// Compresses values with a pre-trained dictionary. Only supported by the zstd backend.
+    pub fn with_dictionary(mut self, id: u32, data: Vec<u8>) -> Self {
+        self.dictionary = Some(CompressionDictionary { id, data });
+        self
+    }
+
     pub fn validate(&self) -> CompressionResult<()> {
         if self.min_compression_size < MIN_COMPRESSED_SIZE {
             return Err(CompressionError::invalid_configuration(
@@ -261,6 +286,21 @@ impl CompressionConfig {
             ));
         }
 
+        if let Some(dictionary) = &self.dictionary {
+            if self.backend != CompressionBackendType::Zstd {
+                return Err(CompressionError::invalid_configuration(
+                    self.backend.backend_name(),
+                    "dictionaries are only supported by the zstd backend",
+                ));
+            }
+            if dictionary.data.is_empty() {
+                return Err(CompressionError::invalid_configuration(
+                    self.backend.backend_name(),
+                    format!("dictionary {} is empty", dictionary.id),
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -386,8 +426,8 @@ impl CompressionManager {
         // Extract backend ID from header and route to appropriate backend
         if let Some(backend_id) = extract_backend_id(value) {
             // If the data was compressed with our configured backend, use it
-            // This respects the client's compression configuration
-            let result = if backend_id == self.backend.backend_id() {
+            // This respects the client's compression configuration, e.g. its dictionary
+            let result = if self.backend.is_compressed(value) {
                 self.backend.decompress(value)
             } else {
                 // Otherwise, use a static backend for decompression
@@ -430,18 +470,150 @@ impl CompressionManager {
 
 pub mod zstd_backend {
     use super::*;
+    use std::io::Read;
+    use zstd::dict::{DecoderDictionary, EncoderDictionary};
 
     #[derive(Debug)]
     pub struct ZstdBackend {
         default_level: i32,
+        dictionary: Option<PreparedDictionary>,
+    }
+
+    /// A dictionary digested once for the configured level, instead of on every call.
+    struct PreparedDictionary {
+        id: u32,
+        level: i32,
+        data: Vec<u8>,
+        encoder: EncoderDictionary<'static>,
+        decoder: DecoderDictionary<'static>,
+    }
+
+    impl fmt::Debug for PreparedDictionary {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PreparedDictionary")
+                .field("id", &self.id)
+                .field("level", &self.level)
+                .field("size", &self.data.len())
+                .finish()
+        }
     }
 
     impl ZstdBackend {
         pub fn new() -> Self {
-            Self { default_level: 3 }
+            Self {
+                default_level: 3,
+                dictionary: None,
+            }
+        }
+
+        /// Creates a backend that compresses with `dictionary`, and that can decompress values compressed with it.
+        /// The dictionary is prepared for `level`, which should be the level the values are compressed at.
+        pub fn with_dictionary(dictionary: &CompressionDictionary, level: Option<i32>) -> Self {
+            let default_level = 3;
+            let level = level.unwrap_or(default_level);
+            Self {
+                default_level,
+                dictionary: Some(PreparedDictionary {
+                    id: dictionary.id,
+                    level,
+                    data: dictionary.data.clone(),
+                    encoder: EncoderDictionary::copy(&dictionary.data, level),
+                    decoder: DecoderDictionary::copy(&dictionary.data),
+                }),
+            }
+        }
+
+        pub fn dictionary_id(&self) -> Option<u32> {
+            self.dictionary.as_ref().map(|dictionary| dictionary.id)
+        }
+
+        fn compress_with_dictionary(
+            &self,
+            dictionary: &PreparedDictionary,
+            data: &[u8],
+            level: i32,
+        ) -> CompressionResult<Vec<u8>> {
+            let compressor = if level == dictionary.level {
+                zstd::bulk::Compressor::with_prepared_dictionary(&dictionary.encoder)
+            } else {
+                zstd::bulk::Compressor::with_dictionary(level, &dictionary.data)
+            };
+            let compressed_data = compressor
+                .and_then(|mut compressor| compressor.compress(data))
+                .map_err(|e| {
+                    CompressionError::compression_failed(
+                        self.backend_name(),
+                        Some(level),
+                        data.len(),
+                        e.to_string(),
+                    )
+                })?;
+
+            let header = create_header(ZSTD_DICTIONARY_BACKEND_ID);
+
+            let mut result = Vec::with_capacity(DICTIONARY_HEADER_SIZE + compressed_data.len());
+            result.extend_from_slice(&header);
+            result.extend_from_slice(&dictionary.id.to_be_bytes());
+            result.extend_from_slice(&compressed_data);
+
+            Ok(result)
+        }
+
+        fn decompress_with_dictionary(&self, data: &[u8]) -> CompressionResult<Vec<u8>> {
+            let Some(dictionary_id) = extract_dictionary_id(data) else {
+                return Err(CompressionError::decompression_failed(
+                    self.backend_name(),
+                    data.len(),
+                    "dictionary header is truncated",
+                ));
+            };
+            let dictionary = self
+                .dictionary
+                .as_ref()
+                .filter(|dictionary| dictionary.id == dictionary_id)
+                .ok_or_else(|| {
+                    CompressionError::decompression_failed(
+                        self.backend_name(),
+                        data.len(),
+                        format!("unknown dictionary id {}", dictionary_id),
+                    )
+                })?;
+
+            let mut decompressed_data = Vec::new();
+            zstd::stream::read::Decoder::with_prepared_dictionary(
+                &data[DICTIONARY_HEADER_SIZE..],
+                &dictionary.decoder,
+            )
+            .and_then(|mut decoder| decoder.read_to_end(&mut decompressed_data))
+            .map_err(|e| {
+                CompressionError::decompression_failed(
+                    self.backend_name(),
+                    data.len(),
+                    e.to_string(),
+                )
+            })?;
+
+            Ok(decompressed_data)
         }
     }
 
+    /// Trains a dictionary of at most `max_size` bytes from sample values, to be used with
+    /// [`CompressionConfig::with_dictionary`]. The samples should be representative of the values
+    /// the client will store; zstd needs at least a few dozen of them.
+    pub fn train_dictionary<S: AsRef<[u8]>>(
+        samples: &[S],
+        max_size: usize,
+    ) -> CompressionResult<Vec<u8>> {
+        zstd::dict::from_samples(samples, max_size).map_err(|e| {
+            CompressionError::compression_failed(
+                "zstd",
+                None,
+                samples.iter().map(|sample| sample.as_ref().len()).sum(),
+                format!("dictionary training failed: {}", e),
+            )
+        })
+    }
+
     impl Default for ZstdBackend {
         fn default() -> Self {
             Self::new()
@@ -454,6 +626,10 @@ pub mod zstd_backend {
 
             self.validate_compression_level(Some(compression_level))?;
 
+            if let Some(dictionary) = &self.dictionary {
+                return self.compress_with_dictionary(dictionary, data, compression_level);
+            }
+
             let compressed_data = zstd::encode_all(data, compression_level).map_err(|e| {
                 CompressionError::compression_failed(
                     self.backend_name(),
@@ -481,6 +657,10 @@ pub mod zstd_backend {
                 ));
             }
 
+            if extract_backend_id(data) == Some(ZSTD_DICTIONARY_BACKEND_ID) {
+                return self.decompress_with_dictionary(data);
+            }
+
             let compressed_data = &data[HEADER_SIZE..];
 
             let decompressed_data = zstd::decode_all(compressed_data).map_err(|e| {
@@ -495,7 +675,11 @@ pub mod zstd_backend {
         }
 
         fn is_compressed(&self, data: &[u8]) -> bool {
-            has_magic_header(data) && extract_backend_id(data) == Some(self.backend_id())
+            has_magic_header(data)
+                && matches!(
+                    extract_backend_id(data),
+                    Some(id) if id == self.backend_id() || id == ZSTD_DICTIONARY_BACKEND_ID
+                )
         }
 
         fn backend_name(&self) -> &'static str {
@@ -721,10 +905,57 @@ pub fn process_command_args_for_compression(
         RequestType::SetEx => compress_single_value_command(args, manager, 2),
         RequestType::PSetEx => compress_single_value_command(args, manager, 2),
         RequestType::SetNX => compress_single_value_command(args, manager, 1),
+        RequestType::HSet | RequestType::HMSet => compress_field_value_pairs(args, manager, 1),
+        RequestType::HSetNX => compress_single_value_command(args, manager, 2),
+        RequestType::HSetEx => compress_hsetex_command(args, manager),
+        RequestType::LPush | RequestType::RPush => compress_values_from(args, manager, 1),
+        RequestType::LSet => compress_single_value_command(args, manager, 2),
+        RequestType::XAdd => compress_xadd_command(args, manager),
         _ => Ok(()),
     }
 }
 
+/// Returns the request type of a command whose values are compressed or decompressed, based on the command name.
+pub fn compression_request_type(cmd: &redis::Cmd) -> Option<RequestType> {
+    use redis::cluster_routing::Routable;
+
+    let request_type = match cmd.command()?.as_slice() {
+        b"SET" => RequestType::Set,
+        b"MSET" => RequestType::MSet,
+        b"MSETNX" => RequestType::MSetNX,
+        b"SETEX" => RequestType::SetEx,
+        b"PSETEX" => RequestType::PSetEx,
+        b"SETNX" => RequestType::SetNX,
+        b"GET" => RequestType::Get,
+        b"MGET" => RequestType::MGet,
+        b"GETEX" => RequestType::GetEx,
+        b"GETDEL" => RequestType::GetDel,
+        b"GETSET" => RequestType::GetSet,
+        b"HSET" => RequestType::HSet,
+        b"HMSET" => RequestType::HMSet,
+        b"HSETNX" => RequestType::HSetNX,
+        b"HSETEX" => RequestType::HSetEx,
+        b"HGET" => RequestType::HGet,
+        b"HMGET" => RequestType::HMGet,
+        b"HGETALL" => RequestType::HGetAll,
+        b"HVALS" => RequestType::HVals,
+        b"HGETEX" => RequestType::HGetEx,
+        b"LPUSH" => RequestType::LPush,
+        b"RPUSH" => RequestType::RPush,
+        b"LSET" => RequestType::LSet,
+        b"LRANGE" => RequestType::LRange,
+        b"LPOP" => RequestType::LPop,
+        b"RPOP" => RequestType::RPop,
+        b"LINDEX" => RequestType::LIndex,
+        b"XADD" => RequestType::XAdd,
+        b"XRANGE" => RequestType::XRange,
+        b"XREVRANGE" => RequestType::XRevRange,
+        b"XREAD" => RequestType::XRead,
+        _ => return None,
+    };
+    Some(request_type)
+}
+
 fn compress_single_value_command(
     args: &mut [Vec<u8>],
     manager: &CompressionManager,
@@ -744,16 +975,82 @@ fn compress_mset_command(
     manager: &CompressionManager,
 ) -> CompressionResult<()> {
     // MSET format: key1 value1 key2 value2 ...
-    // Values are at indices 1, 3, 5, etc. (odd indices starting from 1)
-    let mut i = 1;
+    compress_field_value_pairs(args, manager, 0)
+}
+
+/// Compresses the values of the `name value` pairs starting at `first_pair_index`.
+fn compress_field_value_pairs(
+    args: &mut [Vec<u8>],
+    manager: &CompressionManager,
+    first_pair_index: usize,
+) -> CompressionResult<()> {
+    let mut i = first_pair_index + 1;
     while i < args.len() {
         let compressed_value = manager.compress_value(&args[i]);
         args[i] = compressed_value.into_owned();
-        i += 2; // Skip to next value (skip the key)
+        i += 2; // Skip to next value (skip the name)
     }
     Ok(())
 }
 
+/// Compresses every argument starting at `first_value_index`.
+fn compress_values_from(
+    args: &mut [Vec<u8>],
+    manager: &CompressionManager,
+    first_value_index: usize,
+) -> CompressionResult<()> {
+    for arg in args.iter_mut().skip(first_value_index) {
+        *arg = manager.compress_value(arg).into_owned();
+    }
+    Ok(())
+}
+
+fn compress_hsetex_command(
+    args: &mut [Vec<u8>],
+    manager: &CompressionManager,
+) -> CompressionResult<()> {
+    // HSETEX format: key [FNX | FXX] [EX seconds | PX ms | EXAT ts | PXAT ts | KEEPTTL] FIELDS numfields field value ...
+    let Some(fields_index) = args
+        .iter()
+        .skip(1)
+        .position(|arg| arg.eq_ignore_ascii_case(b"FIELDS"))
+    else {
+        return Ok(());
+    };
+    // `position` is relative to the key, and the pairs start after the number of fields.
+    compress_field_value_pairs(args, manager, fields_index + 3)
+}
+
+fn compress_xadd_command(
+    args: &mut [Vec<u8>],
+    manager: &CompressionManager,
+) -> CompressionResult<()> {
+    // XADD format: key [NOMKSTREAM] [MAXLEN | MINID [= | ~] threshold [LIMIT count]] id field value ...
+    let mut i = 1;
+    while let Some(arg) = args.get(i) {
+        if arg.eq_ignore_ascii_case(b"NOMKSTREAM") {
+            i += 1;
+        } else if arg.eq_ignore_ascii_case(b"MAXLEN") || arg.eq_ignore_ascii_case(b"MINID") {
+            i += 1;
+            if matches!(args.get(i).map(Vec::as_slice), Some(b"=") | Some(b"~")) {
+                i += 1;
+            }
+            // Skip the threshold
+            i += 1;
+            if args
+                .get(i)
+                .is_some_and(|arg| arg.eq_ignore_ascii_case(b"LIMIT"))
+            {
+                i += 2;
+            }
+        } else {
+            break;
+        }
+    }
+    // Skip the entry id
+    compress_field_value_pairs(args, manager, i + 1)
+}
+
 pub fn process_response_for_decompression(
     value: redis::Value,
     request_type: RequestType,
@@ -784,6 +1081,20 @@ pub fn process_response_for_decompression(
         RequestType::GetEx => decompress_single_value_response(value, manager),
         RequestType::GetDel => decompress_single_value_response(value, manager),
         RequestType::GetSet => decompress_single_value_response(value, manager),
+        RequestType::HGet | RequestType::LIndex => decompress_single_value_response(value, manager),
+        RequestType::HMGet | RequestType::HVals | RequestType::HGetEx | RequestType::LRange => {
+            decompress_mget_response(value, manager)
+        }
+        // LPOP and RPOP return an array when called with a count
+        RequestType::LPop | RequestType::RPop => match value {
+            Value::Array(_) => decompress_mget_response(value, manager),
+            _ => decompress_single_value_response(value, manager),
+        },
+        RequestType::HGetAll => decompress_field_value_pairs_response(value, manager),
+        RequestType::XRange | RequestType::XRevRange => {
+            decompress_stream_entries_response(value, manager)
+        }
+        RequestType::XRead => decompress_xread_response(value, manager),
         _ => Ok(value),
     }
 }
@@ -828,6 +1139,102 @@ pub fn decompress_mget_response(
     }
 }
 
+/// Decompresses the values of a map, or of a flat array of `name value` pairs as returned by RESP2.
+pub fn decompress_field_value_pairs_response(
+    value: redis::Value,
+    manager: &CompressionManager,
+) -> CompressionResult<redis::Value> {
+    use redis::Value;
+
+    match value {
+        Value::Map(pairs) => {
+            let decompressed_pairs: Result<Vec<_>, _> = pairs
+                .into_iter()
+                .map(|(name, value)| Ok((name, decompress_single_value_response(value, manager)?)))
+                .collect();
+            Ok(Value::Map(decompressed_pairs?))
+        }
+        Value::Array(values) => {
+            let decompressed_values: Result<Vec<_>, _> = values
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    if index % 2 == 1 {
+                        decompress_single_value_response(value, manager)
+                    } else {
+                        Ok(value)
+                    }
+                })
+                .collect();
+            Ok(Value::Array(decompressed_values?))
+        }
+        _ => Ok(value),
+    }
+}
+
+/// Decompresses the field values of stream entries, formatted as `[[id, [field, value, ...]], ...]`.
+pub fn decompress_stream_entries_response(
+    value: redis::Value,
+    manager: &CompressionManager,
+) -> CompressionResult<redis::Value> {
+    use redis::Value;
+
+    match value {
+        Value::Array(entries) => {
+            let decompressed_entries: Result<Vec<_>, _> = entries
+                .into_iter()
+                .map(|entry| match entry {
+                    Value::Array(mut id_and_fields) if id_and_fields.len() == 2 => {
+                        let fields = id_and_fields.pop().unwrap_or(Value::Nil);
+                        id_and_fields.push(decompress_field_value_pairs_response(fields, manager)?);
+                        Ok(Value::Array(id_and_fields))
+                    }
+                    other => Ok(other),
+                })
+                .collect();
+            Ok(Value::Array(decompressed_entries?))
+        }
+        _ => Ok(value),
+    }
+}
+
+/// Decompresses the entries of every stream in an XREAD response. RESP3 returns a map of stream names to entries,
+/// and RESP2 an array of `[name, entries]` pairs.
+pub fn decompress_xread_response(
+    value: redis::Value,
+    manager: &CompressionManager,
+) -> CompressionResult<redis::Value> {
+    use redis::Value;
+
+    match value {
+        Value::Map(streams) => {
+            let decompressed_streams: Result<Vec<_>, _> = streams
+                .into_iter()
+                .map(|(name, entries)| {
+                    Ok((name, decompress_stream_entries_response(entries, manager)?))
+                })
+                .collect();
+            Ok(Value::Map(decompressed_streams?))
+        }
+        Value::Array(streams) => {
+            let decompressed_streams: Result<Vec<_>, _> = streams
+                .into_iter()
+                .map(|stream| match stream {
+                    Value::Array(mut name_and_entries) if name_and_entries.len() == 2 => {
+                        let entries = name_and_entries.pop().unwrap_or(Value::Nil);
+                        name_and_entries
+                            .push(decompress_stream_entries_response(entries, manager)?);
+                        Ok(Value::Array(name_and_entries))
+                    }
+                    other => Ok(other),
+                })
+                .collect();
+            Ok(Value::Array(decompressed_streams?))
+        }
+        _ => Ok(value),
+    }
+}
+
 /// Magic prefix for compressed data headers (first 3 bytes)
 pub const MAGIC_PREFIX: [u8; 3] = [0x00, 0x01, 0x02];
 
@@ -842,6 +1249,11 @@ pub const CURRENT_VERSION: u8 = 0x00;
 pub const HEADER_SIZE: usize = 5;
 pub const MIN_COMPRESSED_SIZE: usize = HEADER_SIZE + 1;
 
+/// Backend ID of values compressed by zstd with a dictionary.
+/// The header of these values is followed by the 4-byte big-endian ID of the dictionary.
+pub const ZSTD_DICTIONARY_BACKEND_ID: u8 = 0x03;
+pub const DICTIONARY_HEADER_SIZE: usize = HEADER_SIZE + 4;
+
 /// Checks if data has a valid magic header (any version)
 pub fn has_magic_header(data: &[u8]) -> bool {
     data.len() >= HEADER_SIZE && data[0..3] == MAGIC_PREFIX
@@ -867,6 +1279,18 @@ pub fn extract_backend_id(data: &[u8]) -> Option<u8> {
     }
 }
 
+/// Extracts the dictionary ID of a value compressed with a dictionary
+/// Returns None if the data wasn't compressed with a dictionary, or if its header is truncated
+pub fn extract_dictionary_id(data: &[u8]) -> Option<u32> {
+    if extract_backend_id(data) != Some(ZSTD_DICTIONARY_BACKEND_ID)
+        || data.len() < DICTIONARY_HEADER_SIZE
+    {
+        return None;
+    }
+    let id_bytes = data[HEADER_SIZE..DICTIONARY_HEADER_SIZE].try_into().ok()?;
+    Some(u32::from_be_bytes(id_bytes))
+}
+
 /// Checks if the data has a valid magic header with the current version
 pub fn has_current_version_header(data: &[u8]) -> bool {
     extract_version(data) == Some(CURRENT_VERSION)
@@ -922,7 +1346,9 @@ fn get_backend_for_decompression(
     backend_id: u8,
 ) -> CompressionResult<&'static dyn CompressionBackend> {
     match backend_id {
-        0x01 => Ok(static_backends::get_zstd_backend()),
+        // The static zstd backend has no dictionary, so it rejects dictionary-compressed values
+        // with a decompression error rather than reporting an unsupported backend.
+        0x01 | ZSTD_DICTIONARY_BACKEND_ID => Ok(static_backends::get_zstd_backend()),
         0x02 => Ok(static_backends::get_lz4_backend()),
         _ => Err(CompressionError::unsupported_backend(format!(
             "backend ID 0x{:02x}",
@@ -0,0 +1,123 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Pluggable source of the client's password, for secrets that rotate outside of the application, e.g. in a vault
+//! or a file.
+
+use logger_core::log_warn;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Provides the password the client authenticates with.
+///
+/// The client asks for the password when it connects, before every reconnection attempt, and after a request failed
+/// with a `WRONGPASS` or `NOAUTH` error, in which case all connections re-authenticate with the new password and the
+/// request is sent once more. This applies to commands, scripts, transactions and cluster scans, and to batches whose
+/// commands all failed to authenticate. The username of the connection request is kept.
+#[async_trait::async_trait]
+pub trait CredentialsProvider: Send + Sync {
+    /// Returns the current password, or `None` if it isn't available, in which case the last password is kept.
+    async fn get_password(&self) -> Option<String>;
+}
+
+impl fmt::Debug for dyn CredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CredentialsProvider")
+    }
+}
+
+/// Makes the client of `request` take its password from `provider`, e.g. a callback of the language bindings.
+pub fn set_credentials_provider(
+    request: &mut crate::ConnectionRequest,
+    provider: Arc<dyn CredentialsProvider>,
+) {
+    request.credentials_provider = Some(provider);
+}
+
+/// Reads the password from a file, e.g. a mounted secret, every time it's asked for. Trailing line breaks are
+/// ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordFile {
+    path: PathBuf,
+}
+
+impl PasswordFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl CredentialsProvider for PasswordFile {
+    async fn get_password(&self) -> Option<String> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(contents) => Some(contents.trim_end_matches(['\r', '\n']).to_string()),
+            Err(err) => {
+                log_warn(
+                    "credentials",
+                    format!(
+                        "Failed to read the password file {}: {err}",
+                        self.path.display()
+                    ),
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Passes the password of a [`CredentialsProvider`] to the reconnection paths, which take an
+/// [`redis::IAMTokenProvider`].
+pub(crate) struct CredentialsProviderHandle(pub(crate) Arc<dyn CredentialsProvider>);
+
+#[async_trait::async_trait]
+impl redis::IAMTokenProvider for CredentialsProviderHandle {
+    async fn get_valid_token(&self) -> Option<String> {
+        self.0.get_password().await
+    }
+}
+
+/// Returns whether `err` means that the password the client authenticated with isn't valid anymore.
+pub(crate) fn is_authentication_error(err: &redis::RedisError) -> bool {
+    err.kind() == redis::ErrorKind::AuthenticationFailed
+        || matches!(err.code(), Some("WRONGPASS" | "NOAUTH"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_authentication_error() {
+        let wrongpass = redis::parse_redis_value(
+            b"-WRONGPASS invalid username-password pair or user is disabled.\r\n",
+        )
+        .unwrap()
+        .extract_error()
+        .unwrap_err();
+        assert!(is_authentication_error(&wrongpass));
+        let noauth = redis::parse_redis_value(b"-NOAUTH Authentication required.\r\n")
+            .unwrap()
+            .extract_error()
+            .unwrap_err();
+        assert!(is_authentication_error(&noauth));
+        let other = redis::parse_redis_value(b"-ERR unknown command\r\n")
+            .unwrap()
+            .extract_error()
+            .unwrap_err();
+        assert!(!is_authentication_error(&other));
+    }
+
+    #[tokio::test]
+    async fn test_password_file_is_read_on_every_call() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("password");
+        let provider = PasswordFile::new(&path);
+        assert_eq!(provider.get_password().await, None);
+
+        std::fs::write(&path, "first\n").unwrap();
+        assert_eq!(provider.get_password().await.as_deref(), Some("first"));
+        std::fs::write(&path, "second").unwrap();
+        assert_eq!(provider.get_password().await.as_deref(), Some("second"));
+    }
+}
@@ -0,0 +1,472 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Keyspace notifications, delivered as decoded [`KeyspaceEvent`]s instead of raw pattern messages. The events are
+//! selected with a mask in the syntax of the `notify-keyspace-events` server setting, which has to enable them on the
+//! server as well. The notifications are only published on the node the key lives on, so in cluster mode the client
+//! subscribes on every primary.
+
+use crate::client::types::KeyspaceEventsConfig;
+use redis::{ErrorKind, PubSubChannelOrPattern, PushInfo, PushKind, RedisError, Value};
+use std::collections::HashSet;
+
+const KEYSPACE_PREFIX: &[u8] = b"__keyspace@";
+const KEYEVENT_PREFIX: &[u8] = b"__keyevent@";
+const KEYEVENT_PATTERN: &[u8] = b"__keyevent@*__:*";
+
+const GENERIC: u16 = 1 << 0;
+const STRING: u16 = 1 << 1;
+const LIST: u16 = 1 << 2;
+const SET: u16 = 1 << 3;
+const HASH: u16 = 1 << 4;
+const SORTED_SET: u16 = 1 << 5;
+const EXPIRED: u16 = 1 << 6;
+const EVICTED: u16 = 1 << 7;
+const STREAM: u16 = 1 << 8;
+const KEY_MISS: u16 = 1 << 9;
+const NEW_KEY: u16 = 1 << 10;
+const MODULE: u16 = 1 << 11;
+/// The classes of `A`, which doesn't include the key miss and new key events.
+const ALL: u16 =
+    GENERIC | STRING | LIST | SET | HASH | SORTED_SET | EXPIRED | EVICTED | STREAM | MODULE;
+
+/// A keyspace notification, decoded from a [`PushKind::KeyspaceEvent`] push.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyspaceEvent {
+    /// Database of the key.
+    pub db: u32,
+    pub key: Vec<u8>,
+    /// Name of the event, e.g. `set`, `expired` or `lpush`.
+    pub event: String,
+}
+
+impl KeyspaceEvent {
+    /// Decodes the event of a [`PushKind::KeyspaceEvent`] push, whose data is the database, the key and the event.
+    /// Returns `None` for other pushes.
+    pub fn from_push(push: &PushInfo) -> Option<Self> {
+        if push.kind != PushKind::KeyspaceEvent {
+            return None;
+        }
+        match push.data.as_slice() {
+            [
+                Value::BulkString(db),
+                Value::BulkString(key),
+                Value::BulkString(event),
+            ] => Some(Self {
+                db: std::str::from_utf8(db).ok()?.parse().ok()?,
+                key: key.clone(),
+                event: String::from_utf8(event.clone()).ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    fn into_push(self) -> PushInfo {
+        PushInfo {
+            kind: PushKind::KeyspaceEvent,
+            data: vec![
+                Value::BulkString(self.db.to_string().into_bytes()),
+                Value::BulkString(self.key),
+                Value::BulkString(self.event.into_bytes()),
+            ],
+        }
+    }
+}
+
+/// Subscription patterns and decoder of the keyspace notifications selected by a [`KeyspaceEventsConfig`].
+#[derive(Debug)]
+pub(crate) struct KeyspaceEvents {
+    keyevent: bool,
+    classes: u16,
+    /// Glob-style patterns of the keys, all keys if empty.
+    key_patterns: Vec<Vec<u8>>,
+    /// Patterns of the keyspace channels, one per key pattern.
+    keyspace_patterns: HashSet<PubSubChannelOrPattern>,
+}
+
+impl KeyspaceEvents {
+    pub(crate) fn new(config: &KeyspaceEventsConfig) -> Result<Self, RedisError> {
+        let mut keyspace = false;
+        let mut keyevent = false;
+        let mut classes = 0;
+        for flag in config.event_mask.chars() {
+            match flag {
+                'K' => keyspace = true,
+                'E' => keyevent = true,
+                'A' => classes |= ALL,
+                'g' => classes |= GENERIC,
+                '$' => classes |= STRING,
+                'l' => classes |= LIST,
+                's' => classes |= SET,
+                'h' => classes |= HASH,
+                'z' => classes |= SORTED_SET,
+                'x' => classes |= EXPIRED,
+                'e' => classes |= EVICTED,
+                't' => classes |= STREAM,
+                'm' => classes |= KEY_MISS,
+                'n' => classes |= NEW_KEY,
+                'd' => classes |= MODULE,
+                _ => {
+                    return Err(RedisError::from((
+                        ErrorKind::InvalidClientConfig,
+                        "Invalid keyspace event mask",
+                        format!("unknown flag '{flag}' in '{}'", config.event_mask),
+                    )));
+                }
+            }
+        }
+        if !keyspace && !keyevent {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Invalid keyspace event mask",
+                "the mask must contain K, E or both".to_string(),
+            )));
+        }
+        if classes == 0 {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Invalid keyspace event mask",
+                "the mask must contain at least one event class".to_string(),
+            )));
+        }
+
+        let keyspace_patterns = if !keyspace {
+            HashSet::new()
+        } else if config.key_patterns.is_empty() {
+            HashSet::from([Self::keyspace_pattern(b"*")])
+        } else {
+            config
+                .key_patterns
+                .iter()
+                .map(|key_pattern| Self::keyspace_pattern(key_pattern))
+                .collect()
+        };
+
+        Ok(Self {
+            keyevent,
+            classes,
+            key_patterns: config.key_patterns.clone(),
+            keyspace_patterns,
+        })
+    }
+
+    /// Returns the patterns to subscribe to on every primary.
+    pub(crate) fn patterns(&self) -> HashSet<PubSubChannelOrPattern> {
+        let mut patterns = self.keyspace_patterns.clone();
+        if self.keyevent {
+            patterns.insert(KEYEVENT_PATTERN.to_vec());
+        }
+        patterns
+    }
+
+    fn keyspace_pattern(key_pattern: &[u8]) -> PubSubChannelOrPattern {
+        [KEYSPACE_PREFIX, b"*__:", key_pattern].concat()
+    }
+
+    /// Decodes `push` if it's a notification of one of the subscribed patterns. Returns `None` for a notification that
+    /// isn't selected by the mask or the key patterns, and other pushes unchanged.
+    pub(crate) fn decode(&self, push: PushInfo) -> Option<PushInfo> {
+        let Some(event) = self.decode_message(&push) else {
+            return Some(push);
+        };
+        event.and_then(|event| {
+            (self.classes & event_class(&event.event) != 0).then(|| event.into_push())
+        })
+    }
+
+    /// Returns `None` if `push` isn't a notification of the subscribed patterns, and `Some(None)` if it is, but its key
+    /// isn't selected.
+    fn decode_message(&self, push: &PushInfo) -> Option<Option<KeyspaceEvent>> {
+        if push.kind != PushKind::PMessage {
+            return None;
+        }
+        let [
+            Value::BulkString(pattern),
+            Value::BulkString(channel),
+            Value::BulkString(message),
+        ] = push.data.as_slice()
+        else {
+            return None;
+        };
+
+        if self.keyevent && pattern == KEYEVENT_PATTERN {
+            let (db, event) = split_channel(channel, KEYEVENT_PREFIX)?;
+            let selected = self.key_patterns.is_empty()
+                || self
+                    .key_patterns
+                    .iter()
+                    .any(|key_pattern| glob_match(key_pattern, message));
+            return Some(selected.then(|| KeyspaceEvent {
+                db,
+                key: message.clone(),
+                event: String::from_utf8_lossy(event).into_owned(),
+            }));
+        }
+
+        if self.keyspace_patterns.contains(pattern) {
+            let (db, key) = split_channel(channel, KEYSPACE_PREFIX)?;
+            return Some(Some(KeyspaceEvent {
+                db,
+                key: key.to_vec(),
+                event: String::from_utf8_lossy(message).into_owned(),
+            }));
+        }
+
+        None
+    }
+}
+
+/// Splits a `<prefix><db>__:<suffix>` notification channel into the database and the suffix.
+fn split_channel<'a>(channel: &'a [u8], prefix: &[u8]) -> Option<(u32, &'a [u8])> {
+    let rest = channel.strip_prefix(prefix)?;
+    let separator = rest.windows(3).position(|window| window == b"__:")?;
+    let db = std::str::from_utf8(&rest[..separator]).ok()?.parse().ok()?;
+    Some((db, &rest[separator + 3..]))
+}
+
+/// Returns the class of an event, per the events the server documents for each class. Events the client doesn't
+/// know, such as the ones of module key types, are selected by both `g` and `d`.
+fn event_class(event: &str) -> u16 {
+    match event {
+        "expired" => EXPIRED,
+        "evicted" => EVICTED,
+        "keymiss" => KEY_MISS,
+        "new" => NEW_KEY,
+        "set" | "setrange" | "incrby" | "incrbyfloat" | "append" => STRING,
+        "lpush" | "rpush" | "lpop" | "rpop" | "linsert" | "lset" | "lrem" | "ltrim"
+        | "sortstore" => LIST,
+        "sadd" | "srem" | "spop" | "sinterstore" | "sunionstore" | "sdiffstore" => SET,
+        "del" | "rename_from" | "rename_to" | "move_from" | "move_to" | "copy_to" | "restore"
+        | "expire" | "persist" => GENERIC,
+        "hset" | "hincrby" | "hincrbyfloat" | "hdel" | "hexpire" | "hpersist" | "hexpired" => HASH,
+        "zadd" | "zincr" | "zrem" | "zrembyscore" | "zrembyrank" | "zrembylex" | "zpopmin"
+        | "zpopmax" | "zdiffstore" | "zinterstore" | "zunionstore" | "zrangestore" => SORTED_SET,
+        "xadd"
+        | "xtrim"
+        | "xdel"
+        | "xsetid"
+        | "xgroup-create"
+        | "xgroup-createconsumer"
+        | "xgroup-delconsumer"
+        | "xgroup-destroy"
+        | "xgroup-setid" => STREAM,
+        _ => GENERIC | MODULE,
+    }
+}
+
+/// Matches `string` against a glob-style pattern, with the `*`, `?`, `[...]` and `\` syntax of the server.
+fn glob_match(pattern: &[u8], string: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => string.is_empty(),
+        Some((b'*', rest)) => (0..=string.len()).any(|skip| glob_match(rest, &string[skip..])),
+        Some((b'?', rest)) => !string.is_empty() && glob_match(rest, &string[1..]),
+        Some((b'[', rest)) => {
+            let Some((&byte, string_rest)) = string.split_first() else {
+                return false;
+            };
+            let (negated, mut class) = match rest.split_first() {
+                Some((b'^', class)) => (true, class),
+                _ => (false, rest),
+            };
+            let mut matched = false;
+            loop {
+                match class {
+                    [] => break,
+                    [b']', after @ ..] => {
+                        class = after;
+                        break;
+                    }
+                    [b'\\', escaped, after @ ..] => {
+                        matched |= *escaped == byte;
+                        class = after;
+                    }
+                    [start, b'-', end, after @ ..] if *end != b']' => {
+                        let (low, high) = if start <= end {
+                            (*start, *end)
+                        } else {
+                            (*end, *start)
+                        };
+                        matched |= (low..=high).contains(&byte);
+                        class = after;
+                    }
+                    [first, after @ ..] => {
+                        matched |= *first == byte;
+                        class = after;
+                    }
+                }
+            }
+            matched != negated && glob_match(class, string_rest)
+        }
+        Some((b'\\', [escaped, rest @ ..])) => {
+            string.first() == Some(escaped) && glob_match(rest, &string[1..])
+        }
+        Some((first, rest)) => string.first() == Some(first) && glob_match(rest, &string[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyspace_events(event_mask: &str, key_patterns: &[&str]) -> KeyspaceEvents {
+        KeyspaceEvents::new(&KeyspaceEventsConfig {
+            event_mask: event_mask.to_string(),
+            key_patterns: key_patterns.iter().map(|p| p.as_bytes().to_vec()).collect(),
+        })
+        .unwrap()
+    }
+
+    fn pmessage(pattern: &str, channel: &str, message: &str) -> PushInfo {
+        PushInfo {
+            kind: PushKind::PMessage,
+            data: vec![
+                Value::BulkString(pattern.as_bytes().to_vec()),
+                Value::BulkString(channel.as_bytes().to_vec()),
+                Value::BulkString(message.as_bytes().to_vec()),
+            ],
+        }
+    }
+
+    fn decoded(events: &KeyspaceEvents, push: PushInfo) -> Option<KeyspaceEvent> {
+        KeyspaceEvent::from_push(&events.decode(push)?)
+    }
+
+    fn assert_unchanged(events: &KeyspaceEvents, push: PushInfo) {
+        let decoded = events.decode(push.clone()).unwrap();
+        assert_eq!(decoded.kind, push.kind);
+        assert_eq!(decoded.data, push.data);
+    }
+
+    fn event(db: u32, key: &str, event: &str) -> Option<KeyspaceEvent> {
+        Some(KeyspaceEvent {
+            db,
+            key: key.as_bytes().to_vec(),
+            event: event.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_invalid_masks_are_rejected() {
+        for mask in ["", "K", "E", "g$", "KEq"] {
+            let result = KeyspaceEvents::new(&KeyspaceEventsConfig {
+                event_mask: mask.to_string(),
+                key_patterns: vec![],
+            });
+            assert_eq!(
+                result.unwrap_err().kind(),
+                ErrorKind::InvalidClientConfig,
+                "{mask}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_patterns() {
+        let events = keyspace_events("KEA", &["user:*", "order:?"]);
+        let expected: HashSet<_> = [
+            b"__keyspace@*__:user:*".to_vec(),
+            b"__keyspace@*__:order:?".to_vec(),
+            b"__keyevent@*__:*".to_vec(),
+        ]
+        .into();
+        assert_eq!(events.patterns(), expected);
+
+        let events = keyspace_events("Kx", &[]);
+        let expected: HashSet<_> = [b"__keyspace@*__:*".to_vec()].into();
+        assert_eq!(events.patterns(), expected);
+    }
+
+    #[test]
+    fn test_decode_keyspace_notification() {
+        let events = keyspace_events("K$", &["user:*"]);
+        assert_eq!(
+            decoded(
+                &events,
+                pmessage("__keyspace@*__:user:*", "__keyspace@3__:user:1", "set")
+            ),
+            event(3, "user:1", "set")
+        );
+        // Filtered by the event class.
+        assert!(
+            events
+                .decode(pmessage(
+                    "__keyspace@*__:user:*",
+                    "__keyspace@3__:user:1",
+                    "lpush"
+                ))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_decode_keyevent_notification() {
+        let events = keyspace_events("Egx", &["user:[0-9]*"]);
+        assert_eq!(
+            decoded(
+                &events,
+                pmessage("__keyevent@*__:*", "__keyevent@0__:expired", "user:42")
+            ),
+            event(0, "user:42", "expired")
+        );
+        assert_eq!(
+            decoded(
+                &events,
+                pmessage("__keyevent@*__:*", "__keyevent@0__:del", "user:7")
+            ),
+            event(0, "user:7", "del")
+        );
+        // Filtered by the key patterns.
+        assert!(
+            events
+                .decode(pmessage("__keyevent@*__:*", "__keyevent@0__:del", "user:x"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_other_pushes_are_unchanged() {
+        let events = keyspace_events("KEA", &[]);
+        let push = pmessage("news.*", "news.sports", "goal");
+        assert_unchanged(&events, push);
+
+        let push = PushInfo {
+            kind: PushKind::Message,
+            data: vec![
+                Value::BulkString(b"__keyevent@0__:set".to_vec()),
+                Value::BulkString(b"key".to_vec()),
+            ],
+        };
+        assert_unchanged(&events, push);
+    }
+
+    #[test]
+    fn test_event_classes() {
+        assert_eq!(event_class("hset"), HASH);
+        assert_eq!(event_class("zadd"), SORTED_SET);
+        assert_eq!(event_class("xadd"), STREAM);
+        assert_eq!(event_class("sortstore"), LIST);
+        assert_eq!(event_class("rename_to"), GENERIC);
+        assert_eq!(event_class("module.event"), GENERIC | MODULE);
+        // Module events aren't classified by their first letter.
+        assert_eq!(event_class("hll.update"), GENERIC | MODULE);
+        assert_eq!(event_class("zmodule.add"), GENERIC | MODULE);
+        assert_eq!(event_class("xmodule.add"), GENERIC | MODULE);
+        assert_eq!(event_class("zpopmin"), SORTED_SET);
+        assert_eq!(event_class("xgroup-createconsumer"), STREAM);
+        assert_eq!(event_class("hexpired"), HASH);
+        assert_eq!(ALL & (KEY_MISS | NEW_KEY), 0);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match(b"*", b""));
+        assert!(glob_match(b"user:*", b"user:1"));
+        assert!(!glob_match(b"user:*", b"order:1"));
+        assert!(glob_match(b"h?llo", b"hello"));
+        assert!(glob_match(b"h[ae]llo", b"hallo"));
+        assert!(!glob_match(b"h[^e]llo", b"hello"));
+        assert!(glob_match(b"h[a-c]llo", b"hbllo"));
+        assert!(glob_match(b"a\\*b", b"a*b"));
+        assert!(!glob_match(b"a\\*b", b"axb"));
+        assert!(glob_match(b"*:*:end", b"a:b:c:end"));
+    }
+}
@@ -10,6 +10,13 @@ use std::sync::{Arc, Weak};
 use std::time::Duration;
 use tokio::sync::{RwLock, mpsc};
 
+mod keyspace;
+pub use keyspace::KeyspaceEvent;
+pub(crate) use keyspace::KeyspaceEvents;
+
+mod queue;
+pub use queue::{PushReceiver, PushSender, push_channel};
+
 #[cfg(feature = "mock-pubsub")]
 mod mock;
 
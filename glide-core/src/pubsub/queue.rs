@@ -0,0 +1,308 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Queue the push notifications of a client are delivered through. When the connection request has a `pubsub_queue`,
+//! the number of buffered pubsub messages is bounded, so that a consumer that can't keep up with the published
+//! messages doesn't make the memory of the client grow without limit.
+
+use super::keyspace::KeyspaceEvents;
+use crate::client::types::{PubSubOverflowPolicy, PubSubQueueConfig};
+use logger_core::log_warn;
+use redis::{PubSubSynchronizer, PushInfo, PushKind};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use telemetrylib::GlideOpenTelemetry;
+use tokio::sync::{Notify, mpsc};
+
+const LOCK_ERR: &str = "Lock poisoned";
+
+/// Creates the queue the push notifications of a client are delivered through. The sender is passed to
+/// [`Client::new`](crate::client::Client::new), and the notifications are read from the receiver until the client is
+/// dropped.
+pub fn push_channel() -> (PushSender, PushReceiver) {
+    let queue = Arc::new(PushQueue::default());
+    (
+        PushSender {
+            queue: queue.clone(),
+        },
+        PushReceiver { queue },
+    )
+}
+
+/// Sending half of the [`push_channel`], passed to [`Client::new`](crate::client::Client::new).
+pub struct PushSender {
+    queue: Arc<PushQueue>,
+}
+
+impl PushSender {
+    /// Bounds the queue per `config`, and returns the sender the connections deliver the push notifications with. The
+    /// receiver is closed once the returned sender and all of its clones are dropped.
+    pub(crate) fn start(
+        &self,
+        config: Option<PubSubQueueConfig>,
+    ) -> mpsc::UnboundedSender<PushInfo> {
+        if let Some(config) = config {
+            let _ = self.queue.config.set(config);
+        }
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let queue = self.queue.clone();
+        tokio::spawn(async move {
+            while let Some(push) = receiver.recv().await {
+                queue.push(push);
+            }
+            queue.close();
+        });
+        sender
+    }
+
+    /// Sets the synchronizer that resubscribes when the queue overflows with the
+    /// [`Resubscribe`](PubSubOverflowPolicy::Resubscribe) policy.
+    pub(crate) fn set_synchronizer(&self, synchronizer: Weak<dyn PubSubSynchronizer>) {
+        let _ = self.queue.synchronizer.set(synchronizer);
+    }
+
+    /// Sets the decoder the keyspace notifications are delivered through as
+    /// [`KeyspaceEvent`](redis::PushKind::KeyspaceEvent) pushes.
+    pub(crate) fn set_keyspace_events(&self, keyspace_events: KeyspaceEvents) {
+        let _ = self.queue.keyspace_events.set(keyspace_events);
+    }
+}
+
+/// Receiving half of the [`push_channel`].
+pub struct PushReceiver {
+    queue: Arc<PushQueue>,
+}
+
+impl PushReceiver {
+    /// Receives the next push notification, or `None` once the client was dropped and all notifications were received.
+    pub async fn recv(&mut self) -> Option<PushInfo> {
+        loop {
+            {
+                let mut state = self.queue.state.lock().expect(LOCK_ERR);
+                if let Some(push) = state.pushes.pop_front() {
+                    return Some(push);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            // There's a single receiver, so a notification that was sent before it waits is stored as a permit.
+            self.queue.notify.notified().await;
+        }
+    }
+}
+
+#[derive(Default)]
+struct PushQueue {
+    state: Mutex<QueueState>,
+    notify: Notify,
+    /// Bounds the number of buffered messages if set.
+    config: OnceLock<PubSubQueueConfig>,
+    synchronizer: OnceLock<Weak<dyn PubSubSynchronizer>>,
+    /// Decodes the keyspace notifications if set.
+    keyspace_events: OnceLock<KeyspaceEvents>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    pushes: VecDeque<PushInfo>,
+    /// Number of published messages among `pushes`.
+    messages: usize,
+    closed: bool,
+}
+
+/// Returns whether `push` is a published message. Only these are dropped when the queue is full, the other push
+/// notifications, such as subscription confirmations and disconnections, are always delivered.
+fn is_message(push: &PushInfo) -> bool {
+    matches!(
+        push.kind,
+        PushKind::Message | PushKind::PMessage | PushKind::SMessage | PushKind::KeyspaceEvent
+    )
+}
+
+impl PushQueue {
+    fn push(&self, push: PushInfo) {
+        let push = match self.keyspace_events.get() {
+            Some(keyspace_events) => match keyspace_events.decode(push) {
+                Some(push) => push,
+                None => return,
+            },
+            None => push,
+        };
+
+        let mut dropped = 0;
+        let mut resubscribe = false;
+        {
+            let mut state = self.state.lock().expect(LOCK_ERR);
+            match self.config.get() {
+                Some(config) if is_message(&push) && state.messages >= config.capacity => {
+                    match config.overflow_policy {
+                        PubSubOverflowPolicy::DropOldest => {
+                            if let Some(oldest) = state.pushes.iter().position(is_message) {
+                                state.pushes.remove(oldest);
+                                state.pushes.push_back(push);
+                            }
+                            dropped = 1;
+                        }
+                        PubSubOverflowPolicy::DropNewest => dropped = 1,
+                        PubSubOverflowPolicy::Resubscribe => {
+                            state.pushes.retain(|push| !is_message(push));
+                            dropped = state.messages + 1;
+                            state.messages = 0;
+                            resubscribe = true;
+                        }
+                    }
+                }
+                _ => {
+                    if is_message(&push) {
+                        state.messages += 1;
+                    }
+                    state.pushes.push_back(push);
+                }
+            }
+        }
+        self.notify.notify_one();
+
+        if dropped > 0 {
+            let _ = GlideOpenTelemetry::record_pubsub_messages_dropped(dropped as u64);
+        }
+        if resubscribe {
+            log_warn(
+                "pubsub queue",
+                format!("The pubsub queue is full, dropped {dropped} messages and resubscribing"),
+            );
+            if let Some(synchronizer) = self.synchronizer.get().and_then(Weak::upgrade) {
+                synchronizer.resubscribe_all();
+            }
+        }
+    }
+
+    fn close(&self) {
+        self.state.lock().expect(LOCK_ERR).closed = true;
+        self.notify.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::types::KeyspaceEventsConfig;
+    use redis::Value;
+
+    fn message(id: i64) -> PushInfo {
+        PushInfo {
+            kind: PushKind::Message,
+            data: vec![Value::BulkString(b"channel".to_vec()), Value::Int(id)],
+        }
+    }
+
+    fn subscribe() -> PushInfo {
+        PushInfo {
+            kind: PushKind::Subscribe,
+            data: vec![Value::BulkString(b"channel".to_vec()), Value::Int(1)],
+        }
+    }
+
+    fn bounded_queue(capacity: usize, overflow_policy: PubSubOverflowPolicy) -> PushQueue {
+        let queue = PushQueue::default();
+        let _ = queue.config.set(PubSubQueueConfig {
+            capacity,
+            overflow_policy,
+        });
+        queue
+    }
+
+    fn message_ids(queue: &PushQueue) -> Vec<Option<i64>> {
+        queue
+            .state
+            .lock()
+            .unwrap()
+            .pushes
+            .iter()
+            .map(|push| match (&push.kind, push.data.get(1)) {
+                (PushKind::Message, Some(Value::Int(id))) => Some(*id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unbounded_queue_keeps_all_messages() {
+        let queue = PushQueue::default();
+        for id in 0..100 {
+            queue.push(message(id));
+        }
+        assert_eq!(message_ids(&queue).len(), 100);
+    }
+
+    #[test]
+    fn test_drop_oldest_keeps_the_newest_messages() {
+        let queue = bounded_queue(2, PubSubOverflowPolicy::DropOldest);
+        queue.push(subscribe());
+        for id in 0..4 {
+            queue.push(message(id));
+        }
+        assert_eq!(message_ids(&queue), vec![None, Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn test_drop_newest_keeps_the_oldest_messages() {
+        let queue = bounded_queue(2, PubSubOverflowPolicy::DropNewest);
+        for id in 0..4 {
+            queue.push(message(id));
+        }
+        queue.push(subscribe());
+        assert_eq!(message_ids(&queue), vec![Some(0), Some(1), None]);
+    }
+
+    #[test]
+    fn test_resubscribe_drops_the_buffered_messages() {
+        let queue = bounded_queue(2, PubSubOverflowPolicy::Resubscribe);
+        queue.push(message(0));
+        queue.push(subscribe());
+        queue.push(message(1));
+        queue.push(message(2));
+        assert_eq!(message_ids(&queue), vec![None]);
+
+        queue.push(message(3));
+        assert_eq!(message_ids(&queue), vec![None, Some(3)]);
+    }
+
+    #[test]
+    fn test_keyspace_notifications_are_decoded() {
+        let queue = PushQueue::default();
+        let _ = queue.keyspace_events.set(
+            KeyspaceEvents::new(&KeyspaceEventsConfig {
+                event_mask: "Ex".to_string(),
+                key_patterns: vec![],
+            })
+            .unwrap(),
+        );
+        let keyevent = |event: &str| PushInfo {
+            kind: PushKind::PMessage,
+            data: vec![
+                Value::BulkString(b"__keyevent@*__:*".to_vec()),
+                Value::BulkString(format!("__keyevent@0__:{event}").into_bytes()),
+                Value::BulkString(b"key".to_vec()),
+            ],
+        };
+        queue.push(keyevent("expired"));
+        queue.push(keyevent("set"));
+        queue.push(message(0));
+
+        let state = queue.state.lock().unwrap();
+        let kinds: Vec<_> = state.pushes.iter().map(|push| push.kind.clone()).collect();
+        assert_eq!(kinds, vec![PushKind::KeyspaceEvent, PushKind::Message]);
+        assert_eq!(state.messages, 2);
+    }
+
+    #[tokio::test]
+    async fn test_receiver_is_closed_after_the_sender_is_dropped() {
+        let (push_sender, mut push_receiver) = push_channel();
+        let sender = push_sender.start(None);
+        sender.send(message(0)).unwrap();
+        drop(sender);
+
+        assert!(push_receiver.recv().await.is_some());
+        assert!(push_receiver.recv().await.is_none());
+    }
+}
@@ -34,6 +34,8 @@ const STANDALONE_SUBSCRIPTION_KINDS: &[PubSubSubscriptionKind] = &[
 struct SyncDiff {
     is_synchronized: bool,
     to_subscribe: PubSubSubscriptionInfo,
+    /// Node-local patterns missing on a primary, which are subscribed on that primary
+    node_local_to_subscribe_by_address: HashMap<String, HashSet<PubSubChannelOrPattern>>,
     to_unsubscribe_by_address: HashMap<String, PubSubSubscriptionInfo>,
 }
 
@@ -69,6 +71,12 @@ pub struct GlidePubSubSynchronizer {
     /// Pending unsubscribes due to topology change that need to be sent to specific addresses
     pending_unsubscribes: RwLock<HashMap<String, PubSubSubscriptionInfo>>,
 
+    /// Patterns subscribed on every primary, tracked apart from the desired subscriptions
+    node_local_patterns: RwLock<HashSet<PubSubChannelOrPattern>>,
+
+    /// Addresses of the primaries of the last topology, in cluster mode
+    primary_addresses: RwLock<HashSet<String>>,
+
     /// Configurable reconciliation interval
     reconciliation_interval: Duration,
 
@@ -94,6 +102,8 @@ impl GlidePubSubSynchronizer {
             reconciliation_complete_notify: Notify::new(),
             reconciliation_task_handle: Mutex::new(None),
             pending_unsubscribes: RwLock::new(HashMap::new()),
+            node_local_patterns: RwLock::new(HashSet::new()),
+            primary_addresses: RwLock::new(HashSet::new()),
             reconciliation_interval: interval,
             request_timeout,
         });
@@ -123,6 +133,8 @@ impl GlidePubSubSynchronizer {
             .read()
             .expect(LOCK_ERR);
 
+        let node_local_patterns = self.node_local_patterns.read().expect(LOCK_ERR);
+
         let mut actual: PubSubSubscriptionInfo = self
             .subscription_kinds()
             .iter()
@@ -131,10 +143,12 @@ impl GlidePubSubSynchronizer {
 
         for subs in current_by_addr.values() {
             for (kind, channels) in subs.iter() {
-                actual
-                    .get_mut(kind)
-                    .unwrap()
-                    .extend(channels.iter().cloned());
+                actual.get_mut(kind).unwrap().extend(
+                    channels
+                        .iter()
+                        .filter(|ch| !Self::is_node_local(&node_local_patterns, *kind, ch))
+                        .cloned(),
+                );
             }
         }
 
@@ -148,6 +162,8 @@ impl GlidePubSubSynchronizer {
             .current_subscriptions_by_address
             .read()
             .expect(LOCK_ERR);
+        let node_local_patterns = self.node_local_patterns.read().expect(LOCK_ERR);
+        let primary_addresses = self.primary_addresses.read().expect(LOCK_ERR);
 
         let mut actual: PubSubSubscriptionInfo = self
             .subscription_kinds()
@@ -158,19 +174,31 @@ impl GlidePubSubSynchronizer {
         let mut to_unsubscribe_by_address: HashMap<String, PubSubSubscriptionInfo> = HashMap::new();
 
         // Pass 1: O(current_subscriptions)
-        // Iterate over current subscriptions and add to to_unsub each subscription not in desired
+        // Iterate over current subscriptions and add to to_unsub each subscription not in desired.
+        // Node-local patterns are instead unsubscribed from the nodes that aren't primaries anymore.
         for (addr, subs) in current_by_addr.iter() {
+            let is_primary = !self.is_cluster || primary_addresses.contains(addr);
             for (kind, channels) in subs.iter() {
+                let is_node_local = |ch: &PubSubChannelOrPattern| {
+                    Self::is_node_local(&node_local_patterns, *kind, ch)
+                };
+
                 actual
                     .get_mut(kind)
                     .unwrap()
-                    .extend(channels.iter().cloned());
+                    .extend(channels.iter().filter(|ch| !is_node_local(ch)).cloned());
 
                 let desired_for_kind = desired.get(kind);
 
                 let to_unsub: HashSet<_> = channels
                     .iter()
-                    .filter(|ch| desired_for_kind.is_none_or(|d| !d.contains(*ch)))
+                    .filter(|ch| {
+                        if is_node_local(ch) {
+                            !is_primary
+                        } else {
+                            desired_for_kind.is_none_or(|d| !d.contains(*ch))
+                        }
+                    })
                     .cloned()
                     .collect();
 
@@ -205,15 +233,66 @@ impl GlidePubSubSynchronizer {
             }
         }
 
-        let is_synchronized = to_subscribe.is_empty() && to_unsubscribe_by_address.is_empty();
+        // Pass 3: O(node_local_patterns * primaries)
+        // Add the node-local patterns each primary isn't subscribed to. A standalone client has a single node, which
+        // may be subscribed through any of its addresses.
+        let mut node_local_to_subscribe_by_address = HashMap::new();
+        if !node_local_patterns.is_empty() {
+            let missing_patterns = |subscribed: Option<&HashSet<PubSubChannelOrPattern>>| {
+                node_local_patterns
+                    .iter()
+                    .filter(|pattern| subscribed.is_none_or(|s| !s.contains(*pattern)))
+                    .cloned()
+                    .collect::<HashSet<_>>()
+            };
+            if self.is_cluster {
+                for addr in primary_addresses.iter() {
+                    let subscribed = current_by_addr
+                        .get(addr)
+                        .and_then(|subs| subs.get(&PubSubSubscriptionKind::Pattern));
+                    let to_sub = missing_patterns(subscribed);
+                    if !to_sub.is_empty() {
+                        node_local_to_subscribe_by_address.insert(addr.clone(), to_sub);
+                    }
+                }
+            } else {
+                let subscribed: HashSet<_> = current_by_addr
+                    .values()
+                    .filter_map(|subs| subs.get(&PubSubSubscriptionKind::Pattern))
+                    .flatten()
+                    .cloned()
+                    .collect();
+                let to_sub = missing_patterns(Some(&subscribed));
+                if !to_sub.is_empty() {
+                    to_subscribe
+                        .entry(PubSubSubscriptionKind::Pattern)
+                        .or_default()
+                        .extend(to_sub);
+                }
+            }
+        }
+
+        let is_synchronized = to_subscribe.is_empty()
+            && node_local_to_subscribe_by_address.is_empty()
+            && to_unsubscribe_by_address.is_empty();
 
         SyncDiff {
             is_synchronized,
             to_subscribe,
+            node_local_to_subscribe_by_address,
             to_unsubscribe_by_address,
         }
     }
 
+    /// Returns whether `channel` is one of the node-local patterns, which are subscribed on every primary
+    fn is_node_local(
+        node_local_patterns: &HashSet<PubSubChannelOrPattern>,
+        kind: PubSubSubscriptionKind,
+        channel: &PubSubChannelOrPattern,
+    ) -> bool {
+        kind == PubSubSubscriptionKind::Pattern && node_local_patterns.contains(channel)
+    }
+
     /// Check sync state and update metrics - single computation
     fn check_and_record_sync_state(&self) {
         let state = self.compute_sync_diff();
@@ -324,6 +403,24 @@ impl GlidePubSubSynchronizer {
                 .await;
         }
 
+        for (addr, patterns) in diff.node_local_to_subscribe_by_address {
+            match Self::parse_address_to_routing(&addr) {
+                Ok(routing) => {
+                    self.execute_subscription_change(
+                        patterns,
+                        PubSubSubscriptionKind::Pattern,
+                        true,
+                        Some(routing),
+                    )
+                    .await
+                }
+                Err(e) => log_warn(
+                    "pubsub_synchronizer",
+                    format!("Failed to parse address '{}': {:?}", addr, e),
+                ),
+            }
+        }
+
         for (addr, subs_by_kind) in diff.to_unsubscribe_by_address {
             let routing = Self::parse_address_to_routing(&addr).ok();
 
@@ -724,10 +821,20 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
         } else {
             // For regular subscriptions (Exact/Pattern), remove from ALL addresses.
             // These are not slot-bound, and the server's unsubscribe is authoritative.
-            for (_, addr_subs) in current_by_addr.iter_mut() {
+            // Node-local patterns are subscribed on every primary, so they're only removed from the specific address.
+            let node_local_patterns = self.node_local_patterns.read().expect(LOCK_ERR);
+            for (addr, addr_subs) in current_by_addr.iter_mut() {
                 if let Some(existing) = addr_subs.get_mut(&subscription_type) {
                     for channel in &channels {
-                        existing.remove(channel);
+                        if *addr == address
+                            || !Self::is_node_local(
+                                &node_local_patterns,
+                                subscription_type,
+                                channel,
+                            )
+                        {
+                            existing.remove(channel);
+                        }
                     }
                 }
             }
@@ -764,6 +871,47 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
         self.trigger_reconciliation();
     }
 
+    fn add_node_local_patterns(&self, patterns: HashSet<PubSubChannelOrPattern>) {
+        self.node_local_patterns
+            .write()
+            .expect(LOCK_ERR)
+            .extend(patterns);
+        self.trigger_reconciliation();
+    }
+
+    fn resubscribe_all(&self) {
+        let current_by_addr = std::mem::take(
+            &mut *self
+                .current_subscriptions_by_address
+                .write()
+                .expect(LOCK_ERR),
+        );
+        if current_by_addr.is_empty() {
+            return;
+        }
+
+        log_debug(
+            "pubsub_synchronizer",
+            "Unsubscribing from all current subscriptions in order to resubscribe",
+        );
+
+        // The cleared current subscriptions make the reconciliation subscribe to the desired ones again, after it
+        // sent the pending unsubscribes.
+        {
+            let mut pending = self.pending_unsubscribes.write().expect(LOCK_ERR);
+            for (address, subs_by_kind) in current_by_addr {
+                let pending_for_address = pending.entry(address).or_default();
+                for (kind, channels) in subs_by_kind {
+                    pending_for_address
+                        .entry(kind)
+                        .or_default()
+                        .extend(channels);
+                }
+            }
+        }
+        self.trigger_reconciliation();
+    }
+
     fn handle_topology_refresh(&self, new_slot_map: &SlotMap) {
         let new_addresses: HashSet<String> = new_slot_map
             .all_node_addresses()
@@ -773,11 +921,27 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
 
         let mut modified = false;
 
+        let primary_addresses: HashSet<String> = new_slot_map
+            .addresses_for_all_primaries()
+            .iter()
+            .map(|arc| arc.to_string())
+            .collect();
+        let has_node_local_patterns = !self.node_local_patterns.read().expect(LOCK_ERR).is_empty();
+        {
+            let mut current_primaries = self.primary_addresses.write().expect(LOCK_ERR);
+            if *current_primaries != primary_addresses {
+                // Node-local patterns are subscribed on the new primaries, and unsubscribed from the demoted ones.
+                modified = has_node_local_patterns;
+                *current_primaries = primary_addresses;
+            }
+        }
+
         {
             let mut current_by_addr = self
                 .current_subscriptions_by_address
                 .write()
                 .expect(LOCK_ERR);
+            let node_local_patterns = self.node_local_patterns.read().expect(LOCK_ERR);
             let mut pending = self.pending_unsubscribes.write().expect(LOCK_ERR);
 
             // Helper to queue an unsubscribe
@@ -830,6 +994,9 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
                     let mut migrated_channels: HashSet<PubSubChannelOrPattern> = HashSet::new();
 
                     channels.retain(|channel| {
+                        if Self::is_node_local(&node_local_patterns, *kind, channel) {
+                            return true;
+                        }
                         let slot = redis::cluster_topology::get_slot(channel);
 
                         match new_slot_map.shard_addrs_for_slot(slot) {
@@ -1074,3 +1241,113 @@ impl PubSubSynchronizer for GlidePubSubSynchronizer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYEVENT_PATTERN: &[u8] = b"__keyevent@*__:*";
+
+    fn synchronizer_with_primaries(primaries: &[&str]) -> Arc<GlidePubSubSynchronizer> {
+        let synchronizer = GlidePubSubSynchronizer::new(None, true, None, Duration::from_secs(1));
+        set_primaries(&synchronizer, primaries);
+        synchronizer.add_node_local_patterns(HashSet::from([KEYEVENT_PATTERN.to_vec()]));
+        synchronizer
+    }
+
+    /// Sets the primaries as `handle_topology_refresh` does from the slot map.
+    fn set_primaries(synchronizer: &GlidePubSubSynchronizer, primaries: &[&str]) {
+        *synchronizer.primary_addresses.write().unwrap() = primaries
+            .iter()
+            .map(|address| address.to_string())
+            .collect();
+    }
+
+    fn subscribe_pattern(synchronizer: &GlidePubSubSynchronizer, pattern: &[u8], address: &str) {
+        synchronizer.add_current_subscriptions(
+            HashSet::from([pattern.to_vec()]),
+            PubSubSubscriptionKind::Pattern,
+            address.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_node_local_patterns_are_subscribed_on_every_primary() {
+        let synchronizer = synchronizer_with_primaries(&["node1:6379", "node2:6379"]);
+        subscribe_pattern(&synchronizer, KEYEVENT_PATTERN, "node1:6379");
+
+        let diff = synchronizer.compute_sync_diff();
+        assert!(!diff.is_synchronized);
+        assert!(diff.to_subscribe.is_empty());
+        assert_eq!(
+            diff.node_local_to_subscribe_by_address,
+            HashMap::from([(
+                "node2:6379".to_string(),
+                HashSet::from([KEYEVENT_PATTERN.to_vec()])
+            )])
+        );
+
+        subscribe_pattern(&synchronizer, KEYEVENT_PATTERN, "node2:6379");
+        assert!(synchronizer.compute_sync_diff().is_synchronized);
+    }
+
+    #[tokio::test]
+    async fn test_failover_moves_node_local_patterns_to_the_new_primary() {
+        let synchronizer = synchronizer_with_primaries(&["node1:6379", "node2:6379"]);
+        subscribe_pattern(&synchronizer, KEYEVENT_PATTERN, "node1:6379");
+        subscribe_pattern(&synchronizer, KEYEVENT_PATTERN, "node2:6379");
+        assert!(synchronizer.compute_sync_diff().is_synchronized);
+
+        // node3 took over the shard of node2, which is now its replica.
+        set_primaries(&synchronizer, &["node1:6379", "node3:6379"]);
+
+        let diff = synchronizer.compute_sync_diff();
+        assert!(!diff.is_synchronized);
+        assert_eq!(
+            diff.node_local_to_subscribe_by_address,
+            HashMap::from([(
+                "node3:6379".to_string(),
+                HashSet::from([KEYEVENT_PATTERN.to_vec()])
+            )])
+        );
+        assert_eq!(
+            diff.to_unsubscribe_by_address,
+            HashMap::from([(
+                "node2:6379".to_string(),
+                HashMap::from([(
+                    PubSubSubscriptionKind::Pattern,
+                    HashSet::from([KEYEVENT_PATTERN.to_vec()])
+                )])
+            )])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscription_state_hides_node_local_patterns() {
+        let user_pattern = b"news.*".to_vec();
+        let synchronizer = GlidePubSubSynchronizer::new(
+            Some(HashMap::from([(
+                PubSubSubscriptionKind::Pattern,
+                HashSet::from([user_pattern.clone()]),
+            )])),
+            true,
+            None,
+            Duration::from_secs(1),
+        );
+        set_primaries(&synchronizer, &["node1:6379"]);
+        synchronizer.add_node_local_patterns(HashSet::from([KEYEVENT_PATTERN.to_vec()]));
+        subscribe_pattern(&synchronizer, KEYEVENT_PATTERN, "node1:6379");
+        subscribe_pattern(&synchronizer, &user_pattern, "node1:6379");
+
+        let (desired, actual) = synchronizer.get_subscription_state();
+        assert_eq!(
+            desired.get(&PubSubSubscriptionKind::Pattern),
+            Some(&HashSet::from([user_pattern.clone()]))
+        );
+        assert_eq!(
+            actual.get(&PubSubSubscriptionKind::Pattern),
+            Some(&HashSet::from([user_pattern]))
+        );
+        assert!(synchronizer.compute_sync_diff().is_synchronized);
+    }
+}
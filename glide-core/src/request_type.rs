@@ -1,6 +1,7 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 use redis::{Cmd, cmd};
+use telemetrylib::GlideOpenTelemetry;
 
 #[cfg(feature = "proto")]
 use crate::command_request::RequestType as ProtobufRequestType;
@@ -856,13 +857,36 @@ impl RequestType {
             RequestType::GetEx => CommandCompressionBehavior::DecompressValues,
             RequestType::GetDel => CommandCompressionBehavior::DecompressValues,
             RequestType::GetSet => CommandCompressionBehavior::DecompressValues,
+            RequestType::HSet => CommandCompressionBehavior::CompressValues,
+            RequestType::HMSet => CommandCompressionBehavior::CompressValues,
+            RequestType::HSetNX => CommandCompressionBehavior::CompressValues,
+            RequestType::HSetEx => CommandCompressionBehavior::CompressValues,
+            RequestType::LPush => CommandCompressionBehavior::CompressValues,
+            RequestType::RPush => CommandCompressionBehavior::CompressValues,
+            RequestType::LSet => CommandCompressionBehavior::CompressValues,
+            RequestType::XAdd => CommandCompressionBehavior::CompressValues,
+            RequestType::HGet => CommandCompressionBehavior::DecompressValues,
+            RequestType::HMGet => CommandCompressionBehavior::DecompressValues,
+            RequestType::HGetAll => CommandCompressionBehavior::DecompressValues,
+            RequestType::HVals => CommandCompressionBehavior::DecompressValues,
+            RequestType::HGetEx => CommandCompressionBehavior::DecompressValues,
+            RequestType::LRange => CommandCompressionBehavior::DecompressValues,
+            RequestType::LPop => CommandCompressionBehavior::DecompressValues,
+            RequestType::RPop => CommandCompressionBehavior::DecompressValues,
+            RequestType::LIndex => CommandCompressionBehavior::DecompressValues,
+            RequestType::XRange => CommandCompressionBehavior::DecompressValues,
+            RequestType::XRevRange => CommandCompressionBehavior::DecompressValues,
+            RequestType::XRead => CommandCompressionBehavior::DecompressValues,
             _ => CommandCompressionBehavior::NoCompression,
         }
     }
 
     /// Returns a `Cmd` set with the command name matching the request.
+    ///
+    /// When OpenTelemetry is initialized, the command is tagged with the request type, which labels its latency
+    /// metrics. Unlike the command name, the request type is bounded, since all custom commands share one.
     pub fn get_command(&self) -> Option<Cmd> {
-        match self {
+        let mut command = match self {
             RequestType::InvalidRequest => None,
             RequestType::CustomCommand => Some(Cmd::new()),
             RequestType::Get => Some(cmd("GET")),
@@ -1262,6 +1286,10 @@ impl RequestType {
             RequestType::SUnsubscribeBlocking => Some(cmd("SUNSUBSCRIBE_BLOCKING")),
             RequestType::GetSubscriptions => Some(cmd("GET_SUBSCRIPTIONS")),
             _ => todo!(),
+        }?;
+        if GlideOpenTelemetry::is_initialized() {
+            command.set_request_type(Some(format!("{self:?}").into()));
         }
+        Some(command)
     }
 }
@@ -3,7 +3,9 @@
 use super::rotating_buffer::RotatingBuffer;
 use crate::client::Client;
 use crate::client::get_or_init_runtime;
-use crate::compression::process_command_args_for_compression;
+use crate::compression::{
+    CommandCompressionBehavior, compression_request_type, process_command_args_for_compression,
+};
 
 use crate::cluster_scan_container::get_cluster_scan_cursor;
 use crate::command_request::{
@@ -14,6 +16,7 @@ use crate::errors::{RequestErrorType, error_message, error_type};
 use crate::otel_db_semantics::{
     set_db_attributes, set_db_batch_attributes, set_db_script_attributes,
 };
+use crate::pubsub::{PushReceiver, PushSender, push_channel};
 use crate::response;
 use crate::response::Response;
 use ClosingReason::*;
@@ -27,9 +30,7 @@ use redis::cluster_routing::{
     MultipleNodeRoutingInfo, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
 };
 use redis::cluster_routing::{ResponsePolicy, Routable};
-use redis::{
-    ClusterScanArgs, Cmd, PipelineRetryStrategy, PushInfo, RedisError, ScanStateRC, Value,
-};
+use redis::{ClusterScanArgs, Cmd, PipelineRetryStrategy, RedisError, ScanStateRC, Value};
 use std::cell::Cell;
 use std::collections::HashSet;
 use std::fs;
@@ -44,7 +45,6 @@ use thiserror::Error;
 
 use tokio::net::{UnixListener, UnixStream};
 use tokio::sync::Mutex;
-use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Sender, channel};
 use tokio::task;
 use tokio_util::task::LocalPoolHandle;
@@ -331,6 +331,8 @@ async fn send_command(
     mut cmd: Cmd,
     mut client: Client,
     routing: Option<RoutingInfo>,
+    transaction_session_id: Option<Chars>,
+    request_timeout: Option<u32>,
 ) -> ClientUsageResult<Value> {
     if let Some(ref span) = cmd.span() {
         set_db_attributes(span, &cmd, &client);
@@ -349,10 +351,19 @@ async fn send_command(
         );
     }
 
-    client
-        .send_command(&mut cmd, routing)
-        .await
-        .map_err(|err| err.into())
+    match transaction_session_id {
+        Some(session_id) => {
+            client
+                .send_session_command(&session_id, &cmd, request_timeout)
+                .await
+        }
+        None => {
+            client
+                .send_command_with_timeout(&mut cmd, routing, request_timeout)
+                .await
+        }
+    }
+    .map_err(|err| err.into())
 }
 
 /// Process a command for compression by extracting arguments and applying compression
@@ -360,9 +371,11 @@ async fn send_command(
 ///
 /// This function processes the response from a batch operation (pipeline or transaction)
 /// and decompresses individual response values using magic header detection.
+/// Responses of commands returning nested values, e.g. HGETALL, are decompressed according to the command.
 ///
 /// # Arguments
 /// * `response` - The batch response value (typically an array)
+/// * `pipeline` - The batch the response belongs to
 /// * `client` - The client instance containing the compression manager
 ///
 /// # Returns
@@ -370,6 +383,7 @@ async fn send_command(
 /// * `Err(CompressionError)` - If critical decompression errors occur
 fn process_batch_response_for_decompression(
     response: redis::Value,
+    pipeline: &redis::Pipeline,
     client: &Client,
 ) -> Result<redis::Value, crate::compression::CompressionError> {
     use redis::Value;
@@ -392,11 +406,27 @@ fn process_batch_response_for_decompression(
         Value::Array(responses) => {
             // Process each response using the existing decompression function
             let mut processed_responses = Vec::with_capacity(responses.len());
+            let mut commands = pipeline.cmd_iter();
             for response in responses {
-                let processed_response = match crate::compression::decompress_single_value_response(
-                    response.clone(),
-                    manager,
-                ) {
+                let request_type = commands
+                    .next()
+                    .and_then(|cmd| compression_request_type(cmd))
+                    .filter(|request_type| {
+                        request_type.compression_behavior()
+                            == CommandCompressionBehavior::DecompressValues
+                    });
+                let decompressed = match request_type {
+                    Some(request_type) => crate::compression::process_response_for_decompression(
+                        response.clone(),
+                        request_type,
+                        Some(manager),
+                    ),
+                    None => crate::compression::decompress_single_value_response(
+                        response.clone(),
+                        manager,
+                    ),
+                };
+                let processed_response = match decompressed {
                     Ok(decompressed) => decompressed,
                     Err(_) => response, // Return original on error
                 };
@@ -436,15 +466,10 @@ fn process_command_for_compression(
 
     // Extract command name to determine request type
     let command_name = &all_args[0];
-    let command_str = String::from_utf8_lossy(command_name).to_uppercase();
-    let request_type = match command_str.as_str() {
-        "SET" => crate::request_type::RequestType::Set,
-        "MSET" => crate::request_type::RequestType::MSet,
-        "MSETNX" => crate::request_type::RequestType::MSetNX,
-        "SETEX" => crate::request_type::RequestType::SetEx,
-        "PSETEX" => crate::request_type::RequestType::PSetEx,
-        "SETNX" => crate::request_type::RequestType::SetNX,
-        _ => return Ok(()), // Unknown command, no compression needed
+    let Some(request_type) = compression_request_type(cmd).filter(|request_type| {
+        request_type.compression_behavior() == CommandCompressionBehavior::CompressValues
+    }) else {
+        return Ok(()); // Unknown or read command, no compression needed
     };
 
     // Get arguments excluding the command name
@@ -530,7 +555,10 @@ async fn send_batch(
     client: &mut Client,
     routing: Option<RoutingInfo>,
     command_span: Option<GlideSpan>,
+    transaction_session_id: Option<Chars>,
+    request_timeout: Option<u32>,
 ) -> ClientUsageResult<Value> {
+    let timeout = request.timeout.or(request_timeout);
     let mut pipeline = redis::Pipeline::with_capacity(request.commands.capacity());
     pipeline.set_pipeline_span(command_span);
     if request.is_atomic {
@@ -562,25 +590,37 @@ async fn send_batch(
         pipeline.add_command(redis_cmd);
     }
 
-    let res = match request.is_atomic {
-        true => client
+    let res = match (request.is_atomic, transaction_session_id) {
+        (_, Some(session_id)) => client
+            .send_session_transaction(
+                &session_id,
+                &pipeline,
+                timeout,
+                request.raise_on_error.unwrap_or_default(),
+            )
+            .await
+            .map_err(|err| err.into()),
+        (true, None) => client
             .send_transaction(
                 &pipeline,
                 routing,
-                request.timeout,
+                timeout,
                 request.raise_on_error.unwrap_or_default(),
             )
             .await
             .map_err(|err| err.into()),
-        false => client
+        (false, None) => client
             .send_pipeline(
                 &pipeline,
                 routing,
                 request.raise_on_error.unwrap_or_default(),
-                request.timeout,
+                timeout,
                 PipelineRetryStrategy {
                     retry_server_error: request.retry_server_error.unwrap_or_default(),
                     retry_connection_error: request.retry_connection_error.unwrap_or_default(),
+                    retry_idempotent_connection_error: request
+                        .retry_idempotent_connection_error
+                        .unwrap_or_default(),
                 },
             )
             .await
@@ -590,7 +630,7 @@ async fn send_batch(
     // Process response for decompression if needed
     match res {
         Ok(value) => {
-            match process_batch_response_for_decompression(value.clone(), client) {
+            match process_batch_response_for_decompression(value.clone(), &pipeline, client) {
                 Ok(processed_value) => Ok(processed_value),
                 Err(e) => {
                     // Log decompression error but return original response
@@ -706,6 +746,27 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
             None
         };
 
+        let transaction_session_id = request.transaction_session_id;
+        if transaction_session_id.is_some()
+            && !matches!(
+                &request.command,
+                Some(command_request::Command::SingleCommand(_))
+                    | Some(command_request::Command::Batch(_))
+            )
+        {
+            let _res = write_result(
+                Err(ClientUsageError::User(
+                    "Only single commands and batches can be sent in a transaction session"
+                        .to_string(),
+                )),
+                request.callback_idx,
+                &writer,
+                request.root_span_ptr,
+            )
+            .await;
+            return;
+        }
+
         let result = match request.command {
             Some(action) => match action {
                 command_request::Command::ClusterScan(cluster_scan_command) => {
@@ -717,7 +778,14 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                         Ok(mut cmd) => match get_route(request.route.0, Some(&cmd)) {
                             Ok(routes) => {
                                 cmd.set_span(get_unsafe_span_from_ptr(request.root_span_ptr));
-                                send_command(cmd, client, routes).await
+                                send_command(
+                                    cmd,
+                                    client,
+                                    routes,
+                                    transaction_session_id,
+                                    request.timeout,
+                                )
+                                .await
                             }
                             Err(e) => Err(e),
                         },
@@ -727,7 +795,15 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                 command_request::Command::Batch(batch) => match get_route(request.route.0, None) {
                     Ok(routes) => {
                         let otel_command_span = get_unsafe_span_from_ptr(request.root_span_ptr);
-                        send_batch(batch, &mut client, routes, otel_command_span).await
+                        send_batch(
+                            batch,
+                            &mut client,
+                            routes,
+                            otel_command_span,
+                            transaction_session_id,
+                            request.timeout,
+                        )
+                        .await
                     }
                     Err(e) => Err(e),
                 },
@@ -775,6 +851,17 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                     .await
                     .map_err(|err| err.into()),
 
+                command_request::Command::StartTransactionSession(start_session) => client
+                    .start_transaction_session(start_session.routing_key.as_deref())
+                    .await
+                    .map(|session_id| Value::BulkString(session_id.into_bytes()))
+                    .map_err(|err| err.into()),
+
+                command_request::Command::EndTransactionSession(end_session) => {
+                    client.end_transaction_session(&end_session.session_id);
+                    Ok(Value::Okay)
+                }
+
                 command_request::Command::RefreshIamToken(_refresh) => client
                     .refresh_iam_token()
                     .await
@@ -804,6 +891,9 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                         Ok(crate::command_request::CacheMetricsType::TotalLookups) => {
                             client.cache_total_lookups().map_err(|err| err.into())
                         }
+                        Ok(crate::command_request::CacheMetricsType::Invalidations) => {
+                            client.cache_invalidations().map_err(|err| err.into())
+                        }
                         Err(e) => Err(e),
                     }
                 }
@@ -875,7 +965,7 @@ pub fn close_socket(socket_path: &String) {
 async fn create_client(
     writer: &Rc<Writer>,
     request: ConnectionRequest,
-    push_tx: Option<mpsc::UnboundedSender<PushInfo>>,
+    push_tx: Option<PushSender>,
 ) -> Result<Client, ClientCreationError> {
     let client = match Client::new(request.into(), push_tx).await {
         Ok(client) => client,
@@ -888,7 +978,7 @@ async fn create_client(
 async fn wait_for_connection_configuration_and_create_client(
     client_listener: &mut UnixStreamListener,
     writer: &Rc<Writer>,
-    push_tx: Option<mpsc::UnboundedSender<PushInfo>>,
+    push_tx: Option<PushSender>,
 ) -> Result<Client, ClientCreationError> {
     // Wait for the server's address
     match client_listener.next_values::<ConnectionRequest>().await {
@@ -922,7 +1012,7 @@ async fn read_values_loop(
     }
 }
 
-async fn push_manager_loop(mut push_rx: mpsc::UnboundedReceiver<PushInfo>, writer: Rc<Writer>) {
+async fn push_manager_loop(mut push_rx: PushReceiver, writer: Rc<Writer>) {
     loop {
         let result = push_rx.recv().await;
         match result {
@@ -958,7 +1048,7 @@ async fn listen_on_client_stream(socket: UnixStream) {
     let mut client_listener = UnixStreamListener::new(socket.clone());
     let accumulated_outputs = Cell::new(Vec::new());
     let (sender, mut receiver) = channel(1);
-    let (push_tx, push_rx) = tokio::sync::mpsc::unbounded_channel();
+    let (push_tx, push_rx) = push_channel();
     let writer = Rc::new(Writer {
         socket,
         lock: write_lock,
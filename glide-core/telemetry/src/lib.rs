@@ -32,6 +32,18 @@ pub struct Telemetry {
     subscription_out_of_sync_count: usize,
     /// Unix timestamp (in milliseconds) of the last time subscriptions were in sync
     subscription_last_sync_timestamp: u64,
+    /// Total number of dedicated connections opened for blocking commands
+    total_blocking_connections: usize,
+    /// Number of blocking commands that timed out waiting for a dedicated connection
+    blocking_pool_acquire_timeout_count: usize,
+    /// Total number of requests sent by all clients and not yet completed
+    total_inflight_requests: usize,
+    /// Number of nodes whose circuit breaker is currently open or half-open
+    open_circuit_breakers: usize,
+    /// Number of times a circuit breaker was opened
+    circuit_breaker_opened_count: usize,
+    /// Number of pubsub messages dropped because the delivery queue of their client was full
+    pubsub_messages_dropped_count: usize,
 }
 
 lazy_static! {
@@ -202,6 +214,137 @@ impl Telemetry {
             .subscription_last_sync_timestamp
     }
 
+    /// Increment the total number of dedicated blocking connections by `incr_by`
+    /// Return the number of dedicated blocking connections after the increment
+    pub fn incr_total_blocking_connections(incr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.total_blocking_connections = t.total_blocking_connections.saturating_add(incr_by);
+        t.total_blocking_connections
+    }
+
+    /// Decrease the total number of dedicated blocking connections by `decr_by`
+    /// Return the number of dedicated blocking connections after the decrease
+    pub fn decr_total_blocking_connections(decr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.total_blocking_connections = t.total_blocking_connections.saturating_sub(decr_by);
+        t.total_blocking_connections
+    }
+
+    /// Return the number of dedicated blocking connections
+    pub fn total_blocking_connections() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .total_blocking_connections
+    }
+
+    /// Increment the number of blocking commands that timed out waiting for a dedicated connection
+    /// Return the new count after increment
+    pub fn incr_blocking_pool_acquire_timeout() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.blocking_pool_acquire_timeout_count =
+            t.blocking_pool_acquire_timeout_count.saturating_add(1);
+        t.blocking_pool_acquire_timeout_count
+    }
+
+    /// Return the number of blocking commands that timed out waiting for a dedicated connection
+    pub fn blocking_pool_acquire_timeout_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .blocking_pool_acquire_timeout_count
+    }
+
+    /// Increment the total number of inflight requests by `incr_by`
+    /// Return the number of inflight requests after the increment
+    pub fn incr_total_inflight_requests(incr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.total_inflight_requests = t.total_inflight_requests.saturating_add(incr_by);
+        t.total_inflight_requests
+    }
+
+    /// Decrease the total number of inflight requests by `decr_by`
+    /// Return the number of inflight requests after the decrease
+    pub fn decr_total_inflight_requests(decr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.total_inflight_requests = t.total_inflight_requests.saturating_sub(decr_by);
+        t.total_inflight_requests
+    }
+
+    /// Return the number of inflight requests
+    pub fn total_inflight_requests() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .total_inflight_requests
+    }
+
+    /// Increment the number of open circuit breakers by `incr_by`
+    /// Return the number of open circuit breakers after the increment
+    pub fn incr_open_circuit_breakers(incr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.open_circuit_breakers = t.open_circuit_breakers.saturating_add(incr_by);
+        t.open_circuit_breakers
+    }
+
+    /// Decrease the number of open circuit breakers by `decr_by`
+    /// Return the number of open circuit breakers after the decrease
+    pub fn decr_open_circuit_breakers(decr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.open_circuit_breakers = t.open_circuit_breakers.saturating_sub(decr_by);
+        t.open_circuit_breakers
+    }
+
+    /// Return the number of nodes whose circuit breaker is currently open or half-open
+    pub fn open_circuit_breakers() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .open_circuit_breakers
+    }
+
+    /// Increment the number of times a circuit breaker was opened
+    /// Return the new count after increment
+    pub fn incr_circuit_breaker_opened() -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.circuit_breaker_opened_count = t.circuit_breaker_opened_count.saturating_add(1);
+        t.circuit_breaker_opened_count
+    }
+
+    /// Return the number of times a circuit breaker was opened
+    pub fn circuit_breaker_opened_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .circuit_breaker_opened_count
+    }
+
+    /// Increment the number of dropped pubsub messages by `incr_by`
+    /// Return the new count after increment
+    pub fn incr_pubsub_messages_dropped(incr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.pubsub_messages_dropped_count = t.pubsub_messages_dropped_count.saturating_add(incr_by);
+        t.pubsub_messages_dropped_count
+    }
+
+    /// Return the number of pubsub messages dropped because the delivery queue of their client was full
+    pub fn pubsub_messages_dropped_count() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .pubsub_messages_dropped_count
+    }
+
+    /// Return the ratio between the original and the compressed size of the compressed values,
+    /// or 0 if no value was compressed yet
+    pub fn compression_ratio() -> f64 {
+        let t = TELEMETRY.read().expect(MUTEX_READ_ERR);
+        if t.total_bytes_compressed == 0 {
+            return 0.0;
+        }
+        t.total_original_bytes as f64 / t.total_bytes_compressed as f64
+    }
+
     /// Reset the telemetry collected thus far
     pub fn reset() {
         *TELEMETRY.write().expect(MUTEX_WRITE_ERR) = Telemetry::default();
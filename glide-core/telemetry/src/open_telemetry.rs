@@ -32,6 +32,25 @@ const RETRIES_METRIC: &str = "glide.retry_attempts";
 const MOVED_ERROR_METRIC: &str = "glide.moved_errors";
 const SUBSCRIPTION_OUT_OF_SYNC_METRIC: &str = "glide.subscription_out_of_sync_count";
 const SUBSCRIPTION_LAST_SYNC_TIMESTAMP_METRIC: &str = "glide.subscription_last_sync_timestamp";
+const COMMAND_LATENCY_METRIC: &str = "glide.command_latency";
+const INFLIGHT_REQUESTS_METRIC: &str = "glide.inflight_requests";
+const OPEN_CONNECTIONS_METRIC: &str = "glide.open_connections";
+const COMPRESSION_RATIO_METRIC: &str = "glide.compression_ratio";
+const CIRCUIT_BREAKER_STATE_CHANGES_METRIC: &str = "glide.circuit_breaker_state_changes";
+const OPEN_CIRCUIT_BREAKERS_METRIC: &str = "glide.open_circuit_breakers";
+const PUBSUB_MESSAGES_DROPPED_METRIC: &str = "glide.pubsub_messages_dropped";
+
+// Metric attributes
+const COMMAND_ATTRIBUTE: &str = "command";
+const NODE_ATTRIBUTE: &str = "node";
+const STATE_ATTRIBUTE: &str = "state";
+
+/// Bucket boundaries, in milliseconds, of the command latency histogram.
+/// The SDK defaults start at 5ms, which is too coarse for most Valkey commands.
+const COMMAND_LATENCY_BOUNDARIES_MS: [f64; 16] = [
+    0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0,
+    10000.0,
+];
 
 /// Custom error type for OpenTelemetry errors in Glide
 #[derive(Debug, Error)]
@@ -609,6 +628,20 @@ static MOVED_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock:
 static SUBSCRIPTION_OUT_OF_SYNC_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> =
     OnceLock::new();
 static SUBSCRIPTION_LAST_SYNC_GAUGE: OnceLock<opentelemetry::metrics::Gauge<u64>> = OnceLock::new();
+static COMMAND_LATENCY_HISTOGRAM: OnceLock<opentelemetry::metrics::Histogram<f64>> =
+    OnceLock::new();
+static INFLIGHT_REQUESTS_GAUGE: OnceLock<opentelemetry::metrics::ObservableGauge<u64>> =
+    OnceLock::new();
+static OPEN_CONNECTIONS_GAUGE: OnceLock<opentelemetry::metrics::ObservableGauge<u64>> =
+    OnceLock::new();
+static COMPRESSION_RATIO_GAUGE: OnceLock<opentelemetry::metrics::ObservableGauge<f64>> =
+    OnceLock::new();
+static CIRCUIT_BREAKER_STATE_CHANGES_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> =
+    OnceLock::new();
+static OPEN_CIRCUIT_BREAKERS_GAUGE: OnceLock<opentelemetry::metrics::ObservableGauge<u64>> =
+    OnceLock::new();
+static PUBSUB_MESSAGES_DROPPED_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> =
+    OnceLock::new();
 
 /// Singleton instance of GlideOpenTelemetry. Ensures that telemetry setup happens only once across the application.
 static OTEL: OnceCell<RwLock<GlideOpenTelemetry>> = OnceCell::new();
@@ -986,6 +1019,136 @@ impl GlideOpenTelemetry {
                 )
             })?;
 
+        // Create command latency histogram
+        COMMAND_LATENCY_HISTOGRAM
+            .set(
+                meter
+                    .f64_histogram(COMMAND_LATENCY_METRIC)
+                    .with_description("Latency of the commands sent to each node, by command")
+                    .with_unit("ms")
+                    .with_boundaries(COMMAND_LATENCY_BOUNDARIES_MS.to_vec())
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize command latency histogram"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create inflight requests gauge, observed on every export
+        INFLIGHT_REQUESTS_GAUGE
+            .set(
+                meter
+                    .u64_observable_gauge(INFLIGHT_REQUESTS_METRIC)
+                    .with_description(
+                        "Number of requests sent by all clients and not yet completed",
+                    )
+                    .with_unit("1")
+                    .with_callback(|observer| {
+                        observer.observe(Telemetry::total_inflight_requests() as u64, &[])
+                    })
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize inflight requests gauge".to_owned(),
+                )
+            })?;
+
+        // Create open connections gauge, observed on every export
+        OPEN_CONNECTIONS_GAUGE
+            .set(
+                meter
+                    .u64_observable_gauge(OPEN_CONNECTIONS_METRIC)
+                    .with_description("Number of connections currently open to Valkey")
+                    .with_unit("1")
+                    .with_callback(|observer| {
+                        observer.observe(Telemetry::total_connections() as u64, &[])
+                    })
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize open connections gauge".to_owned(),
+                )
+            })?;
+
+        // Create compression ratio gauge, observed on every export
+        COMPRESSION_RATIO_GAUGE
+            .set(
+                meter
+                    .f64_observable_gauge(COMPRESSION_RATIO_METRIC)
+                    .with_description(
+                        "Ratio between the original and the compressed size of the compressed values",
+                    )
+                    .with_unit("1")
+                    .with_callback(|observer| observer.observe(Telemetry::compression_ratio(), &[]))
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize compression ratio gauge".to_owned(),
+                )
+            })?;
+
+        // Create circuit breaker state changes counter
+        CIRCUIT_BREAKER_STATE_CHANGES_COUNTER
+            .set(
+                meter
+                    .u64_counter(CIRCUIT_BREAKER_STATE_CHANGES_METRIC)
+                    .with_description(
+                        "Number of times the circuit breaker of a node changed state, by node and new state",
+                    )
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize circuit breaker state changes counter"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create open circuit breakers gauge, observed on every export
+        OPEN_CIRCUIT_BREAKERS_GAUGE
+            .set(
+                meter
+                    .u64_observable_gauge(OPEN_CIRCUIT_BREAKERS_METRIC)
+                    .with_description(
+                        "Number of nodes whose circuit breaker is currently open or half-open",
+                    )
+                    .with_unit("1")
+                    .with_callback(|observer| {
+                        observer.observe(Telemetry::open_circuit_breakers() as u64, &[])
+                    })
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize open circuit breakers gauge"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create pubsub messages dropped counter
+        PUBSUB_MESSAGES_DROPPED_COUNTER
+            .set(
+                meter
+                    .u64_counter(PUBSUB_MESSAGES_DROPPED_METRIC)
+                    .with_description(
+                        "Number of pubsub messages dropped because the delivery queue of their client was full",
+                    )
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize pubsub messages dropped counter"
+                        .to_owned(),
+                )
+            })?;
+
         Ok(())
     }
 
@@ -1086,6 +1249,85 @@ impl GlideOpenTelemetry {
         Ok(())
     }
 
+    /// Record the latency of a request sent to a node
+    ///
+    /// `request_type` is the request type of the command, e.g. `ConfigGet`, or `Pipeline` or `Transaction` for
+    /// batches, and `node` is the address of the node the request was sent to.
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_command_latency(
+        request_type: &str,
+        node: &str,
+        latency: Duration,
+    ) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            COMMAND_LATENCY_HISTOGRAM
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Command latency histogram not initialized"
+                            .to_string(),
+                    )
+                })?
+                .record(
+                    latency.as_secs_f64() * 1000.0,
+                    &[
+                        opentelemetry::KeyValue::new(COMMAND_ATTRIBUTE, request_type.to_owned()),
+                        opentelemetry::KeyValue::new(NODE_ATTRIBUTE, node.to_owned()),
+                    ],
+                );
+        }
+        Ok(())
+    }
+
+    /// Record a state change of the circuit breaker of a node
+    ///
+    /// `node` is the address of the node, and `state` the new state of its circuit, e.g. `open`.
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_circuit_breaker_state_change(
+        node: &str,
+        state: &str,
+    ) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            CIRCUIT_BREAKER_STATE_CHANGES_COUNTER
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Circuit breaker state changes counter not initialized"
+                            .to_string(),
+                    )
+                })?
+                .add(
+                    1,
+                    &[
+                        opentelemetry::KeyValue::new(NODE_ATTRIBUTE, node.to_owned()),
+                        opentelemetry::KeyValue::new(STATE_ATTRIBUTE, state.to_owned()),
+                    ],
+                );
+        }
+        Ok(())
+    }
+
+    /// Record that `count` pubsub messages were dropped because the delivery queue of their client was full
+    ///
+    /// If OpenTelemetry is not initialized, only the [`Telemetry`] count is updated.
+    pub fn record_pubsub_messages_dropped(count: u64) -> Result<(), GlideOTELError> {
+        Telemetry::incr_pubsub_messages_dropped(count as usize);
+        if GlideOpenTelemetry::is_initialized() {
+            PUBSUB_MESSAGES_DROPPED_COUNTER
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Pubsub messages dropped counter not initialized"
+                            .to_string(),
+                    )
+                })?
+                .add(count, &[]);
+        }
+        Ok(())
+    }
+
     /// Get the flush interval milliseconds
     pub fn get_flush_interval_ms(config: GlideOpenTelemetryConfig) -> Duration {
         config.flush_interval_ms
@@ -1364,6 +1606,71 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_record_command_latency_and_gauges() {
+        let rt = shared_runtime();
+        rt.block_on(async {
+            let _ = std::fs::remove_file(METRICS_JSON);
+            init_otel().await.unwrap();
+            GlideOpenTelemetry::record_command_latency(
+                "Get",
+                "node1:6379",
+                Duration::from_micros(300),
+            )
+            .unwrap();
+            GlideOpenTelemetry::record_command_latency(
+                "Get",
+                "node1:6379",
+                Duration::from_millis(20),
+            )
+            .unwrap();
+            GlideOpenTelemetry::record_command_latency(
+                "Transaction",
+                "node2:6379",
+                Duration::from_millis(3),
+            )
+            .unwrap();
+
+            // Add a sleep to wait for the metrics to be flushed
+            sleep(Duration::from_millis(2100)).await;
+
+            let file_content = std::fs::read_to_string(METRICS_JSON).unwrap();
+            let lines: Vec<&str> = file_content
+                .split('\n')
+                .filter(|l| !l.trim().is_empty())
+                .collect();
+            let metric_json: serde_json::Value =
+                serde_json::from_str(lines[lines.len() - 1]).unwrap();
+
+            let latency_metric = find_metric_by_name(&metric_json, "glide.command_latency")
+                .expect("glide.command_latency metric not found");
+            assert_eq!(latency_metric["unit"], "ms");
+            let data_points = latency_metric["data_points"].as_array().unwrap();
+            let get_point = data_points
+                .iter()
+                .find(|point| point["attributes"]["command"] == "Get")
+                .expect("Get data point not found");
+            assert_eq!(get_point["attributes"]["node"], "node1:6379");
+            assert_eq!(get_point["count"], 2);
+            let transaction_point = data_points
+                .iter()
+                .find(|point| point["attributes"]["command"] == "Transaction")
+                .expect("Transaction data point not found");
+            assert_eq!(transaction_point["attributes"]["node"], "node2:6379");
+            assert_eq!(transaction_point["count"], 1);
+
+            for gauge in [
+                "glide.inflight_requests",
+                "glide.open_connections",
+                "glide.compression_ratio",
+            ] {
+                let gauge_metric = find_metric_by_name(&metric_json, gauge)
+                    .unwrap_or_else(|| panic!("{gauge} metric not found"));
+                assert_eq!(gauge_metric["data_points"].as_array().unwrap().len(), 1);
+            }
+        });
+    }
+
     #[test]
     fn test_set_status_ok() {
         let rt = shared_runtime();
@@ -6,6 +6,7 @@ mod utilities;
 pub(crate) mod test_cache {
 
     use super::*;
+    use glide_core::connection_request::CacheTrackingMode;
     use glide_core::connection_request::ClientSideCache;
     use glide_core::connection_request::EvictionPolicy;
     use redis::Value;
@@ -1175,4 +1176,79 @@ pub(crate) mod test_cache {
             assert_command_count(&mut test_basics.client, "GET", 2, use_cluster).await;
         });
     }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_cache_invalidated_by_server(
+        #[values(false, true)] use_cluster: bool,
+        #[values(CacheTrackingMode::DEFAULT, CacheTrackingMode::OPTIN)]
+        tracking_mode: CacheTrackingMode,
+    ) {
+        block_on_all(async {
+            let cache_id = "test_cache_invalidation";
+
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: false,
+                    client_side_cache: Some(ClientSideCache {
+                        cache_id: cache_id.to_string().into(),
+                        max_cache_kb: 1,
+                        entry_ttl_ms: 0,
+                        eviction_policy: None,
+                        enable_metrics: true,
+                        tracking_mode: Some(tracking_mode.into()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+            let mut set_cmd = redis::Cmd::new();
+            set_cmd.arg("SET").arg("invalidation_key").arg("old_value");
+            test_basics
+                .client
+                .send_command(&mut set_cmd, None)
+                .await
+                .unwrap();
+
+            let mut get_cmd = redis::Cmd::new();
+            get_cmd.arg("GET").arg("invalidation_key");
+            test_basics
+                .client
+                .send_command(&mut get_cmd, None)
+                .await
+                .unwrap();
+            assert!(is_key_cached(
+                cache_id,
+                b"invalidation_key",
+                CachedKeyType::String
+            ));
+
+            // The server pushes the invalidation before replying to the write.
+            let mut set_cmd = redis::Cmd::new();
+            set_cmd.arg("SET").arg("invalidation_key").arg("new_value");
+            test_basics
+                .client
+                .send_command(&mut set_cmd, None)
+                .await
+                .unwrap();
+            assert!(
+                !is_key_cached(cache_id, b"invalidation_key", CachedKeyType::String),
+                "invalidation_key should be removed by the server's invalidation message"
+            );
+
+            let get_result = test_basics
+                .client
+                .send_command(&mut get_cmd, None)
+                .await
+                .unwrap();
+            assert_eq!(get_result, Value::BulkString(b"new_value".to_vec()));
+
+            let invalidations = test_basics.client.cache_invalidations().unwrap();
+            assert_eq!(invalidations, Value::Int(1));
+        });
+    }
 }
@@ -1418,6 +1418,42 @@ pub(crate) mod shared_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_per_command_request_timeout(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    request_timeout: Some(10_000), // milliseconds
+                    shared_server: false,
+                    ..Default::default()
+                },
+            )
+            .await;
+            let mut cmd = redis::Cmd::new();
+            // Create a long running command, which times out by the command's timeout long before the client's
+            cmd.arg("EVAL")
+                .arg(
+                    r#"
+                    while (true)
+                    do
+                    redis.call('ping')
+                    end
+                "#,
+                )
+                .arg("0");
+            let result = test_basics
+                .client
+                .send_command_with_timeout(&mut cmd, None, Some(1))
+                .await;
+            assert!(result.is_err());
+            let err = result.unwrap_err();
+            assert!(err.is_timeout(), "{err}");
+        });
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
@@ -1659,10 +1695,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await
                 .expect("Pipeline failed");
@@ -1723,10 +1756,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     raise_error,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await;
 
@@ -1797,10 +1827,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await
                 .expect("Pipeline failed");
@@ -1823,6 +1850,64 @@ pub(crate) mod shared_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_blocking_command_does_not_delay_commands_to_the_same_node(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async move {
+            let test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    request_timeout: Some(5000),
+                    blocking_commands_pool: Some(
+                        glide_core::connection_request::BlockingCommandsPool::new(),
+                    ),
+                    ..Default::default()
+                },
+            )
+            .await;
+            let key = generate_random_string(10);
+
+            let mut blocking_client = test_basics.client.clone();
+            let blocking_key = key.clone();
+            let blpop = tokio::spawn(async move {
+                blocking_client
+                    .send_command(cmd("BLPOP").arg(&blocking_key).arg(4), None)
+                    .await
+            });
+            // Let the BLPOP reach the server before sending the other commands.
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+            // The key is the same, so in cluster mode the commands go to the node that runs the BLPOP.
+            let mut client = test_basics.client.clone();
+            let result = tokio::time::timeout(
+                std::time::Duration::from_secs(1),
+                client.send_command(cmd("LLEN").arg(&key), None),
+            )
+            .await
+            .expect("LLEN was delayed by the blocking command")
+            .unwrap();
+            assert_eq!(result, Value::Int(0));
+            assert!(!blpop.is_finished());
+
+            client
+                .send_command(cmd("LPUSH").arg(&key).arg("value"), None)
+                .await
+                .unwrap();
+            let result = blpop.await.unwrap().unwrap();
+            assert_eq!(
+                result,
+                Value::Array(vec![
+                    Value::BulkString(key.into_bytes()),
+                    Value::BulkString(b"value".to_vec()),
+                ])
+            );
+        });
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
@@ -1859,10 +1944,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await;
 
@@ -1907,10 +1989,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     Some(3000),
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await
                 .expect("Pipeline failed");
@@ -2006,10 +2085,7 @@ pub(crate) mod shared_client_tests {
                         None,
                         false,
                         None,
-                        PipelineRetryStrategy {
-                            retry_server_error: true,
-                            retry_connection_error: false,
-                        },
+                        PipelineRetryStrategy::new(true, false),
                     )
                     .await
                     .ok()
@@ -2066,10 +2142,7 @@ pub(crate) mod shared_client_tests {
                         None,
                         false,
                         None,
-                        PipelineRetryStrategy {
-                            retry_server_error: true,
-                            retry_connection_error: false,
-                        },
+                        PipelineRetryStrategy::new(true, false),
                     )
                     .await
             };
@@ -2123,6 +2196,86 @@ pub(crate) mod shared_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_pipeline_retries_idempotent_commands_after_kill_connection(
+        #[values(true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let key = format!("{{{}}}", generate_random_string(10));
+            let (list_key, string_key, counter_key) = (
+                format!("{key}list"),
+                format!("{key}string"),
+                format!("{key}counter"),
+            );
+            let configuration = TestConfiguration {
+                shared_server: true,
+                request_timeout: Some(3000),
+                ..Default::default()
+            };
+            let mut test_basics = setup_test_basics(use_cluster, configuration.clone()).await;
+            let mut client_for_kill = match test_basics.server {
+                BackingServer::Cluster(cluster) => {
+                    create_cluster_client(cluster.as_ref(), configuration).await
+                }
+                BackingServer::Standalone(_) => unreachable!("The test runs on a cluster"),
+            };
+
+            let pipeline_future = async {
+                let mut pipeline = Pipeline::new();
+                pipeline
+                    .blpop(&list_key, 2.0)
+                    .set(&string_key, "value")
+                    .get(&string_key)
+                    .incr(&counter_key, 1);
+                test_basics
+                    .client
+                    .send_pipeline(
+                        &pipeline,
+                        None,
+                        false,
+                        None,
+                        PipelineRetryStrategy::new(false, false)
+                            .with_idempotent_connection_error_retries(),
+                    )
+                    .await
+            };
+
+            let kill_future = async {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                kill_connection_for_route(
+                    &mut client_for_kill,
+                    RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        get_slot(key.as_bytes()),
+                        SlotAddr::Master,
+                    ))),
+                )
+                .await;
+            };
+
+            let (pipeline_result, _) = tokio::join!(pipeline_future, kill_future);
+            let result = match pipeline_result {
+                Ok(Value::Array(result)) => result,
+                other => panic!("Expected an array of values, got: {other:?}"),
+            };
+            let is_not_retried = |value: &Value| {
+                matches!(value, Value::ServerError(error)
+                    if error.err_code() == redis::NOT_RETRIED_AFTER_CONNECTION_ERROR_CODE)
+            };
+
+            // SET and GET were retried after the connection was killed, while BLPOP and INCR weren't.
+            assert!(is_not_retried(&result[0]), "Pipeline result: {result:?}");
+            assert_eq!(result[1], Value::Okay, "Pipeline result: {result:?}");
+            assert_eq!(
+                result[2],
+                Value::BulkString(b"value".to_vec()),
+                "Pipeline result: {result:?}"
+            );
+            assert!(is_not_retried(&result[3]), "Pipeline result: {result:?}");
+        });
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
@@ -2160,10 +2313,7 @@ pub(crate) mod shared_client_tests {
                         None,
                         false,
                         None,
-                        PipelineRetryStrategy {
-                            retry_server_error: true,
-                            retry_connection_error: false,
-                        },
+                        PipelineRetryStrategy::new(true, false),
                     )
                     .await
                     .ok()
@@ -2202,10 +2352,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await;
 
@@ -2250,10 +2397,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await
                 .expect("Pipeline failed");
@@ -2294,10 +2438,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await
                 .expect("Pipeline failed");
@@ -2344,10 +2485,7 @@ pub(crate) mod shared_client_tests {
                     None,
                     false,
                     None,
-                    PipelineRetryStrategy {
-                        retry_server_error: true,
-                        retry_connection_error: false,
-                    },
+                    PipelineRetryStrategy::new(true, false),
                 )
                 .await
                 .expect("Pipeline execution failed");
@@ -2401,10 +2539,7 @@ pub(crate) mod shared_client_tests {
                         None,
                         false,
                         None,
-                        PipelineRetryStrategy {
-                            retry_server_error: false,
-                            retry_connection_error: false,
-                        },
+                        PipelineRetryStrategy::new(false, false),
                     )
                     .await
                     .expect("Pipeline failed")
@@ -2985,6 +3120,272 @@ pub(crate) mod shared_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_password_file_is_read_again_after_the_password_rotated(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+            let addresses = match &test_basics.server {
+                BackingServer::Standalone(server) => vec![
+                    server
+                        .as_ref()
+                        .map(|server| server.get_client_addr())
+                        .unwrap_or(get_shared_server_address(false)),
+                ],
+                BackingServer::Cluster(cluster) => cluster
+                    .as_ref()
+                    .map(|cluster| cluster.get_server_addresses())
+                    .unwrap_or(get_shared_cluster_addresses(false)),
+            };
+            let username = format!("rotating-{}", generate_random_string(6));
+            let set_password = |password: &str| {
+                let mut cmd = redis::cmd("ACL");
+                cmd.arg("SETUSER")
+                    .arg(&username)
+                    .arg("on")
+                    .arg("resetpass")
+                    .arg(format!(">{password}"))
+                    .arg("~*")
+                    .arg("+@all");
+                cmd
+            };
+            let all_nodes = Some(RoutingInfo::MultiNode((
+                MultipleNodeRoutingInfo::AllNodes,
+                Some(redis::cluster_routing::ResponsePolicy::AllSucceeded),
+            )));
+            test_basics
+                .client
+                .send_command(&mut set_password("first"), all_nodes.clone())
+                .await
+                .unwrap();
+
+            let dir = tempfile::tempdir().unwrap();
+            let password_file = dir.path().join("password");
+            std::fs::write(&password_file, "first\n").unwrap();
+            let mut connection_request = create_connection_request(
+                &addresses,
+                &TestConfiguration {
+                    cluster_mode: if use_cluster {
+                        ClusterMode::Enabled
+                    } else {
+                        ClusterMode::Disabled
+                    },
+                    connection_info: Some(RedisConnectionInfo {
+                        username: Some(username.clone()),
+                        password: Some("ignored".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            );
+            connection_request.password_file_path = password_file.to_str().unwrap().into();
+            let mut client = Client::new(connection_request.into(), None).await.unwrap();
+            let key = generate_random_string(6);
+            send_set_and_get(client.clone(), key.clone()).await;
+
+            // The password changes on the server, the connections of the user are closed, and the secret is
+            // rotated only after the client tried to reconnect with the old password.
+            test_basics
+                .client
+                .send_command(&mut set_password("second"), all_nodes.clone())
+                .await
+                .unwrap();
+            let mut kill_cmd = redis::cmd("CLIENT");
+            kill_cmd.arg("KILL").arg("USER").arg(&username);
+            test_basics
+                .client
+                .send_command(&mut kill_cmd, all_nodes.clone())
+                .await
+                .unwrap();
+            let _ = client.send_command(&mut redis::cmd("PING"), None).await;
+            std::fs::write(&password_file, "second\n").unwrap();
+
+            let mut get_cmd = redis::cmd("GET");
+            get_cmd.arg(&key);
+            let value = retry(|| async {
+                let mut client = client.clone();
+                let mut cmd = get_cmd.clone();
+                match client.send_command(&mut cmd, None).await {
+                    Ok(value) => Some(value),
+                    Err(err) => {
+                        assert!(
+                            !matches!(err.code(), Some("WRONGPASS" | "NOAUTH")),
+                            "The client didn't re-authenticate: {err:?}"
+                        );
+                        None
+                    }
+                }
+            })
+            .await;
+            assert!(matches!(value, Value::BulkString(_)));
+
+            let mut whoami_cmd = redis::cmd("ACL");
+            whoami_cmd.arg("WHOAMI");
+            let whoami = client.send_command(&mut whoami_cmd, None).await.unwrap();
+            assert_eq!(
+                String::from_owned_redis_value(whoami).unwrap(),
+                username.as_str()
+            );
+
+            let mut acl_deluser_cmd = redis::cmd("ACL");
+            acl_deluser_cmd.arg("DELUSER").arg(&username);
+            let _ = test_basics
+                .client
+                .send_command(&mut acl_deluser_cmd, None)
+                .await;
+        });
+    }
+
+    #[test]
+    fn test_credentials_provider_is_rejected_with_iam_authentication() {
+        block_on_all(async {
+            let mut connection_request = create_connection_request(
+                &[get_shared_server_address(false)],
+                &TestConfiguration::default(),
+            );
+            connection_request.authentication_info =
+                protobuf::MessageField::some(glide_core::connection_request::AuthenticationInfo {
+                    username: "user".into(),
+                    iam_credentials: protobuf::MessageField::some(
+                        glide_core::connection_request::IamCredentials {
+                            cluster_name: "cluster".into(),
+                            region: "us-east-1".into(),
+                            ..Default::default()
+                        },
+                    ),
+                    ..Default::default()
+                });
+            connection_request.password_file_path = "/run/secrets/password".into();
+            let error = Client::new(connection_request.into(), None)
+                .await
+                .err()
+                .expect("The client shouldn't be created");
+            assert!(matches!(
+                error,
+                glide_core::client::ConnectionError::Configuration(_)
+            ));
+        });
+    }
+
+    #[cfg(not(feature = "mock-pubsub"))]
+    async fn next_push(push_receiver: &mut glide_core::pubsub::PushReceiver) -> redis::PushInfo {
+        tokio::time::timeout(std::time::Duration::from_secs(5), push_receiver.recv())
+            .await
+            .expect("No push notification was received")
+            .unwrap()
+    }
+
+    #[cfg(not(feature = "mock-pubsub"))]
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_pubsub_queue_overflow_resubscribes(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async move {
+            let test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+            let addresses = match &test_basics.server {
+                BackingServer::Standalone(server) => vec![
+                    server
+                        .as_ref()
+                        .map(|server| server.get_client_addr())
+                        .unwrap_or(get_shared_server_address(false)),
+                ],
+                BackingServer::Cluster(cluster) => cluster
+                    .as_ref()
+                    .map(|cluster| cluster.get_server_addresses())
+                    .unwrap_or(get_shared_cluster_addresses(false)),
+            };
+            let channel = format!("overflow-{}", generate_random_string(6));
+            let mut connection_request: glide_core::client::ConnectionRequest =
+                create_connection_request(
+                    &addresses,
+                    &TestConfiguration {
+                        cluster_mode: if use_cluster {
+                            ClusterMode::Enabled
+                        } else {
+                            ClusterMode::Disabled
+                        },
+                        ..Default::default()
+                    },
+                )
+                .into();
+            connection_request.pubsub_subscriptions = Some(HashMap::from([(
+                redis::PubSubSubscriptionKind::Exact,
+                std::collections::HashSet::from([channel.clone().into_bytes()]),
+            )]));
+            connection_request.pubsub_queue = Some(glide_core::client::PubSubQueueConfig {
+                capacity: 2,
+                overflow_policy: glide_core::client::PubSubOverflowPolicy::Resubscribe,
+            });
+            let (push_sender, mut push_receiver) = glide_core::pubsub::push_channel();
+            let mut client = Client::new(connection_request, Some(push_sender))
+                .await
+                .unwrap();
+            assert_eq!(
+                next_push(&mut push_receiver).await.kind,
+                redis::PushKind::Subscribe
+            );
+
+            let publish = |message: &str| {
+                let mut publish_cmd = redis::cmd("PUBLISH");
+                publish_cmd.arg(&channel).arg(message);
+                publish_cmd
+            };
+            // The third message overflows the queue while nothing is read.
+            let dropped_before = Telemetry::pubsub_messages_dropped_count();
+            for message in ["first", "second", "third"] {
+                client
+                    .send_command(&mut publish(message), None)
+                    .await
+                    .unwrap();
+            }
+            retry(|| async {
+                (Telemetry::pubsub_messages_dropped_count() >= dropped_before + 3).then_some(())
+            })
+            .await;
+
+            // The buffered messages were dropped, and the channel was unsubscribed from before the resubscribe.
+            assert_eq!(
+                next_push(&mut push_receiver).await.kind,
+                redis::PushKind::Unsubscribe
+            );
+            assert_eq!(
+                next_push(&mut push_receiver).await.kind,
+                redis::PushKind::Subscribe
+            );
+
+            client
+                .send_command(&mut publish("restored"), None)
+                .await
+                .unwrap();
+            let push = next_push(&mut push_receiver).await;
+            assert_eq!(push.kind, redis::PushKind::Message);
+            assert_eq!(
+                push.data,
+                vec![
+                    Value::BulkString(channel.clone().into_bytes()),
+                    Value::BulkString(b"restored".to_vec()),
+                ]
+            );
+        });
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
@@ -3060,4 +3461,105 @@ pub(crate) mod shared_client_tests {
             assert_eq!(hello_info.get("proto").unwrap(), &Value::Int(3));
         });
     }
+
+    fn first_primary_routing(use_cluster: bool) -> Option<RoutingInfo> {
+        use_cluster.then_some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::SpecificNode(Route::new(0, SlotAddr::Master)),
+        ))
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_requests_are_spread_over_multiple_connections_per_node(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    connections_per_node: Some(3),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+            let mut client_ids = std::collections::HashSet::new();
+            for _ in 0..6 {
+                let client_id = test_basics
+                    .client
+                    .send_command(
+                        &mut cmd("CLIENT").arg("ID"),
+                        first_primary_routing(use_cluster),
+                    )
+                    .await
+                    .unwrap();
+                client_ids.insert(client_id);
+            }
+            assert_eq!(client_ids.len(), 3, "{client_ids:?}");
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_watch_applies_to_transaction_with_multiple_connections_per_node(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    connections_per_node: Some(3),
+                    ..Default::default()
+                },
+            )
+            .await;
+            let key = generate_random_string(10);
+            let mut transaction = redis::pipe();
+            transaction.atomic();
+            transaction.set(&key, "from transaction");
+
+            // Repeated, so that the requests would land on every connection if they weren't pinned to the first one.
+            for _ in 0..3 {
+                test_basics
+                    .client
+                    .send_command(&mut cmd("WATCH").arg(&key), None)
+                    .await
+                    .unwrap();
+                test_basics
+                    .client
+                    .send_command(&mut cmd("SET").arg(&key).arg("changed"), None)
+                    .await
+                    .unwrap();
+                let result = test_basics
+                    .client
+                    .send_transaction(&transaction, None, None, true)
+                    .await
+                    .unwrap();
+                assert_eq!(result, Value::Nil, "the transaction should be aborted");
+
+                test_basics
+                    .client
+                    .send_command(&mut cmd("WATCH").arg(&key), None)
+                    .await
+                    .unwrap();
+                let result = test_basics
+                    .client
+                    .send_transaction(&transaction, None, None, true)
+                    .await
+                    .unwrap();
+                assert_eq!(result, Value::Array(vec![Value::Okay]));
+            }
+
+            let value = test_basics
+                .client
+                .send_command(&mut cmd("GET").arg(&key), None)
+                .await
+                .unwrap();
+            assert_eq!(value, Value::BulkString(b"from transaction".to_vec()));
+        });
+    }
 }
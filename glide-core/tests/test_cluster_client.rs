@@ -5,7 +5,7 @@ mod utilities;
 
 #[cfg(test)]
 mod cluster_client_tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::sync::Arc;
     use std::time::Duration;
 
@@ -23,12 +23,15 @@ mod cluster_client_tests {
             self, ProtocolVersion as GlideProtocolVersion, PubSubChannelsOrPatterns,
             PubSubSubscriptions, ReadFrom,
         },
+        pubsub::KeyspaceEvent,
     };
     use redis::{
         InfoDict, RedisConnectionInfo, Value,
         cluster_routing::{
-            MultipleNodeRoutingInfo, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
+            AggregateOp, MultipleNodeRoutingInfo, ResponsePolicy, Route, RoutingInfo,
+            SingleNodeRoutingInfo, SlotAddr,
         },
+        cluster_topology::get_slot,
     };
     use rstest::rstest;
     use versions::Versioning;
@@ -938,4 +941,92 @@ mod cluster_client_tests {
             );
         });
     }
+
+    #[cfg(not(feature = "mock-pubsub"))]
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(LONG_CLUSTER_TEST_TIMEOUT)]
+    fn test_keyspace_events_are_delivered_from_every_shard() {
+        block_on_all(async {
+            let cluster = RedisCluster::new(false, &None, Some(3), Some(0));
+            let mut connection_request: glide_core::client::ConnectionRequest =
+                create_connection_request(
+                    &cluster.get_server_addresses(),
+                    &TestConfiguration {
+                        cluster_mode: ClusterMode::Enabled,
+                        ..Default::default()
+                    },
+                )
+                .into();
+            connection_request.keyspace_events = Some(glide_core::client::KeyspaceEventsConfig {
+                event_mask: "E$".to_string(),
+                key_patterns: vec![],
+            });
+            let (push_sender, mut push_receiver) = glide_core::pubsub::push_channel();
+            let mut client = Client::new(connection_request, Some(push_sender))
+                .await
+                .unwrap();
+
+            let all_primaries = |response_policy| {
+                Some(RoutingInfo::MultiNode((
+                    MultipleNodeRoutingInfo::AllMasters,
+                    Some(response_policy),
+                )))
+            };
+            let mut config_cmd = redis::cmd("CONFIG");
+            config_cmd
+                .arg("SET")
+                .arg("notify-keyspace-events")
+                .arg("E$");
+            client
+                .send_command(&mut config_cmd, all_primaries(ResponsePolicy::AllSucceeded))
+                .await
+                .unwrap();
+
+            // Each primary only notifies about its own keys, so the pattern must be subscribed on all of them.
+            let mut numpat_cmd = redis::cmd("PUBSUB");
+            numpat_cmd.arg("NUMPAT");
+            retry(|| async {
+                let mut client = client.clone();
+                let mut numpat_cmd = numpat_cmd.clone();
+                let numpat = client
+                    .send_command(
+                        &mut numpat_cmd,
+                        all_primaries(ResponsePolicy::Aggregate(AggregateOp::Min)),
+                    )
+                    .await
+                    .ok()?;
+                matches!(numpat, Value::Int(count) if count >= 1).then_some(())
+            })
+            .await;
+
+            // The slots of the 3 shards are split evenly, in increasing order.
+            let keys: HashSet<String> = [0..5461, 5461..10923, 10923..16384]
+                .into_iter()
+                .map(|slots| {
+                    (0..)
+                        .map(|index| format!("keyspace-{index}"))
+                        .find(|key| slots.contains(&get_slot(key.as_bytes())))
+                        .unwrap()
+                })
+                .collect();
+            for key in &keys {
+                let mut set_cmd = redis::cmd("SET");
+                set_cmd.arg(key).arg("value");
+                client.send_command(&mut set_cmd, None).await.unwrap();
+            }
+
+            let mut delivered = HashSet::new();
+            while delivered != keys {
+                let push = tokio::time::timeout(Duration::from_secs(5), push_receiver.recv())
+                    .await
+                    .expect("timed out waiting for the keyspace events")
+                    .unwrap();
+                if let Some(event) = KeyspaceEvent::from_push(&push) {
+                    assert_eq!(event.event, "set");
+                    delivered.insert(String::from_utf8(event.key).unwrap());
+                }
+            }
+        });
+    }
 }
@@ -760,4 +760,216 @@ mod compression_tests {
         let result = manager.try_decompress_value(&unsupported_data);
         assert_eq!(result, unsupported_data);
     }
+
+    #[test]
+    fn test_zstd_dictionary_compression() {
+        use glide_core::compression::lz4_backend::Lz4Backend;
+        use glide_core::compression::zstd_backend::{ZstdBackend, train_dictionary};
+
+        let samples: Vec<Vec<u8>> = (0..500)
+            .map(|i| {
+                format!(
+                    r#"{{"user_id":{},"name":"user-{}","status":"active","plan":"premium","region":"eu-west-{}"}}"#,
+                    i,
+                    i,
+                    i % 3
+                )
+                .into_bytes()
+            })
+            .collect();
+        let dictionary = train_dictionary(&samples, 1024).unwrap();
+        assert!(!dictionary.is_empty());
+        assert!(dictionary.len() <= 1024);
+
+        let config = CompressionConfig::new(CompressionBackendType::Zstd)
+            .with_min_compression_size(16)
+            .with_dictionary(42, dictionary.clone());
+        let backend = ZstdBackend::with_dictionary(
+            config.dictionary.as_ref().unwrap(),
+            config.compression_level,
+        );
+        assert_eq!(backend.dictionary_id(), Some(42));
+        let manager = CompressionManager::new(Box::new(backend), config).unwrap();
+
+        let value = br#"{"user_id":9000,"name":"user-9000","status":"active","plan":"premium","region":"eu-west-1"}"#;
+        let compressed = manager.compress_value(value);
+        assert!(compressed.len() < value.len());
+        assert_eq!(
+            extract_backend_id(&compressed),
+            Some(ZSTD_DICTIONARY_BACKEND_ID)
+        );
+        assert_eq!(extract_dictionary_id(&compressed), Some(42));
+        assert_eq!(manager.decompress_value(&compressed).unwrap(), value);
+
+        // Values compressed without a dictionary are still readable
+        let plain = ZstdBackend::new().compress(value, None).unwrap();
+        assert_eq!(manager.decompress_value(&plain).unwrap(), value);
+
+        // A client with another dictionary id can't decompress the value
+        let other_config = CompressionConfig::new(CompressionBackendType::Zstd)
+            .with_dictionary(43, dictionary.clone());
+        let other_manager = CompressionManager::new(
+            Box::new(ZstdBackend::with_dictionary(
+                other_config.dictionary.as_ref().unwrap(),
+                other_config.compression_level,
+            )),
+            other_config,
+        )
+        .unwrap();
+        let err = other_manager.decompress_value(&compressed).unwrap_err();
+        assert!(matches!(err, CompressionError::DecompressionFailed { .. }));
+        assert!(err.to_string().contains("unknown dictionary id 42"));
+        assert_eq!(
+            other_manager.try_decompress_value(&compressed),
+            compressed.to_vec()
+        );
+
+        // Neither can clients without a dictionary
+        let lz4_manager = CompressionManager::new(
+            Box::new(Lz4Backend::new()),
+            CompressionConfig::new(CompressionBackendType::Lz4),
+        )
+        .unwrap();
+        let err = lz4_manager.decompress_value(&compressed).unwrap_err();
+        assert!(matches!(err, CompressionError::DecompressionFailed { .. }));
+
+        // Truncated dictionary headers are rejected
+        let truncated = &compressed[..DICTIONARY_HEADER_SIZE - 1];
+        assert_eq!(extract_dictionary_id(truncated), None);
+        let err = manager.decompress_value(truncated).unwrap_err();
+        assert!(matches!(err, CompressionError::DecompressionFailed { .. }));
+
+        // Dictionaries are only supported by zstd
+        let err = CompressionConfig::new(CompressionBackendType::Lz4)
+            .with_dictionary(1, dictionary)
+            .validate()
+            .unwrap_err();
+        assert!(matches!(err, CompressionError::InvalidConfiguration { .. }));
+        let err = CompressionConfig::new(CompressionBackendType::Zstd)
+            .with_dictionary(1, Vec::new())
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("empty"));
+    }
+
+    #[test]
+    fn test_hash_list_and_stream_commands_compression() {
+        use glide_core::compression::zstd_backend::ZstdBackend;
+
+        let backend = Box::new(ZstdBackend::new());
+        let config = CompressionConfig::new(CompressionBackendType::Zstd);
+        let manager = CompressionManager::new(backend, config).unwrap();
+        let value = "A".repeat(200).into_bytes();
+
+        // Only the values are compressed, the keys, field names and options are left as-is
+        let mut hset_args = vec![
+            b"key".to_vec(),
+            b"field1".to_vec(),
+            value.clone(),
+            b"field2".to_vec(),
+            value.clone(),
+        ];
+        process_command_args_for_compression(&mut hset_args, RequestType::HSet, Some(&manager))
+            .unwrap();
+        assert_eq!(hset_args[1], b"field1");
+        assert!(has_magic_header(&hset_args[2]));
+        assert_eq!(hset_args[3], b"field2");
+        assert!(has_magic_header(&hset_args[4]));
+
+        let mut hsetex_args = vec![
+            b"key".to_vec(),
+            b"EX".to_vec(),
+            b"10".to_vec(),
+            b"FIELDS".to_vec(),
+            b"1".to_vec(),
+            b"field".to_vec(),
+            value.clone(),
+        ];
+        process_command_args_for_compression(&mut hsetex_args, RequestType::HSetEx, Some(&manager))
+            .unwrap();
+        assert_eq!(hsetex_args[5], b"field");
+        assert!(has_magic_header(&hsetex_args[6]));
+
+        let mut lpush_args = vec![b"key".to_vec(), value.clone(), value.clone()];
+        process_command_args_for_compression(&mut lpush_args, RequestType::LPush, Some(&manager))
+            .unwrap();
+        assert_eq!(lpush_args[0], b"key");
+        assert!(has_magic_header(&lpush_args[1]));
+        assert!(has_magic_header(&lpush_args[2]));
+
+        let mut xadd_args = vec![
+            b"key".to_vec(),
+            b"NOMKSTREAM".to_vec(),
+            b"MAXLEN".to_vec(),
+            b"~".to_vec(),
+            b"1000".to_vec(),
+            b"LIMIT".to_vec(),
+            b"10".to_vec(),
+            b"*".to_vec(),
+            b"field".to_vec(),
+            value.clone(),
+        ];
+        process_command_args_for_compression(&mut xadd_args, RequestType::XAdd, Some(&manager))
+            .unwrap();
+        assert_eq!(xadd_args[7], b"*");
+        assert_eq!(xadd_args[8], b"field");
+        assert!(has_magic_header(&xadd_args[9]));
+
+        // Responses are decompressed according to their shape
+        let compressed = manager.compress_value(&value).into_owned();
+        let hgetall_resp2 = redis::Value::Array(vec![
+            redis::Value::BulkString(b"field".to_vec()),
+            redis::Value::BulkString(compressed.clone()),
+        ]);
+        assert_eq!(
+            process_response_for_decompression(hgetall_resp2, RequestType::HGetAll, Some(&manager))
+                .unwrap(),
+            redis::Value::Array(vec![
+                redis::Value::BulkString(b"field".to_vec()),
+                redis::Value::BulkString(value.clone()),
+            ])
+        );
+
+        let hgetall_resp3 = redis::Value::Map(vec![(
+            redis::Value::BulkString(b"field".to_vec()),
+            redis::Value::BulkString(compressed.clone()),
+        )]);
+        assert_eq!(
+            process_response_for_decompression(hgetall_resp3, RequestType::HGetAll, Some(&manager))
+                .unwrap(),
+            redis::Value::Map(vec![(
+                redis::Value::BulkString(b"field".to_vec()),
+                redis::Value::BulkString(value.clone()),
+            )])
+        );
+
+        let entries = |field_value: Vec<u8>| {
+            redis::Value::Array(vec![redis::Value::Array(vec![
+                redis::Value::BulkString(b"1-0".to_vec()),
+                redis::Value::Array(vec![
+                    redis::Value::BulkString(b"field".to_vec()),
+                    redis::Value::BulkString(field_value),
+                ]),
+            ])])
+        };
+        let xread_resp3 = redis::Value::Map(vec![(
+            redis::Value::BulkString(b"stream".to_vec()),
+            entries(compressed.clone()),
+        )]);
+        assert_eq!(
+            process_response_for_decompression(xread_resp3, RequestType::XRead, Some(&manager))
+                .unwrap(),
+            redis::Value::Map(vec![(
+                redis::Value::BulkString(b"stream".to_vec()),
+                entries(value.clone()),
+            )])
+        );
+
+        let lpop_with_count = redis::Value::Array(vec![redis::Value::BulkString(compressed)]);
+        assert_eq!(
+            process_response_for_decompression(lpop_with_count, RequestType::LPop, Some(&manager))
+                .unwrap(),
+            redis::Value::Array(vec![redis::Value::BulkString(value)])
+        );
+    }
 }
@@ -378,7 +378,6 @@ mod socket_listener {
         cluster_mode: ClusterMode,
     ) {
         // Send the server address
-        const CALLBACK_INDEX: u32 = 0;
         let connection_request = create_connection_request(
             addresses,
             &TestConfiguration {
@@ -388,6 +387,14 @@ mod socket_listener {
                 ..Default::default()
             },
         );
+        send_connection_request(socket, connection_request);
+    }
+
+    fn send_connection_request(
+        socket: &UnixStream,
+        connection_request: connection_request::ConnectionRequest,
+    ) {
+        const CALLBACK_INDEX: u32 = 0;
         let approx_message_length =
             APPROX_RESP_HEADER_LEN + connection_request.compute_size() as usize;
         let mut buffer = Vec::with_capacity(approx_message_length);
@@ -403,6 +410,13 @@ mod socket_listener {
         addresses: &[ConnectionAddr],
         cluster_mode: ClusterMode,
     ) -> UnixStream {
+        let socket = start_socket_listener(socket_path);
+        connect_to_redis(addresses, &socket, use_tls, cluster_mode);
+        socket
+    }
+
+    /// Starts a socket listener and returns a socket connected to it, which didn't send a connection request yet.
+    fn start_socket_listener(socket_path: Option<String>) -> UnixStream {
         let socket_listener_state: Arc<ManualResetEvent> =
             Arc::new(ManualResetEvent::new(EventState::Unset));
         let cloned_state = socket_listener_state.clone();
@@ -420,9 +434,7 @@ mod socket_listener {
         socket_listener_state.wait();
         let path = path_arc.lock().unwrap();
         let path = path.as_ref().expect("Didn't get any socket path");
-        let socket = std::os::unix::net::UnixStream::connect(path).unwrap();
-        connect_to_redis(addresses, &socket, use_tls, cluster_mode);
-        socket
+        std::os::unix::net::UnixStream::connect(path).unwrap()
     }
 
     fn setup_mocked_test_basics(socket_path: Option<String>) -> ServerTestBasicsWithMock {
@@ -1224,6 +1236,181 @@ mod socket_listener {
         );
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_transaction_session_aborts_when_watched_key_changes(
+        #[values(RedisType::Cluster, RedisType::Standalone)] use_cluster: RedisType,
+    ) {
+        let test_basics = setup_test_basics(Tls::NoTls, TestServer::Shared, use_cluster);
+        let mut socket = test_basics.socket;
+        let key = generate_random_string(KEY_LENGTH);
+
+        // Start a session pinned to the key's slot
+        let mut buffer = Vec::with_capacity(200);
+        let mut request = CommandRequest::new();
+        request.callback_idx = 0;
+        let mut start_session = command_request::StartTransactionSession::new();
+        start_session.routing_key = Some(key.clone().into());
+        request.command =
+            Some(command_request::command_request::Command::StartTransactionSession(start_session));
+        write_request(&mut buffer, &mut socket, request);
+        let response = get_response(&mut buffer, Some(&mut socket));
+        let Some(response::Value::RespPointer(pointer)) = response.value else {
+            panic!("Expected a session id, received {response:?}");
+        };
+        let session_id = String::from_owned_redis_value(*pointer_to_value(pointer)).unwrap();
+
+        // WATCH the key in the session
+        buffer.clear();
+        let mut request = get_command_request(
+            1,
+            vec!["WATCH".into(), key.clone().into()],
+            RequestType::CustomCommand.into(),
+            false,
+        );
+        request.transaction_session_id = Some(session_id.clone().into());
+        write_request(&mut buffer, &mut socket, request);
+        assert_ok_response(&mut buffer, &mut socket, 1);
+
+        // Modify the key outside of the session
+        buffer.clear();
+        write_set(
+            &mut buffer,
+            &mut socket,
+            2,
+            key.as_str(),
+            "foo".into(),
+            false,
+        );
+        assert_ok_response(&mut buffer, &mut socket, 2);
+
+        // The transaction is aborted, since the watched key was modified
+        buffer.clear();
+        let mut request = CommandRequest::new();
+        request.callback_idx = 3;
+        let mut batch = Batch::new();
+        batch.is_atomic = true;
+        batch.commands.push(get_command(CommandComponents {
+            args: vec![key.clone().into(), "bar".into()],
+            args_pointer: false,
+            request_type: RequestType::Set.into(),
+        }));
+        request.command = Some(command_request::command_request::Command::Batch(batch));
+        request.transaction_session_id = Some(session_id.clone().into());
+        write_request(&mut buffer, &mut socket, request);
+        assert_null_response(&mut buffer, &mut socket, 3);
+
+        buffer.clear();
+        let mut request = CommandRequest::new();
+        request.callback_idx = 4;
+        let mut end_session = command_request::EndTransactionSession::new();
+        end_session.session_id = session_id.into();
+        request.command =
+            Some(command_request::command_request::Command::EndTransactionSession(end_session));
+        write_request(&mut buffer, &mut socket, request);
+        assert_ok_response(&mut buffer, &mut socket, 4);
+
+        buffer.clear();
+        write_get(&mut buffer, &mut socket, 5, key.as_str(), false);
+        assert_value_response(
+            &mut buffer,
+            Some(&mut socket),
+            5,
+            Value::BulkString(b"foo".to_vec()),
+        );
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_transaction_session_compresses_written_values() {
+        let mut socket = start_socket_listener(None);
+        let mut connection_request = create_connection_request(
+            &[get_shared_server_address(false)],
+            &TestConfiguration {
+                request_timeout: Some(REQUEST_TIMEOUT_MS),
+                ..Default::default()
+            },
+        );
+        let mut compression_config = connection_request::CompressionConfig::new();
+        compression_config.enabled = true;
+        compression_config.backend = connection_request::CompressionBackend::ZSTD.into();
+        compression_config.min_compression_size = 64;
+        connection_request.compression_config = protobuf::MessageField::some(compression_config);
+        send_connection_request(&socket, connection_request);
+        let key = generate_random_string(KEY_LENGTH);
+        let value = "compressible ".repeat(100);
+
+        let mut buffer = Vec::with_capacity(200);
+        let mut request = CommandRequest::new();
+        request.callback_idx = 1;
+        request.command = Some(
+            command_request::command_request::Command::StartTransactionSession(
+                command_request::StartTransactionSession::new(),
+            ),
+        );
+        write_request(&mut buffer, &mut socket, request);
+        let response = get_response(&mut buffer, Some(&mut socket));
+        let Some(response::Value::RespPointer(pointer)) = response.value else {
+            panic!("Expected a session id, received {response:?}");
+        };
+        let session_id = String::from_owned_redis_value(*pointer_to_value(pointer)).unwrap();
+
+        // Write the value inside the session
+        buffer.clear();
+        let mut request = get_command_request(
+            2,
+            vec![key.clone().into(), value.clone().into()],
+            RequestType::Set.into(),
+            false,
+        );
+        request.transaction_session_id = Some(session_id.clone().into());
+        write_request(&mut buffer, &mut socket, request);
+        assert_ok_response(&mut buffer, &mut socket, 2);
+
+        buffer.clear();
+        let mut request = CommandRequest::new();
+        request.callback_idx = 3;
+        let mut end_session = command_request::EndTransactionSession::new();
+        end_session.session_id = session_id.into();
+        request.command =
+            Some(command_request::command_request::Command::EndTransactionSession(end_session));
+        write_request(&mut buffer, &mut socket, request);
+        assert_ok_response(&mut buffer, &mut socket, 3);
+
+        // The value is stored compressed, as if it was written outside of a session
+        buffer.clear();
+        write_command_request(
+            &mut buffer,
+            &mut socket,
+            4,
+            vec!["STRLEN".into(), key.clone().into()],
+            RequestType::CustomCommand.into(),
+            false,
+        );
+        let response = get_response(&mut buffer, Some(&mut socket));
+        let Some(response::Value::RespPointer(pointer)) = response.value else {
+            panic!("Expected the length of the value, received {response:?}");
+        };
+        let stored_length = i64::from_owned_redis_value(*pointer_to_value(pointer)).unwrap();
+        assert!(
+            (stored_length as usize) < value.len(),
+            "Expected a compressed value shorter than {} bytes, stored {stored_length} bytes",
+            value.len()
+        );
+
+        // And decompressed when read back outside of the session
+        buffer.clear();
+        write_get(&mut buffer, &mut socket, 5, key.as_str(), false);
+        assert_value_response(
+            &mut buffer,
+            Some(&mut socket),
+            5,
+            Value::BulkString(value.into_bytes()),
+        );
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
@@ -7,7 +7,7 @@ mod utilities;
 mod standalone_client_tests {
     use super::*;
     use crate::constants::{IP_ADDRESS_V4, IP_ADDRESS_V6};
-    use crate::utilities::mocks::{Mock, ServerMock};
+    use crate::utilities::mocks::{Mock, SentinelMock, ServerMock};
     use glide_core::{
         client::{Client as GlideClient, ConnectionError, StandaloneClient},
         connection_request::{ProtocolVersion, ReadFrom},
@@ -73,6 +73,49 @@ mod standalone_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_unix_socket_connection_reconnects() {
+        block_on_all(async move {
+            let socket_dir = tempfile::tempdir().expect("Failed to create temp dir");
+            let socket_path = socket_dir.path().join("valkey.sock");
+            let _server = RedisServer::new_with_addr_and_modules(
+                redis::ConnectionAddr::Unix(socket_path.clone()),
+                &[],
+            );
+            retry(|| async { socket_path.exists().then_some(()) }).await;
+
+            let mut connection_request = glide_core::connection_request::ConnectionRequest::new();
+            connection_request.unix_socket_path = socket_path.to_str().unwrap().into();
+            let mut client = GlideClient::new(connection_request.into(), None)
+                .await
+                .expect("Failed to create client with a Unix socket");
+            assert_connected(&mut client).await;
+
+            let mut client_id_cmd = redis::cmd("CLIENT");
+            client_id_cmd.arg("ID");
+            let client_id = client
+                .send_command(&mut client_id_cmd.clone(), None)
+                .await
+                .unwrap();
+
+            kill_connection(&mut client).await;
+            let new_client_id = retry(|| async {
+                let mut client = client.clone();
+                client
+                    .send_command(&mut client_id_cmd.clone(), None)
+                    .await
+                    .ok()
+            })
+            .await;
+            assert_ne!(
+                client_id, new_client_id,
+                "The client should have reconnected through the Unix socket"
+            );
+        });
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
@@ -418,6 +461,357 @@ mod standalone_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_reads_from_replica_discovered_through_primary() {
+        let replica = ServerMock::new(create_replica_response());
+        let Some(redis::ConnectionAddr::Tcp(host, port)) = replica.get_addresses().pop() else {
+            panic!("expected a TCP address");
+        };
+        let mut primary_responses = create_primary_responses();
+        primary_responses.insert(
+            "*2\r\n$4\r\nINFO\r\n$11\r\nREPLICATION\r\n".to_string(),
+            Value::BulkString(
+                format!(
+                    "role:master\r\nconnected_slaves:1\r\nslave0:ip={host},port={port},state=online,offset=0,lag=0\r\n"
+                )
+                .into_bytes(),
+            ),
+        );
+        let primary = ServerMock::new(primary_responses);
+        let mut get_cmd = redis::cmd("GET");
+        get_cmd.arg("foo");
+        replica.add_response(&get_cmd, "$-1\r\n".to_string());
+
+        // Only the primary is configured.
+        let mut connection_request =
+            create_connection_request(primary.get_addresses().as_slice(), &Default::default());
+        connection_request.discover_replicas = true;
+        connection_request.read_from = ReadFrom::PreferReplica.into();
+
+        block_on_all(async {
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .unwrap();
+            assert_eq!(client.send_command(&get_cmd).await.unwrap(), Value::Nil);
+        });
+
+        assert_eq!(primary.get_number_of_received_commands(), 0);
+        assert_eq!(replica.get_number_of_received_commands(), 1);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_read_only_client_reads_from_discovered_replica() {
+        let replica = ServerMock::new(create_replica_response());
+        let Some(redis::ConnectionAddr::Tcp(host, port)) = replica.get_addresses().pop() else {
+            panic!("expected a TCP address");
+        };
+        let mut primary_responses = create_primary_responses();
+        primary_responses.insert(
+            "*2\r\n$4\r\nINFO\r\n$11\r\nREPLICATION\r\n".to_string(),
+            Value::BulkString(
+                format!(
+                    "role:master\r\nconnected_slaves:1\r\nslave0:ip={host},port={port},state=online,offset=0,lag=0\r\n"
+                )
+                .into_bytes(),
+            ),
+        );
+        let primary = ServerMock::new(primary_responses);
+        let mut get_cmd = redis::cmd("GET");
+        get_cmd.arg("foo");
+        replica.add_response(&get_cmd, "$-1\r\n".to_string());
+
+        let mut connection_request =
+            create_connection_request(primary.get_addresses().as_slice(), &Default::default());
+        connection_request.discover_replicas = true;
+        connection_request.read_only = Some(true);
+        connection_request.read_from = ReadFrom::PreferReplica.into();
+
+        block_on_all(async {
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .unwrap();
+            assert_eq!(client.send_command(&get_cmd).await.unwrap(), Value::Nil);
+        });
+
+        assert_eq!(primary.get_number_of_received_commands(), 0);
+        assert_eq!(replica.get_number_of_received_commands(), 1);
+    }
+
+    fn sentinel_connection_request(
+        sentinel: &SentinelMock,
+        username: Option<&str>,
+        password: &str,
+    ) -> glide_core::connection_request::ConnectionRequest {
+        let mut connection_request = create_connection_request(&[], &Default::default());
+        let mut sentinel_config = glide_core::connection_request::SentinelConfig::new();
+        sentinel_config
+            .sentinel_addresses
+            .push(get_address_info(&sentinel.get_address()));
+        sentinel_config.master_name = "mymaster".into();
+        if let Some(username) = username {
+            sentinel_config.username = username.into();
+        }
+        sentinel_config.password = password.into();
+        connection_request.sentinel_config = protobuf::MessageField::some(sentinel_config);
+        connection_request
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_primary_is_resolved_through_authenticated_sentinel() {
+        let primary = ServerMock::new(create_primary_responses());
+        let Some(redis::ConnectionAddr::Tcp(host, port)) = primary.get_addresses().pop() else {
+            panic!("expected a TCP address");
+        };
+        let sentinel = SentinelMock::new(
+            "mymaster",
+            (host, port),
+            Some("sentinel-user"),
+            Some("sentinel-password"),
+        );
+        let mut get_cmd = redis::cmd("GET");
+        get_cmd.arg("foo");
+        primary.add_response(&get_cmd, "$-1\r\n".to_string());
+
+        let connection_request =
+            sentinel_connection_request(&sentinel, Some("sentinel-user"), "sentinel-password");
+
+        block_on_all(async {
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .unwrap();
+            assert_eq!(client.send_command(&get_cmd).await.unwrap(), Value::Nil);
+        });
+
+        assert!(sentinel.get_number_of_authenticated_connections() > 0);
+        assert_eq!(primary.get_number_of_received_commands(), 1);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_sentinel_rejects_wrong_credentials() {
+        let primary = ServerMock::new(create_primary_responses());
+        let Some(redis::ConnectionAddr::Tcp(host, port)) = primary.get_addresses().pop() else {
+            panic!("expected a TCP address");
+        };
+        let sentinel = SentinelMock::new(
+            "mymaster",
+            (host, port),
+            Some("sentinel-user"),
+            Some("sentinel-password"),
+        );
+
+        let connection_request =
+            sentinel_connection_request(&sentinel, Some("sentinel-user"), "wrong-password");
+
+        block_on_all(async {
+            let result =
+                StandaloneClient::create_client(connection_request.into(), None, None, None).await;
+            assert!(result.is_err());
+        });
+
+        assert_eq!(sentinel.get_number_of_authenticated_connections(), 0);
+    }
+
+    struct FakeDnsResolver(std::sync::Mutex<std::net::SocketAddr>);
+
+    #[async_trait::async_trait]
+    impl redis::DnsResolver for FakeDnsResolver {
+        async fn resolve(
+            &self,
+            _host: &str,
+            _port: u16,
+        ) -> redis::RedisResult<Vec<std::net::SocketAddr>> {
+            Ok(vec![*self.0.lock().unwrap()])
+        }
+    }
+
+    #[rstest]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_connection_moves_to_new_address_of_hostname() {
+        let old_server = ServerMock::new(create_primary_responses());
+        let new_listener = std::net::TcpListener::bind((IP_ADDRESS_V6, 0)).unwrap();
+        let new_address = new_listener.local_addr().unwrap();
+        let new_server = ServerMock::new_with_listener(create_primary_responses(), new_listener);
+        let Some(redis::ConnectionAddr::Tcp(_, old_port)) = old_server.get_addresses().pop() else {
+            panic!("expected a TCP address");
+        };
+        let old_address = std::net::SocketAddr::new(IP_ADDRESS_V4.parse().unwrap(), old_port);
+        let resolver = std::sync::Arc::new(FakeDnsResolver(std::sync::Mutex::new(old_address)));
+        let mut get_cmd = redis::cmd("GET");
+        get_cmd.arg("foo");
+        old_server.add_response(&get_cmd, "$-1\r\n".to_string());
+        new_server.add_response(&get_cmd, "$-1\r\n".to_string());
+
+        let address = redis::ConnectionAddr::Tcp("valkey.test".to_string(), old_port);
+        let mut connection_request = create_connection_request(&[address], &Default::default());
+        connection_request.dns_refresh_interval_ms = 50;
+        let mut connection_request: glide_core::client::ConnectionRequest =
+            connection_request.into();
+        connection_request.dns_resolver = Some(resolver.clone());
+
+        block_on_all(async {
+            let mut client = StandaloneClient::create_client(connection_request, None, None, None)
+                .await
+                .unwrap();
+            assert_eq!(client.send_command(&get_cmd).await.unwrap(), Value::Nil);
+
+            *resolver.0.lock().unwrap() = new_address;
+            // The replaced connection is closed once the client moved to the new address.
+            old_server.wait_for_client_disconnect().await;
+            assert_eq!(client.send_command(&get_cmd).await.unwrap(), Value::Nil);
+        });
+
+        assert_eq!(old_server.get_number_of_received_commands(), 1);
+        assert_eq!(new_server.get_number_of_received_commands(), 1);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_discovered_replica_matches_replica_configured_by_hostname() {
+        let replica = ServerMock::new(create_replica_response());
+        let Some(redis::ConnectionAddr::Tcp(_, replica_port)) = replica.get_addresses().pop()
+        else {
+            panic!("expected a TCP address");
+        };
+        // The primary lists the replica by IP, while the client was configured with its hostname.
+        let mut primary_responses = create_primary_responses();
+        primary_responses.insert(
+            "*2\r\n$4\r\nINFO\r\n$11\r\nREPLICATION\r\n".to_string(),
+            Value::BulkString(
+                format!(
+                    "role:master\r\nconnected_slaves:1\r\nslave0:ip={IP_ADDRESS_V4},port={replica_port},state=online,offset=0,lag=0\r\n"
+                )
+                .into_bytes(),
+            ),
+        );
+        let primary = ServerMock::new(primary_responses);
+        let Some(redis::ConnectionAddr::Tcp(_, primary_port)) = primary.get_addresses().pop()
+        else {
+            panic!("expected a TCP address");
+        };
+        let resolver = std::sync::Arc::new(FakeDnsResolver(std::sync::Mutex::new(
+            std::net::SocketAddr::new(IP_ADDRESS_V4.parse().unwrap(), replica_port),
+        )));
+        let mut get_cmd = redis::cmd("GET");
+        get_cmd.arg("foo");
+        // The mock accepts a single connection, so a second connection to the replica couldn't serve the reads.
+        replica.add_response(&get_cmd, "$-1\r\n".to_string());
+        replica.add_response(&get_cmd, "$-1\r\n".to_string());
+
+        let addresses = [
+            redis::ConnectionAddr::Tcp(IP_ADDRESS_V4.to_string(), primary_port),
+            redis::ConnectionAddr::Tcp("replica.test".to_string(), replica_port),
+        ];
+        let mut connection_request = create_connection_request(&addresses, &Default::default());
+        connection_request.discover_replicas = true;
+        connection_request.read_from = ReadFrom::PreferReplica.into();
+        let mut connection_request: glide_core::client::ConnectionRequest =
+            connection_request.into();
+        connection_request.dns_resolver = Some(resolver);
+
+        block_on_all(async {
+            let mut client = StandaloneClient::create_client(connection_request, None, None, None)
+                .await
+                .unwrap();
+            assert_eq!(client.send_command(&get_cmd).await.unwrap(), Value::Nil);
+            assert_eq!(client.send_command(&get_cmd).await.unwrap(), Value::Nil);
+        });
+
+        assert_eq!(primary.get_number_of_received_commands(), 0);
+        assert_eq!(replica.get_number_of_received_commands(), 2);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_write_follows_primary_after_failover() {
+        let servers = create_primary_mock_with_replicas(1);
+        let (old_primary, new_primary) = (&servers[0], &servers[1]);
+        let mut set_cmd = redis::cmd("SET");
+        set_cmd.arg("foo").arg("bar");
+        let role_cmd = redis::cmd("ROLE");
+
+        // The primary was demoted, and rejects the write.
+        old_primary.add_response(
+            &set_cmd,
+            "-READONLY You can't write against a read only replica.\r\n".to_string(),
+        );
+        old_primary.add_response(
+            &role_cmd,
+            "*5\r\n$5\r\nslave\r\n$9\r\n127.0.0.1\r\n:6379\r\n$9\r\nconnected\r\n:0\r\n"
+                .to_string(),
+        );
+        new_primary.add_response(&role_cmd, "*3\r\n$6\r\nmaster\r\n:0\r\n*0\r\n".to_string());
+        new_primary.add_response(&set_cmd, "+OK\r\n".to_string());
+        new_primary.add_response(&set_cmd, "+OK\r\n".to_string());
+
+        let addresses = get_mock_addresses(&servers);
+        let connection_request =
+            create_connection_request(addresses.as_slice(), &Default::default());
+
+        block_on_all(async {
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .unwrap();
+
+            assert_eq!(client.send_command(&set_cmd).await.unwrap(), Value::Okay);
+            // The following writes go straight to the new primary.
+            assert_eq!(client.send_command(&set_cmd).await.unwrap(), Value::Okay);
+        });
+
+        assert_eq!(old_primary.get_number_of_received_commands(), 2);
+        assert_eq!(new_primary.get_number_of_received_commands(), 3);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_write_keeps_primary_while_two_nodes_report_primary_role() {
+        let servers = create_primary_mock_with_replicas(1);
+        let (primary, replica) = (&servers[0], &servers[1]);
+        let mut set_cmd = redis::cmd("SET");
+        set_cmd.arg("foo").arg("bar");
+        let role_cmd = redis::cmd("ROLE");
+
+        // The failover is in progress: the primary rejects the write, but both nodes still report the primary role.
+        primary.add_response(
+            &set_cmd,
+            "-READONLY You can't write against a read only replica.\r\n".to_string(),
+        );
+        primary.add_response(&role_cmd, "*3\r\n$6\r\nmaster\r\n:0\r\n*0\r\n".to_string());
+        replica.add_response(&role_cmd, "*3\r\n$6\r\nmaster\r\n:0\r\n*0\r\n".to_string());
+
+        let addresses = get_mock_addresses(&servers);
+        let connection_request =
+            create_connection_request(addresses.as_slice(), &Default::default());
+
+        block_on_all(async {
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .unwrap();
+
+            let err = client.send_command(&set_cmd).await.unwrap_err();
+            assert_eq!(err.kind(), redis::ErrorKind::ReadOnly);
+        });
+
+        // The write isn't sent to the replica, since the primary didn't move.
+        assert_eq!(primary.get_number_of_received_commands(), 2);
+        assert_eq!(replica.get_number_of_received_commands(), 1);
+    }
+
     #[rstest]
     #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
     fn test_send_acl_request_to_all_nodes() {
@@ -890,6 +1284,280 @@ mod standalone_client_tests {
         });
     }
 
+    /// Starts a TLS server with `tls_paths` and the extra `args`, and returns it with a connection request that trusts
+    /// its CA and applies `tls_options`.
+    async fn tls_server_and_request(
+        tls_paths: &TlsFilePaths,
+        args: &[&str],
+        tls_options: glide_core::connection_request::TlsOptions,
+    ) -> (
+        RedisServer,
+        glide_core::connection_request::ConnectionRequest,
+    ) {
+        let server = RedisServer::new_with_addr_tls_modules_and_spawner(
+            redis::ConnectionAddr::TcpTls {
+                host: "127.0.0.1".to_string(),
+                port: get_available_port(),
+                insecure: false,
+                tls_params: None,
+            },
+            Some(tls_paths.clone()),
+            &[],
+            false,
+            |cmd| cmd.args(args).spawn().expect("Failed to spawn server"),
+        );
+        // Skip wait_for_server_to_become_ready since it uses default OS verifier
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await; // Give server time to start
+
+        let mut connection_request = create_connection_request(
+            &[server.get_client_addr()],
+            &TestConfiguration {
+                use_tls: true,
+                shared_server: false,
+                ..Default::default()
+            },
+        );
+        connection_request.tls_mode = glide_core::connection_request::TlsMode::SecureTls.into();
+        connection_request.root_certs = vec![tls_paths.read_ca_cert_as_bytes().into()];
+        connection_request.tls_options = protobuf::MessageField::some(tls_options);
+        // Use minimal retries to fail fast
+        connection_request.connection_retry_strategy =
+            Some(glide_core::connection_request::ConnectionRetryStrategy {
+                number_of_retries: 1,
+                factor: 1,
+                exponent_base: 1,
+                ..Default::default()
+            })
+            .into();
+        (server, connection_request)
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_tls_connection_checks_pinned_public_key() {
+        block_on_all(async move {
+            let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+            let tls_paths = build_tls_file_paths(&tempdir);
+
+            let (_server, connection_request) = tls_server_and_request(
+                &tls_paths,
+                &[],
+                glide_core::connection_request::TlsOptions {
+                    pinned_spki_sha256: vec![vec![0; 32].into()],
+                    ..Default::default()
+                },
+            )
+            .await;
+            let client_result =
+                StandaloneClient::create_client(connection_request.into(), None, None, None).await;
+            assert!(
+                client_result.is_err(),
+                "Expected connection to fail when the server's public key isn't pinned"
+            );
+
+            let (_server, connection_request) = tls_server_and_request(
+                &tls_paths,
+                &[],
+                glide_core::connection_request::TlsOptions {
+                    pinned_spki_sha256: vec![
+                        vec![0; 32].into(),
+                        tls_paths.redis_cert_spki_sha256().into(),
+                    ],
+                    ..Default::default()
+                },
+            )
+            .await;
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .expect("Failed to create client with the server's public key pinned");
+            assert_connected(&mut client).await;
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_tls_connection_with_minimum_protocol_version_tls13() {
+        block_on_all(async move {
+            let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+            let tls_paths = build_tls_file_paths(&tempdir);
+            let tls13_options = || glide_core::connection_request::TlsOptions {
+                min_protocol_version: glide_core::connection_request::TlsProtocolVersion::Tls13
+                    .into(),
+                ..Default::default()
+            };
+
+            // A server that only speaks TLS 1.2 is rejected.
+            let (_server, connection_request) = tls_server_and_request(
+                &tls_paths,
+                &["--tls-protocols", "TLSv1.2"],
+                tls13_options(),
+            )
+            .await;
+            let client_result =
+                StandaloneClient::create_client(connection_request.into(), None, None, None).await;
+            assert!(
+                client_result.is_err(),
+                "Expected connection to fail when the server doesn't support TLS 1.3"
+            );
+
+            // A server that also speaks TLS 1.3 negotiates it.
+            let (_server, connection_request) = tls_server_and_request(
+                &tls_paths,
+                &["--tls-protocols", "TLSv1.2 TLSv1.3"],
+                tls13_options(),
+            )
+            .await;
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .expect("Failed to create client with TLS 1.3");
+            assert_connected(&mut client).await;
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_tls_connection_with_server_name_override() {
+        block_on_all(async move {
+            let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+            let tls_paths = build_tls_file_paths(&tempdir);
+
+            // The server is reached by its IP address, which its certificate is also issued for, so a failure means
+            // that the certificate was verified against the overriding name.
+            let (_server, connection_request) = tls_server_and_request(
+                &tls_paths,
+                &[],
+                glide_core::connection_request::TlsOptions {
+                    server_name: "not-the-server.invalid".into(),
+                    ..Default::default()
+                },
+            )
+            .await;
+            let client_result =
+                StandaloneClient::create_client(connection_request.into(), None, None, None).await;
+            assert!(
+                client_result.is_err(),
+                "Expected connection to fail when the certificate isn't issued for the server name"
+            );
+
+            let (_server, connection_request) = tls_server_and_request(
+                &tls_paths,
+                &[],
+                glide_core::connection_request::TlsOptions {
+                    server_name: "localhost".into(),
+                    ..Default::default()
+                },
+            )
+            .await;
+            let mut client =
+                StandaloneClient::create_client(connection_request.into(), None, None, None)
+                    .await
+                    .expect("Failed to create client with the server name override");
+            assert_connected(&mut client).await;
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_tls_connection_uses_rotated_certificate_files() {
+        block_on_all(async move {
+            let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+            let tls_paths = build_tls_file_paths(&tempdir);
+            let ca_cert_bytes = tls_paths.read_ca_cert_as_bytes();
+            let trusted_cert_bytes = tls_paths.read_redis_cert_as_bytes();
+            let trusted_key_bytes = tls_paths.read_redis_key_as_bytes();
+            // Signed by a CA that the server doesn't trust.
+            let other_tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+            let other_tls_paths = build_tls_file_paths(&other_tempdir);
+            let untrusted_cert_bytes = other_tls_paths.read_redis_cert_as_bytes();
+            let untrusted_key_bytes = other_tls_paths.read_redis_key_as_bytes();
+
+            let server = RedisServer::new_with_addr_tls_modules_and_spawner(
+                redis::ConnectionAddr::TcpTls {
+                    host: "127.0.0.1".to_string(),
+                    port: get_available_port(),
+                    insecure: false,
+                    tls_params: None,
+                },
+                Some(tls_paths.clone()),
+                &[],
+                true,
+                |cmd| cmd.spawn().expect("Failed to spawn server"),
+            );
+            let server_addr = server.get_client_addr();
+            // Skip wait_for_server_to_become_ready since it uses default OS verifier
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await; // Give server time to start
+
+            let client_tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+            let root_certs_path = client_tempdir.path().join("ca.crt");
+            let client_cert_path = client_tempdir.path().join("client.crt");
+            let client_key_path = client_tempdir.path().join("client.key");
+            std::fs::write(&root_certs_path, &ca_cert_bytes).unwrap();
+            std::fs::write(&client_cert_path, &trusted_cert_bytes).unwrap();
+            std::fs::write(&client_key_path, &trusted_key_bytes).unwrap();
+
+            let mut connection_request = create_connection_request(
+                &[server_addr],
+                &TestConfiguration {
+                    use_tls: true,
+                    shared_server: false,
+                    ..Default::default()
+                },
+            );
+            connection_request.tls_mode = glide_core::connection_request::TlsMode::SecureTls.into();
+            connection_request.tls_certificate_files =
+                protobuf::MessageField::some(glide_core::connection_request::TlsCertificateFiles {
+                    root_certs_path: root_certs_path.to_str().unwrap().into(),
+                    client_cert_path: client_cert_path.to_str().unwrap().into(),
+                    client_key_path: client_key_path.to_str().unwrap().into(),
+                    reload_interval_ms: 50,
+                    ..Default::default()
+                });
+            let mut client = GlideClient::new(connection_request.into(), None)
+                .await
+                .expect("Failed to create client with certificate files");
+            assert_connected(&mut client).await;
+
+            // Once the rotated files are loaded, the connection that replaces a killed one is rejected by the
+            // server, while the open connection keeps working until it's killed.
+            std::fs::write(&client_cert_path, &untrusted_cert_bytes).unwrap();
+            std::fs::write(&client_key_path, &untrusted_key_bytes).unwrap();
+            retry_until_timeout(
+                || async {
+                    let mut client = client.clone();
+                    kill_connection(&mut client).await;
+                    client
+                        .send_command(&mut redis::cmd("PING"), None)
+                        .await
+                        .err()
+                },
+                std::time::Duration::from_secs(10),
+            )
+            .await;
+
+            // Rotating the trusted certificate back lets the client reconnect.
+            std::fs::write(&client_cert_path, &trusted_cert_bytes).unwrap();
+            std::fs::write(&client_key_path, &trusted_key_bytes).unwrap();
+            retry_until_timeout(
+                || async {
+                    let mut client = client.clone();
+                    client
+                        .send_command(&mut redis::cmd("PING"), None)
+                        .await
+                        .ok()
+                },
+                std::time::Duration::from_secs(10),
+            )
+            .await;
+            assert_connected(&mut client).await;
+        });
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
@@ -195,6 +195,11 @@ impl ServerMock {
         self.closing_signal.set();
         self.closing_completed_signal.wait().await;
     }
+
+    /// Waits until the client closed its connection to the mock.
+    pub async fn wait_for_client_disconnect(&self) {
+        self.closing_completed_signal.wait().await;
+    }
 }
 
 impl Mock for ServerMock {
@@ -220,3 +225,167 @@ impl Drop for ServerMock {
         self.closing_signal.set();
     }
 }
+
+/// Splits the first complete RESP command off `buffer`, and returns its arguments.
+fn take_command(buffer: &mut Vec<u8>) -> Option<Vec<String>> {
+    fn line(buffer: &[u8], start: usize) -> Option<(&str, usize)> {
+        let end = buffer[start..].windows(2).position(|w| w == b"\r\n")? + start;
+        Some((from_utf8(&buffer[start..end]).ok()?, end + 2))
+    }
+
+    let (count, mut position) = line(buffer, 0)?;
+    let count: usize = count.strip_prefix('*')?.parse().ok()?;
+    let mut args = Vec::with_capacity(count);
+    for _ in 0..count {
+        let (len, start) = line(buffer, position)?;
+        let len: usize = len.strip_prefix('$')?.parse().ok()?;
+        if buffer.len() < start + len + 2 {
+            return None;
+        }
+        args.push(String::from_utf8_lossy(&buffer[start..start + len]).into_owned());
+        position = start + len + 2;
+    }
+    buffer.drain(..position);
+    Some(args)
+}
+
+/// Mock sentinel that accepts any number of connections, and reports `primary` as the address of `master_name` to
+/// connections that authenticated with the expected credentials.
+pub struct SentinelMock {
+    address: ConnectionAddr,
+    authenticated_connections: Arc<AtomicU16>,
+    closing_signal: Arc<ManualResetEvent>,
+}
+
+struct SentinelMockState {
+    master_name: String,
+    primary: (String, u16),
+    username: Option<String>,
+    password: Option<String>,
+    authenticated_connections: Arc<AtomicU16>,
+    closing_signal: Arc<ManualResetEvent>,
+}
+
+impl SentinelMock {
+    pub fn new(
+        master_name: &str,
+        primary: (String, u16),
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> Self {
+        let listener = super::get_listener_on_available_port();
+        let address = ConnectionAddr::Tcp(
+            "localhost".to_string(),
+            listener.local_addr().unwrap().port(),
+        );
+        let authenticated_connections = Arc::new(AtomicU16::new(0));
+        let closing_signal = Arc::new(ManualResetEvent::new(false));
+        let state = Arc::new(SentinelMockState {
+            master_name: master_name.to_string(),
+            primary,
+            username: username.map(str::to_string),
+            password: password.map(str::to_string),
+            authenticated_connections: authenticated_connections.clone(),
+            closing_signal: closing_signal.clone(),
+        });
+        std::thread::spawn(move || {
+            for socket in listener.incoming() {
+                let Ok(socket) = socket else {
+                    return;
+                };
+                if state.closing_signal.is_set() {
+                    return;
+                }
+                let state = state.clone();
+                std::thread::spawn(move || state.serve(socket));
+            }
+        });
+
+        Self {
+            address,
+            authenticated_connections,
+            closing_signal,
+        }
+    }
+
+    pub fn get_address(&self) -> ConnectionAddr {
+        self.address.clone()
+    }
+
+    /// Number of connections that authenticated with the expected credentials.
+    pub fn get_number_of_authenticated_connections(&self) -> u16 {
+        self.authenticated_connections.load(Ordering::Acquire)
+    }
+}
+
+impl SentinelMockState {
+    fn serve(&self, mut socket: StdTcpStream) {
+        let _ = socket.set_read_timeout(Some(std::time::Duration::from_millis(10)));
+        let mut authenticated = self.password.is_none();
+        let mut buffer = Vec::new();
+        let mut chunk = vec![0; 1024];
+        while let Some(size) = read_from_socket(&mut chunk, &mut socket, &self.closing_signal) {
+            buffer.extend_from_slice(&chunk[..size]);
+            while let Some(args) = take_command(&mut buffer) {
+                let response = self.respond(&args, &mut authenticated);
+                if socket.write_all(response.as_bytes()).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn respond(&self, args: &[String], authenticated: &mut bool) -> String {
+        let command = args[0].to_uppercase();
+        if command == "AUTH" {
+            let (username, password) = match args {
+                [_, password] => (None, password),
+                [_, username, password] => (Some(username), password),
+                _ => return "-ERR wrong number of arguments for 'auth' command\r\n".to_string(),
+            };
+            if username.map(String::as_str).unwrap_or("default")
+                == self.username.as_deref().unwrap_or("default")
+                && Some(password.as_str()) == self.password.as_deref()
+            {
+                *authenticated = true;
+                self.authenticated_connections
+                    .fetch_add(1, Ordering::AcqRel);
+                return "+OK\r\n".to_string();
+            }
+            return "-WRONGPASS invalid username-password pair or user is disabled.\r\n"
+                .to_string();
+        }
+        if !*authenticated {
+            return "-NOAUTH Authentication required.\r\n".to_string();
+        }
+
+        let bulk = |value: &str| format!("${}\r\n{value}\r\n", value.len());
+        match (command.as_str(), args.get(1).map(|arg| arg.to_uppercase())) {
+            ("SENTINEL", Some(subcommand)) if subcommand == "GET-MASTER-ADDR-BY-NAME" => {
+                if args.get(2) == Some(&self.master_name) {
+                    let (host, port) = &self.primary;
+                    format!("*2\r\n{}{}", bulk(host), bulk(&port.to_string()))
+                } else {
+                    "*-1\r\n".to_string()
+                }
+            }
+            ("SENTINEL", Some(subcommand))
+                if subcommand == "REPLICAS" || subcommand == "SLAVES" =>
+            {
+                "*0\r\n".to_string()
+            }
+            ("SUBSCRIBE", Some(_)) => {
+                format!("*3\r\n{}{}:1\r\n", bulk("subscribe"), bulk(&args[1]))
+            }
+            ("PING", _) => "+PONG\r\n".to_string(),
+            ("CLIENT", _) => "+OK\r\n".to_string(),
+            _ => format!("-ERR unknown command '{}'\r\n", args[0]),
+        }
+    }
+}
+
+impl Drop for SentinelMock {
+    fn drop(&mut self) {
+        self.closing_signal.set();
+    }
+}
@@ -530,6 +530,32 @@ impl TlsFilePaths {
     pub fn read_redis_key_as_bytes(&self) -> Vec<u8> {
         fs::read(&self.redis_key).expect("Failed to read redis private key file")
     }
+    /// Returns the SHA-256 hash of the DER encoded public key of the redis certificate, as pinned by clients.
+    pub fn redis_cert_spki_sha256(&self) -> Vec<u8> {
+        let mut public_key_cmd = process::Command::new("openssl")
+            .arg("pkey")
+            .arg("-in")
+            .arg(&self.redis_key)
+            .arg("-pubout")
+            .arg("-outform")
+            .arg("DER")
+            .stdout(process::Stdio::piped())
+            .stderr(process::Stdio::null())
+            .spawn()
+            .expect("failed to spawn openssl");
+        let hash = process::Command::new("openssl")
+            .arg("dgst")
+            .arg("-sha256")
+            .arg("-binary")
+            .stdin(public_key_cmd.stdout.take().expect("should have stdout"))
+            .stderr(process::Stdio::null())
+            .output()
+            .expect("failed to hash the public key");
+        public_key_cmd
+            .wait()
+            .expect("failed to export the public key");
+        hash.stdout
+    }
 }
 
 pub async fn wait_for_server_to_become_ready(server_address: &ConnectionAddr) {
@@ -764,6 +790,9 @@ pub fn create_connection_request(
 
     connection_request.client_side_cache =
         protobuf::MessageField::from_option(configuration.client_side_cache.clone());
+    connection_request.blocking_commands_pool =
+        protobuf::MessageField::from_option(configuration.blocking_commands_pool.clone());
+    connection_request.connections_per_node = configuration.connections_per_node;
 
     connection_request
 }
@@ -783,6 +812,8 @@ pub struct TestConfiguration {
     pub protocol: ProtocolVersion,
     pub lazy_connect: bool,
     pub client_side_cache: Option<connection_request::ClientSideCache>,
+    pub blocking_commands_pool: Option<connection_request::BlockingCommandsPool>,
+    pub connections_per_node: Option<u32>,
 }
 
 pub(crate) async fn setup_test_basics_internal(configuration: &TestConfiguration) -> TestBasics {
@@ -1089,6 +1120,6 @@ pub fn is_key_cached(
     key: &[u8],
     cache_key_type: redis::cache::glide_cache::CachedKeyType,
 ) -> bool {
-    let cache = redis::cache::get_or_create_cache(cache_id, 1000, 0, None, true);
+    let cache = redis::cache::get_or_create_cache(cache_id, 1000, 0, None, true, None);
     cache.get(key, cache_key_type).is_some()
 }
@@ -6,6 +6,7 @@ use dashmap::DashMap;
 use glide_core::client::Client as GlideClient;
 use glide_core::client::ConnectionRequest;
 use glide_core::errors::{error_message, error_type};
+use glide_core::pubsub::{PushSender, push_channel};
 use jni::JNIEnv;
 use jni::JavaVM;
 use jni::objects::{GlobalRef, JClass, JObject, JStaticMethodID, JValue};
@@ -174,7 +175,7 @@ pub fn generate_safe_handle() -> u64 {
 /// Create actual glide-core Valkey client with specified configuration
 pub async fn create_glide_client(
     connection_request: ConnectionRequest,
-    push_tx: Option<tokio::sync::mpsc::UnboundedSender<redis::PushInfo>>,
+    push_tx: Option<PushSender>,
 ) -> Result<GlideClient> {
     let client = GlideClient::new(connection_request, push_tx)
         .await
@@ -203,7 +204,7 @@ pub async fn ensure_client_for_handle(handle_id: u64) -> Result<GlideClient> {
         // Always setup push channel for push message support
         // This enables dynamic subscriptions to work,
         // even when no initial subscriptions are configured
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<redis::PushInfo>();
+        let (tx, mut rx) = push_channel();
 
         let client = create_glide_client(cfg, Some(tx)).await?;
         table.insert(handle_id, client.clone());
@@ -345,6 +345,9 @@ async fn execute_command_request_and_complete(
                                 retry_connection_error: batch
                                     .retry_connection_error
                                     .unwrap_or(false),
+                                retry_idempotent_connection_error: batch
+                                    .retry_idempotent_connection_error
+                                    .unwrap_or(false),
                             },
                         )
                         .await
@@ -1409,7 +1412,7 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_createClient(
 
         // Always create push channel to support dynamic subscriptions via customCommand
         // This matches the behavior of socket_listener.rs which always creates push channels
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<redis::PushInfo>();
+        let (tx, rx) = glide_core::pubsub::push_channel();
 
         match runtime.block_on(async { create_glide_client(connection_request, Some(tx)).await }) {
             Ok(client) => {
@@ -1703,6 +1706,9 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_executeBatchAsync(
                                             retry_connection_error: batch
                                                 .retry_connection_error
                                                 .unwrap_or(false),
+                                            retry_idempotent_connection_error: batch
+                                                .retry_idempotent_connection_error
+                                                .unwrap_or(false),
                                         },
                                     )
                                     .await
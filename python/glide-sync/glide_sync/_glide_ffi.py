@@ -246,6 +246,7 @@ class _GlideFFI:
             typedef struct {
                 bool retry_server_error;
                 bool retry_connection_error;
+                bool retry_idempotent_connection_error;
                 bool has_timeout;
                 uint32_t timeout;
                 const RouteInfo* route_info;